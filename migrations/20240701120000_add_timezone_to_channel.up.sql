@@ -0,0 +1,3 @@
+-- Channel.hour and Channel.minute are now in the channel's own timezone. Existing channels were
+-- scheduled in UTC, so they keep the same time
+ALTER TABLE channel ADD COLUMN timezone text NOT NULL DEFAULT 'UTC';
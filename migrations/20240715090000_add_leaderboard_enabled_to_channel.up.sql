@@ -0,0 +1,2 @@
+-- Channels get leaderboards posted weekly and monthly, unless they opt out
+ALTER TABLE channel ADD COLUMN leaderboard_enabled boolean NOT NULL DEFAULT true;
@@ -0,0 +1,28 @@
+-- Similarities are computed once per secret, rather than in a materialised view per game
+CREATE TABLE
+secret_similarity (
+    secret text NOT NULL,
+    word text NOT NULL,
+    rank bigint NOT NULL,
+    similarity double precision NOT NULL,
+    PRIMARY KEY (secret, word)
+);
+
+CREATE INDEX secret_similarity_rank_idx ON secret_similarity USING btree (
+    secret, rank
+);
+
+-- Drop the materialised views, the similarities are recomputed for active games when needed
+DO $$
+DECLARE
+    matview record;
+BEGIN
+    FOR matview IN
+        SELECT matviewname FROM pg_matviews WHERE matviewname LIKE 'word2vec\_%'
+    LOOP
+        EXECUTE format('DROP MATERIALIZED VIEW %I', matview.matviewname);
+    END LOOP;
+END $$;
+
+-- The nightly matview cleanup task no longer exists
+DELETE FROM fang_tasks WHERE metadata->>'type' = 'MatViewCleanupTask';
@@ -0,0 +1,5 @@
+ALTER TABLE channel
+DROP COLUMN secret_pool;
+
+DROP TABLE secret_pool_word;
+DROP TABLE secret_pool;
@@ -0,0 +1,18 @@
+-- Named lists of secrets that a channel can choose from, instead of the built-in target words
+CREATE TABLE
+secret_pool (
+    name text NOT NULL,
+    description text NOT NULL DEFAULT '',
+    PRIMARY KEY (name)
+);
+
+CREATE TABLE
+secret_pool_word (
+    pool_name text NOT NULL REFERENCES secret_pool (name) ON DELETE CASCADE,
+    word text NOT NULL,
+    PRIMARY KEY (pool_name, word)
+);
+
+-- Channels without a pool use the built-in target words
+ALTER TABLE channel
+ADD COLUMN secret_pool text REFERENCES secret_pool (name) ON DELETE SET NULL;
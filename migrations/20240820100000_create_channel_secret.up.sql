@@ -0,0 +1,19 @@
+-- The secrets that each channel has played, so they aren't repeated
+CREATE TABLE
+channel_secret (
+    channel_id text NOT NULL REFERENCES channel (id),
+    secret text NOT NULL,
+    -- The puzzle number that the secret was last used for
+    puzzle_number bigint NOT NULL,
+    PRIMARY KEY (channel_id, secret)
+);
+
+-- Backfill the secrets of games played before they were recorded
+INSERT INTO
+    channel_secret (channel_id, secret, puzzle_number)
+SELECT
+    channel_id, secret, max(puzzle_number)
+FROM
+    game
+GROUP BY
+    channel_id, secret;
@@ -0,0 +1 @@
+ALTER TABLE slack_bots DROP COLUMN revoked_at;
@@ -0,0 +1 @@
+ALTER TABLE slack_bots ADD COLUMN revoked_at timestamp with time zone;
@@ -0,0 +1 @@
+DROP INDEX slack_bots_team_enterprise_idx;
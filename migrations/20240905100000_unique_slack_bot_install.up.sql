@@ -0,0 +1,15 @@
+-- Reinstalls used to add another row, so keep only the latest install of each team
+DELETE FROM
+    slack_bots AS old
+USING
+    slack_bots AS latest
+WHERE
+    old.team_id = latest.team_id AND
+    old.enterprise_id IS NOT DISTINCT FROM latest.enterprise_id AND
+    (old.installed_at, old.id) < (latest.installed_at, latest.id);
+
+-- Every install was stored as an enterprise install, but org-wide installs were never supported.
+-- The column has no default and installs always set it, so only the old installs need correcting
+UPDATE slack_bots SET is_enterprise_install = false WHERE is_enterprise_install;
+
+CREATE UNIQUE INDEX slack_bots_team_enterprise_idx ON slack_bots (team_id, COALESCE(enterprise_id, ''));
@@ -0,0 +1,8 @@
+ALTER TABLE channel DROP COLUMN enterprise_id;
+
+DELETE FROM slack_bots WHERE team_id IS NULL;
+
+DROP INDEX slack_bots_team_enterprise_idx;
+CREATE UNIQUE INDEX slack_bots_team_enterprise_idx ON slack_bots (team_id, COALESCE(enterprise_id, ''));
+
+ALTER TABLE slack_bots ALTER COLUMN team_id SET NOT NULL;
@@ -0,0 +1,7 @@
+-- Org-wide installs on Enterprise Grid belong to the org rather than a team
+ALTER TABLE slack_bots ALTER COLUMN team_id DROP NOT NULL;
+
+DROP INDEX slack_bots_team_enterprise_idx;
+CREATE UNIQUE INDEX slack_bots_team_enterprise_idx ON slack_bots (COALESCE(team_id, ''), COALESCE(enterprise_id, ''));
+
+ALTER TABLE channel ADD COLUMN enterprise_id varchar(32);
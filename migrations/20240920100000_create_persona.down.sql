@@ -0,0 +1,6 @@
+ALTER TABLE channel
+DROP COLUMN ai_enabled;
+ALTER TABLE channel
+DROP COLUMN persona;
+
+DROP TABLE persona;
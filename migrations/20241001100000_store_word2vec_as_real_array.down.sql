@@ -0,0 +1,13 @@
+ALTER TABLE word2vec
+DROP CONSTRAINT word2vec_vec_dimensions;
+
+-- The vectors can only go back to a vector column when the pgvecto.rs extension is installed,
+-- otherwise they're left as arrays
+DO $$
+BEGIN
+  IF EXISTS (SELECT FROM pg_extension WHERE extname = 'vectors') THEN
+    ALTER TABLE word2vec
+    ALTER COLUMN vec TYPE vector(300) USING vec::vector(300);
+  END IF;
+END
+$$;
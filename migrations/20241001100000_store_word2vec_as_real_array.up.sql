@@ -0,0 +1,7 @@
+-- Store the vectors as plain arrays, so that only the postgres similarity backend needs the
+-- pgvecto.rs extension. Databases set up before the extension was optional have a vector column
+ALTER TABLE word2vec
+ALTER COLUMN vec TYPE real[] USING translate(vec::text, '[]', '{}')::real[];
+
+ALTER TABLE word2vec
+ADD CONSTRAINT word2vec_vec_dimensions CHECK (cardinality(vec) = 300);
@@ -0,0 +1,178 @@
+use crate::{
+    config::get_config, importer::read_word_list, normalise::is_inflection_of, SimilariumError,
+};
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+/// Split a message into lowercase words, dropping any punctuation
+fn get_words(message: &str) -> impl Iterator<Item = String> + '_ {
+    message
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+}
+
+/// Remove any mentions, such as `<@U123>` or `<@U123|name>`, of users that aren't allowed, along
+/// with any mentions of groups of users such as `<!here>`, `<!channel>` or `<!subteam^S123>`
+fn strip_mentions(message: &str, allowed_user_ids: &[&str]) -> String {
+    let mut stripped = String::with_capacity(message.len());
+    let mut rest = message;
+
+    while let Some(start) = rest.find("<@").into_iter().chain(rest.find("<!")).min() {
+        let Some(length) = rest[start..].find('>') else {
+            break;
+        };
+        let mention = &rest[start..start + length + 1];
+        let user_id = match mention.starts_with("<@") {
+            true => mention[2..mention.len() - 1].split('|').next(),
+            false => None,
+        };
+
+        stripped.push_str(&rest[..start]);
+        if user_id.is_some_and(|user_id| allowed_user_ids.contains(&user_id)) {
+            stripped.push_str(mention);
+        }
+        rest = &rest[start + length + 1..];
+    }
+    stripped.push_str(rest);
+
+    // Tidy up the gaps that were left behind
+    stripped
+        .split(' ')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// What an AI message is checked against before it's posted
+#[derive(Debug)]
+pub struct MessageFilter<'a> {
+    /// The secret, which the message must not give away
+    pub secret: &'a str,
+    /// The users that can be mentioned, mentions of anyone else are removed
+    pub allowed_user_ids: Vec<&'a str>,
+    /// Words that the message must not contain
+    pub deny_list: &'a HashSet<String>,
+}
+
+impl MessageFilter<'_> {
+    /// Check that a message is safe to post, removing mentions of users that aren't allowed
+    ///
+    /// Messages that contain the secret, an inflection of it or a denied word are rejected with a
+    /// validation error, so that another message can be asked for.
+    pub fn apply(&self, message: &str) -> Result<String, SimilariumError> {
+        let secret = self.secret.to_lowercase();
+
+        for word in get_words(message) {
+            if word == secret || is_inflection_of(&word, &secret) {
+                return validation_error!("The message gives away the secret");
+            }
+            if self.deny_list.contains(&word) {
+                return validation_error!("The message contains the denied word {:?}", word);
+            }
+        }
+
+        Ok(strip_mentions(message, &self.allowed_user_ids))
+    }
+}
+
+static DENY_LIST: OnceLock<HashSet<String>> = OnceLock::new();
+
+/// Get the words that AI messages must not contain, from `LLM_DENY_LIST_PATH` if it's set
+///
+/// The list is read the first time this is called, so it should be called at startup to find out
+/// about a missing file then rather than when the first message is written.
+pub fn get_deny_list() -> Result<&'static HashSet<String>, SimilariumError> {
+    if let Some(deny_list) = DENY_LIST.get() {
+        return Ok(deny_list);
+    }
+
+    let deny_list = match &get_config().llm_deny_list_path {
+        Some(path) => {
+            log::info!("Loading AI deny list from {}", path.display());
+            read_word_list(path)?
+                .into_iter()
+                .map(|word| word.to_lowercase())
+                .collect()
+        }
+        None => HashSet::new(),
+    };
+
+    Ok(DENY_LIST.get_or_init(|| deny_list))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_mentions() {
+        assert_eq!(
+            strip_mentions("Go <@U1> and <@U2|bob>, not <@U3>!", &["U1", "U2"]),
+            "Go <@U1> and <@U2|bob>, not !"
+        );
+        assert_eq!(
+            strip_mentions("<@U1234567890> well done <@U1>", &["U1"]),
+            "well done <@U1>"
+        );
+        assert_eq!(strip_mentions("Broken <@U1", &[]), "Broken <@U1");
+    }
+
+    #[test]
+    fn test_strip_mentions_removes_group_mentions() {
+        assert_eq!(
+            strip_mentions(
+                "<!here> <!channel> look, <!subteam^S123|@team> and <@U1>!",
+                &["U1"]
+            ),
+            "look, and <@U1>!"
+        );
+        assert_eq!(strip_mentions("Hey <!everyone>", &["U1"]), "Hey");
+    }
+
+    #[test]
+    fn test_filter_rejects_the_secret() {
+        let deny_list = HashSet::new();
+        let filter = MessageFilter {
+            secret: "apple",
+            allowed_user_ids: vec![],
+            deny_list: &deny_list,
+        };
+
+        assert!(filter.apply("Think of APPLES in an orchard").is_err());
+        assert!(filter.apply("It's an apple!").is_err());
+        assert!(filter.apply("Not an application though").is_ok());
+        assert_eq!(
+            filter.apply("Think of an orchard").unwrap(),
+            "Think of an orchard"
+        );
+    }
+
+    #[test]
+    fn test_filter_rejects_irregular_forms_of_the_secret() {
+        let deny_list = HashSet::new();
+        let filter = MessageFilter {
+            secret: "mouse",
+            allowed_user_ids: vec![],
+            deny_list: &deny_list,
+        };
+
+        assert!(filter.apply("Three blind mice").is_err());
+    }
+
+    #[test]
+    fn test_filter_rejects_denied_words_and_strips_mentions() {
+        let deny_list = HashSet::from(["dumb".to_string()]);
+        let filter = MessageFilter {
+            secret: "apple",
+            allowed_user_ids: vec!["U1"],
+            deny_list: &deny_list,
+        };
+
+        assert!(filter.apply("What a dumb guess <@U1>").is_err());
+        assert_eq!(
+            filter.apply("Nice one <@U1>, and <@U9>").unwrap(),
+            "Nice one <@U1>, and"
+        );
+    }
+}
@@ -0,0 +1,403 @@
+mod filter;
+mod offline;
+mod openai;
+
+use crate::{config::get_config, SimilariumError};
+use async_openai::config::OpenAIConfig;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+pub use filter::{get_deny_list, MessageFilter};
+pub use offline::TemplateProvider;
+pub use openai::OpenAiProvider;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Message {
+    pub message: String,
+}
+
+/// What to write a message about, along with the state of the game
+///
+/// The top guesses are `(rank, word)` pairs, closest first. The templates are the prompts of the
+/// channel's persona, see `render_template`.
+#[derive(Debug)]
+pub enum Prompt<'a> {
+    /// A clue about the secret, which must not give the secret away
+    Hint {
+        guess_count: i64,
+        top_word: &'a str,
+        top_rank: i64,
+        top_guesses: Vec<(i64, &'a str)>,
+        secret: &'a str,
+        closest_words: Vec<&'a str>,
+    },
+    /// A user made the first guess in the top `bucket` words
+    Celebration {
+        template: &'a str,
+        guess_count: i64,
+        user_id: &'a str,
+        word: &'a str,
+        word_rank: i64,
+        top_guesses: Vec<(i64, &'a str)>,
+        bucket: i64,
+    },
+    /// The players are struggling to get close to the secret
+    Taunt {
+        template: &'a str,
+        guess_count: i64,
+        top_word: &'a str,
+        top_word_rank: i64,
+        participant_user_ids: Vec<String>,
+        top_guesses: Vec<(i64, &'a str)>,
+    },
+    /// A user found the secret
+    Win {
+        template: &'a str,
+        guess_count: i64,
+        user_id: &'a str,
+        top_guesses: Vec<(i64, &'a str)>,
+    },
+}
+
+impl Prompt<'_> {
+    /// The name of the kind of message, which is safe to log as it doesn't include the secret
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Prompt::Hint { .. } => "hint",
+            Prompt::Celebration { .. } => "celebration",
+            Prompt::Taunt { .. } => "taunt",
+            Prompt::Win { .. } => "win",
+        }
+    }
+}
+
+/// Fill in the placeholders of a persona's prompt template with the state of the game
+///
+/// The placeholders are `{guess_count}`, `{user}`, `{word}`, `{word_rank}`, `{bucket}`,
+/// `{top_word}`, `{top_word_rank}` and `{participants}`, each filled in for the prompts that they
+/// apply to. Any other placeholders are left as they are.
+pub fn render_template(template: &str, prompt: &Prompt<'_>) -> String {
+    let values = match prompt {
+        Prompt::Hint {
+            guess_count,
+            top_word,
+            top_rank,
+            ..
+        } => vec![
+            ("guess_count", guess_count.to_string()),
+            ("top_word", top_word.to_string()),
+            ("top_word_rank", top_rank.to_string()),
+        ],
+        Prompt::Celebration {
+            guess_count,
+            user_id,
+            word,
+            word_rank,
+            bucket,
+            ..
+        } => vec![
+            ("guess_count", guess_count.to_string()),
+            ("user", user_id.to_string()),
+            ("word", word.to_string()),
+            ("word_rank", word_rank.to_string()),
+            ("bucket", bucket.to_string()),
+        ],
+        Prompt::Taunt {
+            guess_count,
+            top_word,
+            top_word_rank,
+            participant_user_ids,
+            ..
+        } => vec![
+            ("guess_count", guess_count.to_string()),
+            ("top_word", top_word.to_string()),
+            ("top_word_rank", top_word_rank.to_string()),
+            ("participants", participant_user_ids.join(", ")),
+        ],
+        Prompt::Win {
+            guess_count,
+            user_id,
+            ..
+        } => vec![
+            ("guess_count", guess_count.to_string()),
+            ("user", user_id.to_string()),
+        ],
+    };
+
+    values
+        .iter()
+        .fold(template.to_string(), |text, (placeholder, value)| {
+            text.replace(&format!("{{{}}}", placeholder), value)
+        })
+}
+
+/// Writes the messages that the game posts, such as celebrations and taunts
+pub trait LlmProvider {
+    fn get_message(
+        &self,
+        prompt: &Prompt<'_>,
+    ) -> impl Future<Output = Result<Message, SimilariumError>>;
+}
+
+/// Which `LlmProvider` to use, set with the `LLM_PROVIDER` env variable
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum LlmProviderKind {
+    OpenAi,
+    /// An OpenAI compatible server, such as Ollama or the llama.cpp server
+    Local,
+    /// Messages from templates, without calling out to a model
+    Offline,
+}
+
+impl FromStr for LlmProviderKind {
+    type Err = SimilariumError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "openai" => Ok(LlmProviderKind::OpenAi),
+            "local" => Ok(LlmProviderKind::Local),
+            "offline" => Ok(LlmProviderKind::Offline),
+            _ => value_error!(
+                "Unknown LLM provider {:?}, expected \"openai\", \"local\" or \"offline\"",
+                value
+            ),
+        }
+    }
+}
+
+/// The configured `LlmProvider`, which is cheap to clone
+#[derive(Debug, Clone)]
+pub enum LlmEngine {
+    OpenAi(Box<OpenAiProvider>),
+    Offline(TemplateProvider),
+}
+
+impl LlmProvider for LlmEngine {
+    async fn get_message(&self, prompt: &Prompt<'_>) -> Result<Message, SimilariumError> {
+        match self {
+            LlmEngine::OpenAi(provider) => provider.get_message(prompt).await,
+            LlmEngine::Offline(provider) => provider.get_message(prompt).await,
+        }
+    }
+}
+
+/// Get a message from the provider that passes the filter, trying again if it fails, and falling
+/// back to a message from a template once all the attempts have failed
+///
+/// The template's message goes through the filter as well, as it can include a guess that gives
+/// away the secret, in which case there's no message to post.
+pub async fn get_message_or_fallback(
+    llm: &impl LlmProvider,
+    prompt: &Prompt<'_>,
+    filter: &MessageFilter<'_>,
+    attempts: u32,
+) -> Option<Message> {
+    for attempt in 1..=attempts {
+        let message = llm
+            .get_message(prompt)
+            .await
+            .and_then(|message| filter.apply(&message.message));
+        match message {
+            Ok(message) => return Some(Message { message }),
+            Err(e) => log::warn!(
+                "Unable to get a message from the model, attempt {}/{}: {:?}",
+                attempt,
+                attempts,
+                e
+            ),
+        }
+    }
+
+    match filter.apply(&TemplateProvider.get_message_text(prompt)) {
+        Ok(message) => Some(Message { message }),
+        Err(e) => {
+            log::warn!("Unable to use the template message either: {:?}", e);
+            None
+        }
+    }
+}
+
+static LLM_ENGINE: OnceLock<LlmEngine> = OnceLock::new();
+
+/// Get the configured LLM engine
+pub fn get_llm_engine() -> Result<&'static LlmEngine, SimilariumError> {
+    if let Some(engine) = LLM_ENGINE.get() {
+        return Ok(engine);
+    }
+
+    let config = get_config();
+    let engine = match config.llm_provider {
+        LlmProviderKind::OpenAi => LlmEngine::OpenAi(Box::new(OpenAiProvider::new(
+            // Reads the key from OPENAI_API_KEY
+            OpenAIConfig::default(),
+            &config.llm_model,
+            config.llm_temperature,
+            config.llm_timeout,
+        ))),
+        LlmProviderKind::Local => {
+            let Some(base_url) = &config.llm_base_url else {
+                return value_error!("LLM_BASE_URL needs to be set for the local LLM provider");
+            };
+            let openai_config = OpenAIConfig::new()
+                .with_api_base(base_url)
+                .with_api_key(config.llm_api_key.clone().unwrap_or_default());
+            LlmEngine::OpenAi(Box::new(OpenAiProvider::new(
+                openai_config,
+                &config.llm_model,
+                config.llm_temperature,
+                config.llm_timeout,
+            )))
+        }
+        LlmProviderKind::Offline => LlmEngine::Offline(TemplateProvider),
+    };
+
+    Ok(LLM_ENGINE.get_or_init(|| engine))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn test_parse_llm_provider_kind() {
+        assert_eq!(
+            "openai".parse::<LlmProviderKind>().unwrap(),
+            LlmProviderKind::OpenAi
+        );
+        assert_eq!(
+            "Local".parse::<LlmProviderKind>().unwrap(),
+            LlmProviderKind::Local
+        );
+        assert_eq!(
+            "offline".parse::<LlmProviderKind>().unwrap(),
+            LlmProviderKind::Offline
+        );
+        assert!("claude".parse::<LlmProviderKind>().is_err());
+    }
+
+    #[test]
+    fn test_render_template() {
+        let prompt = Prompt::Taunt {
+            template: "",
+            guess_count: 50,
+            top_word: "pear",
+            top_word_rank: 1500,
+            participant_user_ids: vec!["U1".to_string(), "U2".to_string()],
+            top_guesses: vec![(1500, "pear")],
+        };
+
+        assert_eq!(
+            render_template(
+                "{guess_count} guesses, best {top_word} at {top_word_rank} by {participants}",
+                &prompt
+            ),
+            "50 guesses, best pear at 1500 by U1, U2"
+        );
+        // Placeholders that don't apply to the prompt are left alone
+        assert_eq!(
+            render_template("{user} {bucket}", &prompt),
+            "{user} {bucket}"
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_get_message_or_fallback_falls_back_when_the_secret_is_given_away() {
+        let llm = FlakyProvider {
+            failures: AtomicU32::new(0),
+        };
+        let deny_list = HashSet::new();
+
+        // The model always writes "From the model", which gives away the secret "model"
+        let message =
+            get_message_or_fallback(&llm, &get_win_prompt(), &get_filter("model", &deny_list), 3)
+                .await
+                .unwrap();
+        assert_eq!(
+            message.message,
+            TemplateProvider.get_message_text(&get_win_prompt())
+        );
+    }
+
+    /// A provider that fails a number of times before it starts writing messages
+    struct FlakyProvider {
+        failures: AtomicU32,
+    }
+
+    impl LlmProvider for FlakyProvider {
+        async fn get_message(&self, _prompt: &Prompt<'_>) -> Result<Message, SimilariumError> {
+            if self.failures.load(Ordering::SeqCst) > 0 {
+                self.failures.fetch_sub(1, Ordering::SeqCst);
+                return ai_error!("Model did not respond");
+            }
+            Ok(Message {
+                message: "From the model".to_string(),
+            })
+        }
+    }
+
+    fn get_win_prompt() -> Prompt<'static> {
+        Prompt::Win {
+            template: "{user} won",
+            guess_count: 3,
+            user_id: "U123",
+            top_guesses: vec![],
+        }
+    }
+
+    fn get_filter<'a>(secret: &'a str, deny_list: &'a HashSet<String>) -> MessageFilter<'a> {
+        MessageFilter {
+            secret,
+            allowed_user_ids: vec!["U123"],
+            deny_list,
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_get_message_or_fallback_retries_the_provider() {
+        let llm = FlakyProvider {
+            failures: AtomicU32::new(1),
+        };
+        let deny_list = HashSet::new();
+
+        let message =
+            get_message_or_fallback(&llm, &get_win_prompt(), &get_filter("apple", &deny_list), 2)
+                .await
+                .unwrap();
+        assert_eq!(message.message, "From the model");
+    }
+
+    #[actix_web::test]
+    async fn test_get_message_or_fallback_falls_back_to_a_template() {
+        let llm = FlakyProvider {
+            failures: AtomicU32::new(2),
+        };
+        let deny_list = HashSet::new();
+
+        let message =
+            get_message_or_fallback(&llm, &get_win_prompt(), &get_filter("apple", &deny_list), 2)
+                .await
+                .unwrap();
+        assert_eq!(
+            message.message,
+            TemplateProvider.get_message_text(&get_win_prompt())
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_get_message_or_fallback_filters_the_template() {
+        let llm = FlakyProvider {
+            failures: AtomicU32::new(2),
+        };
+        // Every win template says how many guesses it took
+        let deny_list = HashSet::from(["guesses".to_string()]);
+
+        let message =
+            get_message_or_fallback(&llm, &get_win_prompt(), &get_filter("apple", &deny_list), 2)
+                .await;
+        assert!(message.is_none());
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{
+    ai::{LlmProvider, Message, Prompt},
+    SimilariumError,
+};
+
+/// Pick one of the templates, based on the guess count so that the same game state always gets
+/// the same message
+fn pick(templates: &[&'static str], guess_count: i64) -> &'static str {
+    templates[guess_count.rem_euclid(templates.len() as i64) as usize]
+}
+
+/// Writes messages from templates, without calling out to a model
+///
+/// The messages are deterministic, which makes this useful for running without an AI service and
+/// in tests.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TemplateProvider;
+
+impl TemplateProvider {
+    pub(crate) fn get_message_text(&self, prompt: &Prompt<'_>) -> String {
+        match prompt {
+            Prompt::Hint {
+                guess_count,
+                secret,
+                ..
+            } => {
+                // Only describe the shape of the secret, never the secret itself
+                let letters = secret.chars().count();
+                let first = secret.chars().next().unwrap_or_default();
+                match guess_count.rem_euclid(2) {
+                    0 => format!("The secret has {} letters.", letters),
+                    _ => format!("The secret starts with the letter '{}'.", first),
+                }
+            }
+            Prompt::Celebration {
+                guess_count,
+                user_id,
+                word,
+                bucket,
+                ..
+            } => {
+                let celebration = pick(&["Woohoo", "Look at that", "Huzzah"], *guess_count);
+                format!(
+                    "{}! <@{}> guessed *{}*, the first guess in the top {}, after {} guesses :tada:",
+                    celebration, user_id, word, bucket, guess_count
+                )
+            }
+            Prompt::Taunt {
+                guess_count,
+                top_word,
+                top_word_rank,
+                ..
+            } => {
+                let taunt = pick(
+                    &[
+                        "The secret is still safe",
+                        "Is that the best you can do?",
+                        "The secret is getting comfortable",
+                    ],
+                    *guess_count,
+                );
+                format!(
+                    "{} After {} guesses the closest is *{}* at {} :smirk:",
+                    taunt, guess_count, top_word, top_word_rank
+                )
+            }
+            Prompt::Win {
+                guess_count,
+                user_id,
+                ..
+            } => {
+                let win = pick(
+                    &["We have a winner", "Secret found", "Well done"],
+                    *guess_count,
+                );
+                format!(
+                    "{}! <@{}> found the secret after {} guesses :trophy:",
+                    win, user_id, guess_count
+                )
+            }
+        }
+    }
+}
+
+impl LlmProvider for TemplateProvider {
+    async fn get_message(&self, prompt: &Prompt<'_>) -> Result<Message, SimilariumError> {
+        Ok(Message {
+            message: self.get_message_text(prompt),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[actix_web::test]
+    async fn test_hint_does_not_give_away_the_secret() {
+        for guess_count in 0..4 {
+            let message = TemplateProvider
+                .get_message(&Prompt::Hint {
+                    guess_count,
+                    top_word: "pear",
+                    top_rank: 3,
+                    top_guesses: vec![(3, "pear")],
+                    secret: "apple",
+                    closest_words: vec!["fruit"],
+                })
+                .await
+                .unwrap();
+
+            assert!(!message.message.contains("apple"));
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_messages_are_deterministic() {
+        let prompt = Prompt::Win {
+            template: "",
+            guess_count: 42,
+            user_id: "U123",
+            top_guesses: vec![],
+        };
+
+        let first = TemplateProvider.get_message(&prompt).await.unwrap();
+        let second = TemplateProvider.get_message(&prompt).await.unwrap();
+
+        assert_eq!(first.message, second.message);
+        assert_eq!(
+            first.message,
+            "We have a winner! <@U123> found the secret after 42 guesses :trophy:"
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_celebration_mentions_the_user() {
+        let message = TemplateProvider
+            .get_message(&Prompt::Celebration {
+                template: "",
+                guess_count: 10,
+                user_id: "U123",
+                word: "pear",
+                word_rank: 8,
+                top_guesses: vec![(8, "pear")],
+                bucket: 10,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            message.message,
+            "Look at that! <@U123> guessed *pear*, the first guess in the top 10, after 10 \
+             guesses :tada:"
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{
+    ai::{render_template, LlmProvider, Message, Prompt},
+    SimilariumError,
+};
+use async_openai::{
+    config::OpenAIConfig,
+    types::{
+        ChatCompletionRequestSystemMessageArgs, ChatCompletionResponseFormat,
+        ChatCompletionResponseFormatType, CreateChatCompletionRequestArgs,
+    },
+    Client,
+};
+use std::time::Duration;
+
+const GAME_EXPLANATION: &str = "Similarium is a secret word guessing game. Multiple players try to guess the secret word, with each guess being ranked by how close it is to the secret. Closeness to the secret is calculated with Word2Vec, to get a semantic similarity to the secret. So 'love' would be close to 'hate' for example. The closest word will rank 1, next closest and so on, all the way up to about 100 thousand.";
+const GAME_STATE: &str = "I will provide the state of the game by listing up to top 10 guesses made so far, each guess will be in its own line in the format '123 Apple', which means the word 'Apple' ranked at 123 (122 words are closer to the secret).";
+const RETURN_FORMAT: &str = "Your response should be a valid JSON object in the form of '{{\"message\": \"<the message>\"}}'.";
+const USER_ID_CLARIFICATIONS: &str = "Users will be referenced by user ids from Slack, in the form of U<digits>. Any reference you make to a user has to be the full ID, surrounded by angle brackets and prefixed with an 'at' symbol. For example, for the user U1234567890 you need to reference them only as <@U1234567890>. NOTE: U1234567890 is just an example, NEVER use that user ID";
+
+/// Format the top guesses with a guess per line, leaving out the secret itself (rank 0)
+fn get_game_state(top_guesses: &[(i64, &str)]) -> String {
+    top_guesses
+        .iter()
+        .filter(|(rank, _)| *rank != 0)
+        .map(|(rank, word)| format!("{} {}", rank, word))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Build the full prompt that's sent to the model
+fn get_prompt_text(prompt: &Prompt<'_>) -> String {
+    match prompt {
+        Prompt::Hint {
+            guess_count,
+            top_word,
+            top_rank,
+            top_guesses,
+            secret,
+            closest_words,
+        } => {
+            // Format the closest words in a string, where each word is in it's own line with the
+            // index + 1 first. So the top line would be '1 Apple' if Apple is the closest word
+            let closest_words_str = closest_words
+                .iter()
+                .enumerate()
+                .map(|(idx, word)| format!("{} {}", idx + 1, word))
+                .collect::<Vec<String>>()
+                .join("\n");
+            let hint_prompt = format!("The players have made {guess_count} guesses with the closest guess being {top_word} at {top_rank}. The secret is {secret}. You need to provide some hint to the secret *without* giving away the secret.\nThe 10 words closest to the secret are:\n{closest_words_str})");
+            let game_state = get_game_state(top_guesses);
+
+            format!(
+                "{GAME_EXPLANATION}\n{GAME_STATE}\n{game_state}\n{hint_prompt}\n{RETURN_FORMAT}"
+            )
+        }
+        Prompt::Celebration {
+            template,
+            top_guesses,
+            ..
+        }
+        | Prompt::Taunt {
+            template,
+            top_guesses,
+            ..
+        }
+        | Prompt::Win {
+            template,
+            top_guesses,
+            ..
+        } => {
+            let persona_prompt = render_template(template, prompt);
+            let game_state = get_game_state(top_guesses);
+
+            format!(
+                "{GAME_EXPLANATION}\n{GAME_STATE}\n{game_state}\n{persona_prompt}\n{RETURN_FORMAT}\n{USER_ID_CLARIFICATIONS}"
+            )
+        }
+    }
+}
+
+/// Writes messages with the chat completions API, of OpenAI or of a compatible server
+#[derive(Debug, Clone)]
+pub struct OpenAiProvider {
+    client: Client<OpenAIConfig>,
+    model: String,
+    temperature: f32,
+    timeout: Duration,
+}
+
+impl OpenAiProvider {
+    pub fn new(
+        openai_config: OpenAIConfig,
+        model: &str,
+        temperature: f32,
+        timeout: Duration,
+    ) -> Self {
+        OpenAiProvider {
+            client: Client::with_config(openai_config),
+            model: model.to_string(),
+            temperature,
+            timeout,
+        }
+    }
+}
+
+impl LlmProvider for OpenAiProvider {
+    async fn get_message(&self, prompt: &Prompt<'_>) -> Result<Message, SimilariumError> {
+        let request = CreateChatCompletionRequestArgs::default()
+            .max_tokens(512u16)
+            .model(&self.model)
+            .temperature(self.temperature)
+            .response_format(ChatCompletionResponseFormat {
+                r#type: ChatCompletionResponseFormatType::JsonObject,
+            })
+            .messages([ChatCompletionRequestSystemMessageArgs::default()
+                .content(get_prompt_text(prompt))
+                .build()?
+                .into()])
+            .build()?;
+
+        log::debug!("Asking {} for a {} message", self.model, prompt.kind());
+
+        let Ok(response) =
+            tokio::time::timeout(self.timeout, self.client.chat().create(request)).await
+        else {
+            return ai_error!("Model did not respond within {:?}", self.timeout);
+        };
+        let content = response?
+            .choices
+            .first()
+            .and_then(|choice| choice.message.content.clone())
+            .map_or_else(|| ai_error!("Unable to get response from model"), Ok)?;
+
+        Ok(serde_json::from_str(&content)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_prompt_text_leaves_out_the_secret_from_the_game_state() {
+        let text = get_prompt_text(&Prompt::Win {
+            template: "The user '{user}' just found the secret!",
+            guess_count: 42,
+            user_id: "U123",
+            top_guesses: vec![(0, "apple"), (3, "pear"), (10, "plum")],
+        });
+
+        assert!(text.starts_with(GAME_EXPLANATION));
+        assert!(text.contains(&format!("{GAME_STATE}\n3 pear\n10 plum\n")));
+        assert!(text.contains("\nThe user 'U123' just found the secret!\n"));
+        assert!(!text.contains("0 apple"));
+        assert!(text.ends_with(USER_ID_CLARIFICATIONS));
+    }
+
+    #[test]
+    fn test_get_prompt_text_lists_the_closest_words_for_a_hint() {
+        let text = get_prompt_text(&Prompt::Hint {
+            guess_count: 12,
+            top_word: "pear",
+            top_rank: 3,
+            top_guesses: vec![(3, "pear")],
+            secret: "apple",
+            closest_words: vec!["fruit", "orchard"],
+        });
+
+        assert!(text.contains("The secret is apple."));
+        assert!(text.contains("are:\n1 fruit\n2 orchard)"));
+        assert!(text.ends_with(RETURN_FORMAT));
+    }
+}
@@ -1,9 +1,13 @@
 use crate::{
+    ai::{get_deny_list, get_llm_engine},
     api::scopes,
     config::{get_config, Config},
-    db::get_pool,
-    slack_client::SlackClient,
-    workers::{ensure_recurring_tasks, start_workers},
+    db::{get_pool, run_migrations},
+    game::updates::GameMessageUpdater,
+    similarity::{get_similarity_engine, SimilarityEngine},
+    slack_client::{RetryPolicy, SlackClient},
+    suggestions::{get_suggestion_index, SuggestionIndex},
+    workers::{connect_queue, ensure_recurring_tasks, start_workers},
     SimilariumError,
 };
 use actix_web::{error, middleware::Logger, web, App, HttpRequest, HttpResponse, HttpServer};
@@ -21,18 +25,28 @@ async fn not_found(request: HttpRequest, text: String) -> HttpResponse {
 
 pub struct AppState {
     pub db: sqlx::PgPool,
+    pub similarity: SimilarityEngine,
+    pub suggestions: Arc<SuggestionIndex>,
     pub config: Config,
     pub slack_client: SlackClient,
     pub queue: Arc<Mutex<AsyncQueue<NoTls>>>,
+    pub game_updater: Arc<GameMessageUpdater>,
 }
 
-pub async fn run() -> Result<(), SimilariumError> {
-    env_logger::init_from_env(env_logger::Env::new().default_filter_or("info"));
+/// Run the API server
+///
+/// The background workers are started in the same process, unless `with_workers` is false, in
+/// which case they are expected to be running separately with the `worker` command.
+pub async fn run(with_workers: bool) -> Result<(), SimilariumError> {
     let config = get_config();
 
-    log::info!("Running migrations");
     let pool = get_pool();
-    sqlx::migrate!("./migrations").run(pool).await?;
+    run_migrations(pool).await?;
+    let similarity = get_similarity_engine().await?;
+    // Check the LLM provider is configured before taking any requests
+    get_llm_engine()?;
+    get_deny_list()?;
+    let suggestions = get_suggestion_index().await?;
 
     let json_cfg = web::JsonConfig::default()
         .limit(4096)
@@ -42,14 +56,19 @@ pub async fn run() -> Result<(), SimilariumError> {
             error::InternalError::from_response(err, HttpResponse::Conflict().into()).into()
         });
 
-    let queue = start_workers(
-        &config.database_url,
-        config.worker_count,
-        config.worker_max_pool_size,
-    )
-    .await?;
+    let queue = connect_queue(&config.database_url, config.worker_max_pool_size).await?;
+
+    if with_workers {
+        start_workers(&queue, config.worker_count).await?;
+        ensure_recurring_tasks(queue.clone()).await?;
+    }
 
-    ensure_recurring_tasks(queue.clone()).await?;
+    let game_updater = Arc::new(GameMessageUpdater::new(
+        config.game_update_window,
+        pool.clone(),
+        SlackClient::default(),
+        queue.clone(),
+    ));
 
     log::info!("Starting server on {}:{}", config.host, config.port);
     let bind_tuple = (config.host.clone(), config.port);
@@ -60,9 +79,13 @@ pub async fn run() -> Result<(), SimilariumError> {
             .app_data(json_cfg.clone())
             .app_data(web::Data::new(AppState {
                 db: pool.clone(),
+                similarity: similarity.clone(),
+                suggestions: suggestions.clone(),
                 config: config.clone(),
-                slack_client: SlackClient::default(),
+                // Interactions have to be answered within Slack's 3 second deadline
+                slack_client: SlackClient::default().with_retry_policy(RetryPolicy::interactive()),
                 queue: Arc::new(Mutex::new(queue.clone())),
+                game_updater: game_updater.clone(),
             }))
             .configure(scopes::config)
             .default_service(web::get().to(not_found))
@@ -0,0 +1,249 @@
+use crate::SimilariumError;
+use actix_web::{
+    body::{EitherBody, MessageBody},
+    dev::{forward_ready, Payload, Service, ServiceRequest, ServiceResponse, Transform},
+    web, Error,
+};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::future::{ready, Future, Ready};
+use std::pin::Pin;
+use std::rc::Rc;
+
+const SIGNATURE_HEADER: &str = "X-Slack-Signature";
+const TIMESTAMP_HEADER: &str = "X-Slack-Request-Timestamp";
+const SIGNATURE_VERSION: &str = "v0";
+
+/// How old, in seconds, a request can be before it's rejected as a possible replay
+const MAX_REQUEST_AGE: i64 = 60 * 5;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Get the HMAC-SHA256 of the parts of a message, which are signed one after another
+pub(crate) fn get_mac(secret: &str, parts: &[&[u8]]) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key size");
+    for part in parts {
+        mac.update(part);
+    }
+    mac
+}
+
+fn get_slack_mac(signing_secret: &str, timestamp: &str, body: &[u8]) -> HmacSha256 {
+    let base = format!("{SIGNATURE_VERSION}:{timestamp}:");
+    get_mac(signing_secret, &[base.as_bytes(), body])
+}
+
+/// Compute the Slack signature of a request body, in the form of `v0=<hex digest>`
+///
+/// See https://api.slack.com/authentication/verifying-requests-from-slack
+pub fn compute_slack_signature(signing_secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mac = get_slack_mac(signing_secret, &timestamp.to_string(), body);
+    format!(
+        "{SIGNATURE_VERSION}={}",
+        hex::encode(mac.finalize().into_bytes())
+    )
+}
+
+/// Verify that a request was signed by Slack, and that it's recent enough to not be a replay
+pub fn verify_slack_signature(
+    signing_secret: &str,
+    timestamp: Option<&str>,
+    signature: Option<&str>,
+    body: &[u8],
+    now: i64,
+) -> Result<(), SimilariumError> {
+    let (timestamp, signature) = match (timestamp, signature) {
+        (Some(timestamp), Some(signature)) => (timestamp, signature),
+        _ => return unauthorized_error!("Missing Slack signature headers"),
+    };
+
+    let request_time = match timestamp.parse::<i64>() {
+        Ok(request_time) => request_time,
+        Err(_) => return unauthorized_error!("Invalid Slack request timestamp"),
+    };
+    if (now - request_time).abs() > MAX_REQUEST_AGE {
+        return unauthorized_error!("Slack request timestamp is too old");
+    }
+
+    let digest = match signature
+        .strip_prefix(&format!("{SIGNATURE_VERSION}="))
+        .and_then(|digest| hex::decode(digest).ok())
+    {
+        Some(digest) => digest,
+        None => return unauthorized_error!("Invalid Slack signature"),
+    };
+
+    // verify_slice does a constant time comparison
+    match get_slack_mac(signing_secret, timestamp, body).verify_slice(&digest) {
+        Ok(_) => Ok(()),
+        Err(_) => unauthorized_error!("Invalid Slack signature"),
+    }
+}
+
+/// Middleware that rejects any request that isn't signed with the Slack signing secret
+pub struct VerifySlackSignature {
+    signing_secret: Rc<String>,
+}
+
+impl VerifySlackSignature {
+    pub fn new(signing_secret: &str) -> Self {
+        VerifySlackSignature {
+            signing_secret: Rc::new(signing_secret.to_string()),
+        }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for VerifySlackSignature
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = VerifySlackSignatureMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(VerifySlackSignatureMiddleware {
+            service: Rc::new(service),
+            signing_secret: self.signing_secret.clone(),
+        }))
+    }
+}
+
+pub struct VerifySlackSignatureMiddleware<S> {
+    service: Rc<S>,
+    signing_secret: Rc<String>,
+}
+
+impl<S, B> Service<ServiceRequest> for VerifySlackSignatureMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: MessageBody + 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    #[allow(clippy::type_complexity)]
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+    forward_ready!(service);
+
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        let signing_secret = self.signing_secret.clone();
+
+        Box::pin(async move {
+            // The body has to be read in full to verify it, and then put back for the handler
+            let body = req.extract::<web::Bytes>().await?;
+
+            let header = |name: &str| {
+                req.headers()
+                    .get(name)
+                    .and_then(|value| value.to_str().ok())
+                    .map(|value| value.to_string())
+            };
+            if let Err(e) = verify_slack_signature(
+                &signing_secret,
+                header(TIMESTAMP_HEADER).as_deref(),
+                header(SIGNATURE_HEADER).as_deref(),
+                &body,
+                chrono::Utc::now().timestamp(),
+            ) {
+                log::warn!(
+                    "Rejecting request to {}: {}",
+                    req.path(),
+                    e.message.as_deref().unwrap_or_default()
+                );
+                return Ok(req.error_response(e).map_into_right_body());
+            }
+
+            let (_, mut payload) = actix_http::h1::Payload::create(true);
+            payload.unread_data(body);
+            req.set_payload(Payload::from(payload));
+
+            let res = service.call(req).await?;
+            Ok(res.map_into_left_body())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SimilariumErrorType;
+
+    const SECRET: &str = "8f742231b10e8888abcd99yyyzzz85a5";
+
+    #[test]
+    fn test_compute_slack_signature() {
+        // Example from the Slack documentation
+        let body = "token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J&team_domain=testteamnow&channel_id=G8PSS9T3V&channel_name=foobar&user_id=U2CERLKJA&user_name=roadrunner&command=%2Fwebhook-collect&text=&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1DC2JH3J%2F397700885554%2F96rGlfmibIGlgcZRskXaIFfN&trigger_id=398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c";
+
+        assert_eq!(
+            compute_slack_signature(SECRET, 1531420618, body.as_bytes()),
+            "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503"
+        );
+    }
+
+    #[test]
+    fn test_verify_slack_signature_accepts_valid_signature() {
+        let signature = compute_slack_signature(SECRET, 1000, b"body");
+
+        assert!(
+            verify_slack_signature(SECRET, Some("1000"), Some(&signature), b"body", 1010).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_verify_slack_signature_rejects_tampered_body() {
+        let signature = compute_slack_signature(SECRET, 1000, b"body");
+
+        assert_eq!(
+            verify_slack_signature(SECRET, Some("1000"), Some(&signature), b"b0dy", 1000)
+                .unwrap_err()
+                .error_type,
+            SimilariumErrorType::Unauthorized
+        );
+    }
+
+    #[test]
+    fn test_verify_slack_signature_rejects_wrong_secret() {
+        let signature = compute_slack_signature("not-the-secret", 1000, b"body");
+
+        assert!(
+            verify_slack_signature(SECRET, Some("1000"), Some(&signature), b"body", 1000).is_err()
+        );
+    }
+
+    #[test]
+    fn test_verify_slack_signature_rejects_replays() {
+        let signature = compute_slack_signature(SECRET, 1000, b"body");
+
+        assert_eq!(
+            verify_slack_signature(SECRET, Some("1000"), Some(&signature), b"body", 1301),
+            unauthorized_error!("Slack request timestamp is too old")
+        );
+        assert_eq!(
+            verify_slack_signature(SECRET, Some("1000"), Some(&signature), b"body", 699),
+            unauthorized_error!("Slack request timestamp is too old")
+        );
+    }
+
+    #[test]
+    fn test_verify_slack_signature_rejects_missing_or_malformed_headers() {
+        let signature = compute_slack_signature(SECRET, 1000, b"body");
+
+        assert!(verify_slack_signature(SECRET, None, Some(&signature), b"body", 1000).is_err());
+        assert!(verify_slack_signature(SECRET, Some("1000"), None, b"body", 1000).is_err());
+        assert!(
+            verify_slack_signature(SECRET, Some("soon"), Some(&signature), b"body", 1000).is_err()
+        );
+        assert!(
+            verify_slack_signature(SECRET, Some("1000"), Some("v1=abc"), b"body", 1000).is_err()
+        );
+        assert!(
+            verify_slack_signature(SECRET, Some("1000"), Some("v0=nothex"), b"body", 1000).is_err()
+        );
+    }
+}
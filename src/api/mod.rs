@@ -1,5 +1,8 @@
 mod app;
+mod middleware;
+mod oauth;
 mod scopes;
 mod utils;
 
 pub use app::{run, AppState};
+pub use middleware::{compute_slack_signature, verify_slack_signature, VerifySlackSignature};
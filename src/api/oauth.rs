@@ -0,0 +1,161 @@
+use crate::{api::middleware::get_mac, SimilariumError};
+use hmac::Mac;
+use rand::Rng;
+use reqwest::Url;
+
+const AUTHORIZE_URL: &str = "https://slack.com/oauth/v2/authorize";
+
+/// The bot scopes that the app asks for when it's installed
+pub const BOT_SCOPES: &str = "chat:write,commands,users:read";
+
+/// How old, in seconds, the state of an install can be before it's rejected
+pub const MAX_STATE_AGE: i64 = 60 * 10;
+
+/// Sign the `state` that's passed through the OAuth flow, in the form of
+/// `<timestamp>.<nonce>.<hex digest>`
+///
+/// The state is checked when Slack redirects back, so that only installs that were started
+/// from `/auth/install` are completed.
+pub fn sign_install_state(secret: &str, now: i64) -> String {
+    let nonce = hex::encode(rand::thread_rng().gen::<[u8; 16]>());
+    let payload = format!("{}.{}", now, nonce);
+    let digest = hex::encode(
+        get_mac(secret, &[payload.as_bytes()])
+            .finalize()
+            .into_bytes(),
+    );
+
+    format!("{}.{}", payload, digest)
+}
+
+/// Get the nonce of a signed `state`, which is also kept in a cookie on the browser that started
+/// the install
+pub fn get_state_nonce(state: &str) -> Option<&str> {
+    let mut parts = state.split('.');
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(_), Some(nonce), Some(_), None) => Some(nonce),
+        _ => None,
+    }
+}
+
+/// Verify that the `state` was signed by us, that the install was started recently, and that it
+/// was started by the same browser, which has the state's nonce in a cookie
+///
+/// Checking the nonce stops someone from sending their own install link to somebody else, and
+/// having the app installed on a workspace that they didn't mean to.
+pub fn verify_install_state(
+    secret: &str,
+    state: &str,
+    cookie_nonce: Option<&str>,
+    now: i64,
+) -> Result<(), SimilariumError> {
+    let Some((payload, digest)) = state.rsplit_once('.') else {
+        return unauthorized_error!("Invalid install state");
+    };
+    let Some((timestamp, nonce)) = payload
+        .split_once('.')
+        .and_then(|(timestamp, nonce)| Some((timestamp.parse::<i64>().ok()?, nonce)))
+    else {
+        return unauthorized_error!("Invalid install state");
+    };
+    if now - timestamp > MAX_STATE_AGE || timestamp > now {
+        return unauthorized_error!("Install state has expired");
+    }
+    if cookie_nonce != Some(nonce) {
+        return unauthorized_error!("Install was started from another browser");
+    }
+    let Ok(digest) = hex::decode(digest) else {
+        return unauthorized_error!("Invalid install state");
+    };
+
+    // verify_slice does a constant time comparison
+    match get_mac(secret, &[payload.as_bytes()]).verify_slice(&digest) {
+        Ok(_) => Ok(()),
+        Err(_) => unauthorized_error!("Invalid install state"),
+    }
+}
+
+/// Get the URL that starts installing the app on a workspace
+pub fn get_authorize_url(client_id: &str, state: &str) -> Url {
+    Url::parse_with_params(
+        AUTHORIZE_URL,
+        &[
+            ("client_id", client_id),
+            ("scope", BOT_SCOPES),
+            ("state", state),
+        ],
+    )
+    .expect("The authorize URL is valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SimilariumErrorType;
+
+    const SECRET: &str = "client_secret";
+    const NOW: i64 = 1_700_000_000;
+
+    #[test]
+    fn test_verify_install_state_accepts_signed_state() {
+        let state = sign_install_state(SECRET, NOW);
+        let nonce = get_state_nonce(&state);
+
+        assert!(nonce.is_some_and(|nonce| nonce.len() == 32));
+        assert!(verify_install_state(SECRET, &state, nonce, NOW + 60).is_ok());
+    }
+
+    #[test]
+    fn test_verify_install_state_rejects_other_secret() {
+        let state = sign_install_state("other_secret", NOW);
+
+        assert_eq!(
+            verify_install_state(SECRET, &state, get_state_nonce(&state), NOW)
+                .unwrap_err()
+                .error_type,
+            SimilariumErrorType::Unauthorized
+        );
+    }
+
+    #[test]
+    fn test_verify_install_state_rejects_expired_state() {
+        let state = sign_install_state(SECRET, NOW);
+        let nonce = get_state_nonce(&state);
+
+        assert!(verify_install_state(SECRET, &state, nonce, NOW + MAX_STATE_AGE).is_ok());
+        assert!(verify_install_state(SECRET, &state, nonce, NOW + MAX_STATE_AGE + 1).is_err());
+    }
+
+    #[test]
+    fn test_verify_install_state_rejects_tampered_state() {
+        let state = sign_install_state(SECRET, NOW);
+        let nonce = get_state_nonce(&state);
+        let (_, rest) = state.split_once('.').unwrap();
+        let tampered = format!("{}.{}", NOW + 100, rest);
+
+        assert!(verify_install_state(SECRET, &tampered, nonce, NOW + 100).is_err());
+        assert!(verify_install_state(SECRET, "garbage", None, NOW).is_err());
+        assert!(verify_install_state(SECRET, "", None, NOW).is_err());
+        assert_eq!(get_state_nonce("garbage"), None);
+    }
+
+    #[test]
+    fn test_verify_install_state_rejects_other_browsers() {
+        let state = sign_install_state(SECRET, NOW);
+        let other_state = sign_install_state(SECRET, NOW);
+
+        assert!(verify_install_state(SECRET, &state, None, NOW).is_err());
+        assert!(verify_install_state(SECRET, &state, get_state_nonce(&other_state), NOW).is_err());
+    }
+
+    #[test]
+    fn test_get_authorize_url() {
+        let url = get_authorize_url("client_id", "state.x");
+
+        assert_eq!(
+            url.as_str(),
+            "https://slack.com/oauth/v2/authorize?client_id=client_id\
+             &scope=chat%3Awrite%2Ccommands%2Cusers%3Aread&state=state.x"
+        );
+    }
+}
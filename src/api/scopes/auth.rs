@@ -1,19 +1,95 @@
-use crate::{api::app::AppState, models::SlackBot, slack_client::SlackOAuth, SimilariumError};
-use actix_web::{get, web, HttpResponse, Scope};
+use crate::{
+    api::{
+        app::AppState,
+        oauth::{
+            get_authorize_url, get_state_nonce, sign_install_state, verify_install_state,
+            MAX_STATE_AGE,
+        },
+    },
+    models::SlackBot,
+    slack_client::{responses::SlackOAuthResponse, SlackOAuth},
+    SimilariumError,
+};
+use actix_web::{
+    cookie::{time, Cookie, SameSite},
+    get,
+    http::StatusCode,
+    web, HttpRequest, HttpResponse, Scope,
+};
 use serde::Deserialize;
 
+/// The cookie that ties an install to the browser that started it, holding the state's nonce
+const INSTALL_NONCE_COOKIE: &str = "similarium_install_nonce";
+
+/// Get the cookie holding the nonce of an install, which only lives as long as the install state
+///
+/// It's `SameSite=Lax` so that the browser still sends it when Slack redirects back.
+fn get_nonce_cookie(nonce: &str) -> Cookie<'_> {
+    Cookie::build(INSTALL_NONCE_COOKIE, nonce)
+        .path("/auth")
+        .http_only(true)
+        .secure(true)
+        .same_site(SameSite::Lax)
+        .max_age(time::Duration::seconds(MAX_STATE_AGE))
+        .finish()
+}
+
 #[derive(Deserialize, Debug)]
 struct OAuthRedirect {
-    code: String,
+    code: Option<String>,
+    state: Option<String>,
+    error: Option<String>,
 }
 
-#[get("/oauth_redirect")]
-async fn get_oauth_redirect(
-    info: web::Query<OAuthRedirect>,
-    app_state: web::Data<AppState>,
-) -> Result<HttpResponse, SimilariumError> {
-    let code = &info.code;
+/// Escape text that's shown on a page, as the team name comes from Slack
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// The page that the install finishes on, with the title and message already escaped
+fn get_install_page(status: StatusCode, title: &str, message: &str) -> HttpResponse {
+    HttpResponse::build(status)
+        .content_type("text/html; charset=utf-8")
+        .body(format!(
+            "<!DOCTYPE html>\n\
+             <html lang=\"en\">\n\
+             <head><meta charset=\"utf-8\"><title>Similarium - {title}</title></head>\n\
+             <body style=\"font-family: sans-serif; max-width: 40em; margin: 4em auto\">\n\
+             <h1>{title}</h1>\n\
+             <p>{message}</p>\n\
+             </body>\n\
+             </html>\n"
+        ))
+}
 
+fn get_failure_page(status: StatusCode, message: &str) -> HttpResponse {
+    get_install_page(status, "Installation failed", message)
+}
+
+/// Start installing the app by sending the user to Slack
+#[get("/install")]
+async fn get_install(app_state: web::Data<AppState>) -> HttpResponse {
+    let state = sign_install_state(
+        &app_state.config.slack_client_secret,
+        chrono::Utc::now().timestamp(),
+    );
+    let url = get_authorize_url(&app_state.config.slack_client_id, &state);
+
+    HttpResponse::Found()
+        .append_header(("Location", url.as_str()))
+        .cookie(get_nonce_cookie(
+            get_state_nonce(&state).expect("Signed state has a nonce"),
+        ))
+        .finish()
+}
+
+/// Exchange the code for a token and store the install, returning the name of the team, or of
+/// the org for org-wide installs on Enterprise Grid
+async fn install(code: &str, app_state: &AppState) -> Result<Option<String>, SimilariumError> {
     let payload = app_state
         .slack_client
         .post_oauth_code(
@@ -23,28 +99,165 @@ async fn get_oauth_redirect(
         )
         .await?;
 
+    let SlackOAuthResponse {
+        ok: true,
+        access_token: Some(access_token),
+        ..
+    } = payload
+    else {
+        return slack_api_error!("Error exchanging OAuth code: {:?}", payload.error);
+    };
+    if payload.team.is_none() && payload.enterprise.is_none() {
+        return slack_api_error!("OAuth response has neither a team nor an enterprise");
+    }
+
+    let now = chrono::Utc::now();
+    let (team_id, team_name) = payload
+        .team
+        .map_or((None, None), |team| (Some(team.id), team.name));
+    let (enterprise_id, enterprise_name) = payload.enterprise.map_or((None, None), |enterprise| {
+        (Some(enterprise.id), enterprise.name)
+    });
+    let name = match payload.is_enterprise_install {
+        true => enterprise_name.clone(),
+        false => team_name.clone(),
+    };
+
     let slack_bot = SlackBot {
         id: uuid::Uuid::new_v4(),
         app_id: payload.app_id,
-        team_id: payload.team.id.clone(),
-        team_name: payload.team.name.clone(),
-        bot_token: payload.access_token,
+        enterprise_id,
+        enterprise_name,
+        team_id,
+        team_name,
+        bot_token: Some(access_token),
+        bot_id: None,
         bot_user_id: payload.bot_user_id,
         bot_scopes: payload.scope,
+        bot_refresh_token: payload.refresh_token,
+        bot_token_expires_at: payload
+            .expires_in
+            .map(|expires_in| now + chrono::Duration::seconds(expires_in)),
         is_enterprise_install: payload.is_enterprise_install,
-        installed_at: chrono::Utc::now(),
-        bot_id: None,
-        bot_refresh_token: None,
-        bot_token_expires_at: None,
-        enterprise_id: None,
-        enterprise_name: None,
+        installed_at: now,
+        revoked_at: None,
     };
-    slack_bot.insert(&app_state.db).await?;
+    slack_bot.upsert(&app_state.db).await?;
+
+    Ok(name)
+}
+
+/// Where Slack sends the user back to once they've allowed, or cancelled, the install
+#[get("/oauth_redirect")]
+async fn get_oauth_redirect(
+    request: HttpRequest,
+    info: web::Query<OAuthRedirect>,
+    app_state: web::Data<AppState>,
+) -> HttpResponse {
+    let cookie = request.cookie(INSTALL_NONCE_COOKIE);
+    let mut response = finish_install(&info, cookie.as_ref().map(Cookie::value), &app_state).await;
 
-    // Redirect the user
-    Ok(HttpResponse::Ok().body("Auth, sweet auth!"))
+    // Each nonce is only good for one attempt at installing
+    if let Err(e) = response.add_removal_cookie(&get_nonce_cookie("")) {
+        log::error!("Unable to clear the install cookie: {}", e);
+    }
+    response
+}
+
+/// Complete the install that Slack redirected back with, returning the page to show
+async fn finish_install(
+    info: &OAuthRedirect,
+    cookie_nonce: Option<&str>,
+    app_state: &AppState,
+) -> HttpResponse {
+    match info.error.as_deref() {
+        Some("access_denied") => {
+            return get_install_page(
+                StatusCode::OK,
+                "Installation cancelled",
+                "Similarium was not installed. You can close this page.",
+            )
+        }
+        Some(error) => {
+            log::warn!("Slack returned an error during install: {}", error);
+            return get_failure_page(
+                StatusCode::BAD_REQUEST,
+                "Slack could not complete the installation, please try again.",
+            );
+        }
+        None => (),
+    }
+
+    let (Some(code), Some(state)) = (&info.code, &info.state) else {
+        return get_failure_page(
+            StatusCode::BAD_REQUEST,
+            "The installation link is incomplete, please start the installation again.",
+        );
+    };
+
+    if let Err(e) = verify_install_state(
+        &app_state.config.slack_client_secret,
+        state,
+        cookie_nonce,
+        chrono::Utc::now().timestamp(),
+    ) {
+        log::warn!("Rejected install: {:?}", e.message);
+        return get_failure_page(
+            StatusCode::BAD_REQUEST,
+            "The installation link has expired or is invalid, please start the installation \
+             again.",
+        );
+    }
+
+    match install(code, app_state).await {
+        Ok(team_name) => {
+            let team = team_name.map_or("your workspace".to_string(), |name| escape_html(&name));
+            get_install_page(
+                StatusCode::OK,
+                "Similarium installed",
+                &format!(
+                    "Similarium has been installed on {}. Start a daily game in any channel \
+                     with <code>/similarium start</code>.",
+                    team
+                ),
+            )
+        }
+        Err(e) => {
+            log::error!("Error installing: {:?}", e);
+            get_failure_page(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Something went wrong while installing Similarium, please try again.",
+            )
+        }
+    }
 }
 
 pub fn scope() -> Scope {
-    web::scope("/auth").service(get_oauth_redirect)
+    web::scope("/auth")
+        .service(get_install)
+        .service(get_oauth_redirect)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(
+            escape_html("<Tom & Jerry's \"team\">"),
+            "&lt;Tom &amp; Jerry&#39;s &quot;team&quot;&gt;"
+        );
+    }
+
+    #[test]
+    fn test_get_nonce_cookie() {
+        let cookie = get_nonce_cookie("abc123");
+
+        assert_eq!(
+            cookie.to_string(),
+            "similarium_install_nonce=abc123; HttpOnly; SameSite=Lax; Secure; Path=/auth; \
+             Max-Age=600"
+        );
+    }
 }
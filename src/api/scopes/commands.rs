@@ -7,12 +7,20 @@ use crate::{
         utils::{parse_command, Command},
     },
     game::{
-        end_game, get_active_games_on_channel, manual_start, schedule_game_on_channel,
-        stop_games_on_channel, utils::get_help_blocks,
+        end_game, get_active_games_on_channel,
+        leaderboard::{get_leaderboard, get_leaderboard_blocks},
+        manual_start,
+        personas::{get_current_persona, get_personas_blocks, use_persona},
+        request_hint, schedule_game_on_channel,
+        secret_pools::{get_secret_pools_blocks, use_secret_pool},
+        stats::{get_channel_stats_blocks, get_player_stats, get_player_stats_blocks},
+        stop_games_on_channel,
+        utils::get_help_blocks,
     },
-    models::SlackBot,
+    models::{Channel, GameWinnerAssociation, Persona, SecretPool, SlackBot, DEFAULT_PERSONA},
     payloads::CommandPayload,
-    SimilariumError,
+    utils::get_or_create_user,
+    SimilariumError, SimilariumErrorType,
 };
 
 #[post("/similarium")]
@@ -21,7 +29,19 @@ async fn post_similarium_command(
     app_state: web::Data<AppState>,
 ) -> Result<HttpResponse, SimilariumError> {
     let payload = form.into_inner();
-    let token = SlackBot::get_slack_bot_token(&payload.team_id, &app_state.db).await?;
+    // A channel shared across an org is served by the install it was started with, whichever
+    // workspace the command came from. Until the first `start` there's no channel to go by
+    let token = match Channel::get(&payload.channel_id, &app_state.db).await? {
+        Some(channel) => channel.get_slack_bot_token(&app_state.db).await?,
+        None => {
+            SlackBot::get_slack_bot_token(
+                payload.enterprise_id.as_deref(),
+                &payload.team_id,
+                &app_state.db,
+            )
+            .await?
+        }
+    };
     let command = match parse_command(&payload.text) {
         Ok(command) => command,
         Err(e) => {
@@ -95,10 +115,222 @@ async fn post_similarium_command(
                 }
             }
         }
+        Command::Hint => {
+            let active_games =
+                get_active_games_on_channel(&app_state.db, &payload.channel_id).await?;
+            match active_games.last() {
+                Some(game) => {
+                    get_or_create_user(
+                        &payload.user_id,
+                        &token,
+                        &app_state.db,
+                        &app_state.slack_client,
+                    )
+                    .await?;
+                    let mut queue = app_state.queue.lock().await.clone();
+                    request_hint(
+                        &app_state.db,
+                        &app_state.similarity,
+                        &app_state.slack_client,
+                        &mut queue,
+                        game,
+                        &payload.user_id,
+                        &token,
+                    )
+                    .await?;
+                }
+                None => {
+                    app_state
+                        .slack_client
+                        .post_ephemeral(
+                            ":no_entry_sign: There's no game running on this channel",
+                            &payload.channel_id,
+                            &payload.user_id,
+                            &token,
+                            None,
+                        )
+                        .await?;
+                }
+            }
+        }
+        Command::Stats(user_id) => {
+            let user_id = user_id.unwrap_or(payload.user_id.clone());
+            let stats = get_player_stats(&payload.channel_id, &user_id, &app_state.db).await?;
+            app_state
+                .slack_client
+                .post_ephemeral(
+                    "Player stats",
+                    &payload.channel_id,
+                    &payload.user_id,
+                    &token,
+                    Some(get_player_stats_blocks(&user_id, &stats)),
+                )
+                .await?;
+        }
+        Command::ChannelStats => {
+            let solvers = GameWinnerAssociation::get_top_solvers(
+                &payload.channel_id,
+                None,
+                10,
+                &app_state.db,
+            )
+            .await?;
+            app_state
+                .slack_client
+                .post_ephemeral(
+                    "Top solvers",
+                    &payload.channel_id,
+                    &payload.user_id,
+                    &token,
+                    Some(get_channel_stats_blocks(&solvers)),
+                )
+                .await?;
+        }
+        Command::Leaderboard(period) => {
+            let solvers = get_leaderboard(
+                &payload.channel_id,
+                period,
+                chrono::Utc::now(),
+                &app_state.db,
+            )
+            .await?;
+            app_state
+                .slack_client
+                .post_ephemeral(
+                    period.title(),
+                    &payload.channel_id,
+                    &payload.user_id,
+                    &token,
+                    Some(get_leaderboard_blocks(period, &solvers)),
+                )
+                .await?;
+        }
+        Command::SetLeaderboardEnabled(enabled) => {
+            let message = match Channel::get(&payload.channel_id, &app_state.db).await? {
+                Some(mut channel) => {
+                    channel.leaderboard_enabled = enabled;
+                    channel.update(&app_state.db).await?;
+                    if enabled {
+                        ":trophy: Leaderboards will be posted to this channel every week and month"
+                    } else {
+                        ":no_bell: Leaderboards will no longer be posted to this channel"
+                    }
+                }
+                None => {
+                    ":no_entry_sign: No game is registered for the channel, did you mean to run \"start\"?"
+                }
+            };
+            app_state
+                .slack_client
+                .post_ephemeral(message, &payload.channel_id, &payload.user_id, &token, None)
+                .await?;
+        }
+        Command::PoolList => {
+            let pools = SecretPool::get_summaries(&app_state.db).await?;
+            let current = Channel::get(&payload.channel_id, &app_state.db)
+                .await?
+                .and_then(|channel| channel.secret_pool);
+            app_state
+                .slack_client
+                .post_ephemeral(
+                    "Secret pools",
+                    &payload.channel_id,
+                    &payload.user_id,
+                    &token,
+                    Some(get_secret_pools_blocks(&pools, current.as_deref())),
+                )
+                .await?;
+        }
+        Command::PoolUse(name) => {
+            match use_secret_pool(&payload.channel_id, &name, &app_state.db).await {
+                Ok(_) => {
+                    app_state
+                        .slack_client
+                        .post_message(
+                            &format!(
+                                "<@{}> has switched the secrets to the *{}* pool, starting with the next game",
+                                payload.user_id, name
+                            ),
+                            &payload.channel_id,
+                            &token,
+                            None,
+                        )
+                        .await?;
+                }
+                Err(SimilariumError {
+                    error_type: SimilariumErrorType::ValidationError,
+                    message,
+                }) => {
+                    app_state
+                        .slack_client
+                        .post_ephemeral(
+                            &message.unwrap_or_default(),
+                            &payload.channel_id,
+                            &payload.user_id,
+                            &token,
+                            None,
+                        )
+                        .await?;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Command::PersonaList => {
+            let personas = Persona::get_all(&app_state.db).await?;
+            let channel = Channel::get(&payload.channel_id, &app_state.db).await?;
+            let current = match &channel {
+                Some(channel) => get_current_persona(channel),
+                None => Some(DEFAULT_PERSONA),
+            };
+            app_state
+                .slack_client
+                .post_ephemeral(
+                    "AI personas",
+                    &payload.channel_id,
+                    &payload.user_id,
+                    &token,
+                    Some(get_personas_blocks(&personas, current)),
+                )
+                .await?;
+        }
+        Command::PersonaUse(name) => {
+            match use_persona(&payload.channel_id, name.as_deref(), &app_state.db).await {
+                Ok(_) => {
+                    let message = match name {
+                        Some(name) => format!(
+                            "<@{}> has switched the AI messages to the *{}* persona",
+                            payload.user_id, name
+                        ),
+                        None => format!("<@{}> has turned the AI messages off", payload.user_id),
+                    };
+                    app_state
+                        .slack_client
+                        .post_message(&message, &payload.channel_id, &token, None)
+                        .await?;
+                }
+                Err(SimilariumError {
+                    error_type: SimilariumErrorType::ValidationError,
+                    message,
+                }) => {
+                    app_state
+                        .slack_client
+                        .post_ephemeral(
+                            &message.unwrap_or_default(),
+                            &payload.channel_id,
+                            &payload.user_id,
+                            &token,
+                            None,
+                        )
+                        .await?;
+                }
+                Err(e) => return Err(e),
+            }
+        }
         Command::ManualStart => {
             manual_start(
                 &payload,
                 &app_state.db,
+                &app_state.similarity,
                 &app_state.slack_client,
                 &payload.channel_id,
                 &token,
@@ -108,8 +340,16 @@ async fn post_similarium_command(
         Command::ManualEnd => {
             let active_games =
                 get_active_games_on_channel(&app_state.db, &payload.channel_id).await?;
+            let mut queue = app_state.queue.lock().await.clone();
             for mut game in active_games {
-                end_game(&app_state.db, &app_state.slack_client, &mut game, &token).await?;
+                end_game(
+                    &app_state.db,
+                    &app_state.slack_client,
+                    &mut queue,
+                    &mut game,
+                    &token,
+                )
+                .await?;
             }
         }
         Command::Debug => todo!(),
@@ -1,47 +1,176 @@
 use crate::{
-    ai::{get_celebration, get_taunt, get_win_message},
     api::app::AppState,
-    game::{submit_guess, utils::get_game_blocks},
-    models::{Game, Guess, GuessContext, GuessContextOrder, SlackBot},
-    payloads::{Channel, Event, EventPayload, User},
+    game::{
+        get_suggestion_blocks, get_suggestion_game, home::publish_home, request_hint, submit_guess,
+        SUGGESTION_ACTION_ID,
+    },
+    models::{self, Game, Guess, GuessContext, GuessContextOrder, SlackBot},
+    normalise::{is_inflection_of, normalise_guess},
+    payloads::{CallbackEvent, Event, EventEnvelope, EventPayload, InteractionPayload, User},
     slack_client::SlackMessage,
-    utils::get_or_create_user,
+    tasks::{AiMessage, AiMessageTask},
+    utils::{get_or_create_user, uninstall_team},
     SimilariumError,
 };
-use actix_web::{post, web, HttpResponse, Scope};
+use actix_web::{post, web, HttpMessage, HttpRequest, HttpResponse, Scope};
+use fang::{asynk::async_queue::AsyncQueueable, AsyncRunnable};
+use num_format::{Locale, ToFormattedString};
 
+/// Handle everything that Slack sends, which is either an interaction such as a button being
+/// clicked, sent as a form with a JSON `payload`, or an Events API envelope sent as JSON
 #[post("")]
 async fn post_events(
-    event: web::Form<Event>,
+    request: HttpRequest,
+    body: web::Bytes,
     app_state: web::Data<AppState>,
 ) -> Result<HttpResponse, SimilariumError> {
-    // Parse the event.payload json string into EventPayload
-    let payload = serde_json::from_str::<EventPayload>(&event.payload)?;
+    if request.content_type() == "application/json" {
+        let envelope = serde_json::from_slice::<EventEnvelope>(&body)?;
+        return handle_event_envelope(envelope, app_state).await;
+    }
+
+    let event = serde_urlencoded::from_bytes::<Event>(&body)?;
+    match serde_json::from_str::<InteractionPayload>(&event.payload)? {
+        InteractionPayload::BlockActions(payload) => {
+            handle_block_actions(*payload, app_state).await
+        }
+        interaction => {
+            log::debug!("Ignoring interaction: {:?}", interaction);
+            Ok(HttpResponse::Ok().into())
+        }
+    }
+}
+
+async fn handle_event_envelope(
+    envelope: EventEnvelope,
+    app_state: web::Data<AppState>,
+) -> Result<HttpResponse, SimilariumError> {
+    match envelope {
+        EventEnvelope::UrlVerification { challenge } => {
+            return Ok(HttpResponse::Ok().json(serde_json::json!({ "challenge": challenge })));
+        }
+        EventEnvelope::EventCallback {
+            team_id,
+            enterprise_id,
+            event,
+        } => match event {
+            CallbackEvent::AppHomeOpened { user, tab } if tab == "home" => {
+                let token = SlackBot::get_slack_bot_token(
+                    enterprise_id.as_deref(),
+                    &team_id,
+                    &app_state.db,
+                )
+                .await?;
+                publish_home(
+                    &app_state.db,
+                    &app_state.slack_client,
+                    &user,
+                    enterprise_id.as_deref(),
+                    &team_id,
+                    &token,
+                )
+                .await?;
+            }
+            CallbackEvent::AppUninstalled => {
+                uninstall_team(enterprise_id.as_deref(), &team_id, &app_state.db).await?
+            }
+            // Only the bot token is used, revoking a user's token leaves the app installed
+            CallbackEvent::TokensRevoked { tokens } if !tokens.bot.is_empty() => {
+                uninstall_team(enterprise_id.as_deref(), &team_id, &app_state.db).await?
+            }
+            event => log::debug!("Ignoring event: {:?}", event),
+        },
+        EventEnvelope::AppRateLimited {
+            team_id,
+            minute_rate_limited,
+        } => {
+            log::warn!(
+                "Slack stopped sending events for team {} at {}",
+                team_id,
+                minute_rate_limited
+            );
+        }
+        EventEnvelope::Unknown => log::debug!("Ignoring unknown event envelope"),
+    }
 
+    Ok(HttpResponse::Ok().into())
+}
+
+async fn handle_block_actions(
+    payload: EventPayload,
+    app_state: web::Data<AppState>,
+) -> Result<HttpResponse, SimilariumError> {
     match payload {
         EventPayload {
             actions,
             user,
-            channel,
+            channel: Some(channel),
             message,
             ..
         } if actions.len() == 1 => {
             let action = actions.first().unwrap();
-            if action.action_id != "submit-guess" {
-                return validation_error!("Invalid action_id: {}", action.action_id);
-            }
-            let guess_value = action.value.trim();
-            let local_user = get_or_create_user(
-                &user.id,
-                &user.team_id,
-                &app_state.db,
-                &app_state.slack_client,
-            )
-            .await?;
-            let token = SlackBot::get_slack_bot_token(&user.team_id, &app_state.db).await?;
-            let game = Game::get(channel.id.as_str(), message.ts.as_str(), &app_state.db)
+            let (game, guess_value) = match (action.action_id.as_str(), &message) {
+                ("submit-guess" | "request-hint", Some(message)) => (
+                    Game::get(channel.id.as_str(), message.ts.as_str(), &app_state.db).await?,
+                    action.value.trim(),
+                ),
+                // Suggestions are on an ephemeral message, which isn't sent with the action
+                (action_id, _) if action_id.starts_with(SUGGESTION_ACTION_ID) => {
+                    let (game, word) =
+                        get_suggestion_game(&action.value, &channel.id, &app_state.db).await?;
+                    if game.is_none() {
+                        let token = models::Channel::get(&channel.id, &app_state.db)
+                            .await?
+                            .map_or_else(|| validation_error!("Channel not found"), Ok)?
+                            .get_slack_bot_token(&app_state.db)
+                            .await?;
+                        app_state
+                            .slack_client
+                            .post_ephemeral(
+                                ":no_entry_sign: This game has ended, so the suggestion can't be guessed",
+                                &channel.id,
+                                &user.id,
+                                &token,
+                                None,
+                            )
+                            .await?;
+                        return Ok(HttpResponse::Ok().into());
+                    }
+                    (game, word)
+                }
+                (action_id, Some(_)) => {
+                    return validation_error!("Invalid action_id: {}", action_id);
+                }
+                (action_id, None) => {
+                    log::debug!("Ignoring block action: {}", action_id);
+                    return Ok(HttpResponse::Ok().into());
+                }
+            };
+            let game = game.map_or_else(|| validation_error!("Game not found"), Ok)?;
+            // The channel may be shared with the user's workspace on Enterprise Grid, so use the
+            // install that serves the channel rather than the user's own workspace
+            let local_channel = models::Channel::get(&game.channel_id, &app_state.db)
                 .await?
-                .map_or_else(|| validation_error!("Game not found"), Ok)?;
+                .map_or_else(|| validation_error!("Channel not found"), Ok)?;
+            let token = local_channel.get_slack_bot_token(&app_state.db).await?;
+            let local_user =
+                get_or_create_user(&user.id, &token, &app_state.db, &app_state.slack_client)
+                    .await?;
+
+            if action.action_id == "request-hint" {
+                let mut queue = app_state.queue.lock().await.clone();
+                request_hint(
+                    &app_state.db,
+                    &app_state.similarity,
+                    &app_state.slack_client,
+                    &mut queue,
+                    &game,
+                    &user.id,
+                    &token,
+                )
+                .await?;
+                return Ok(HttpResponse::Ok().into());
+            }
 
             if game.user_already_won(&user.id, &app_state.db).await? {
                 app_state
@@ -68,12 +197,25 @@ async fn post_events(
 
             // Match on SimilariumError with error_type SimilariumErrorType::NotFound to let the
             // user know the word isn't in the dictionary
-            let guess = match submit_guess(&local_user, &game, guess_value, &app_state.db).await {
+            let guess = match submit_guess(
+                &local_user,
+                &game,
+                guess_value,
+                &app_state.db,
+                &app_state.similarity,
+            )
+            .await
+            {
                 Ok(guess) => guess,
                 Err(SimilariumError {
                     error_type: crate::error::SimilariumErrorType::NotFound,
                     ..
                 }) => {
+                    let suggestions = app_state.suggestions.suggest(&normalise_guess(guess_value));
+                    let blocks = match suggestions.is_empty() {
+                        true => None,
+                        false => Some(get_suggestion_blocks(guess_value, &suggestions, &game)),
+                    };
                     app_state
                         .slack_client
                         .post_ephemeral(
@@ -84,7 +226,7 @@ async fn post_events(
                             &channel.id,
                             &user.id,
                             &token,
-                            None,
+                            blocks,
                         )
                         .await?;
                     return Ok(HttpResponse::Ok().into());
@@ -123,25 +265,41 @@ async fn post_events(
                     .await?;
 
                 // Post on the channel to celebrate!
-                win_message(guess_num, &user, &top_guesses, &app_state, &channel, &token).await?;
+                if local_channel.ai_enabled {
+                    win_message(guess_num, &user, &top_guesses, &app_state, &game).await?;
+                }
+            } else {
+                // The game message is only updated once a burst of guesses is over, so let the
+                // user know how their guess did straight away
+                let mut message = format!(
+                    "*{}* is ranked *{}* with a similarity of {:.2}",
+                    guess.word,
+                    guess.rank.to_formatted_string(&Locale::en),
+                    guess.similarity
+                );
+                // A plural or other inflection of the secret doesn't win, but it's close enough
+                // to be worth pointing out
+                if is_inflection_of(&guess.word, &game.secret) {
+                    message.push_str(
+                        "\n:eyes: *So close!* That's another form of the secret word, try a different one :eyes:",
+                    );
+                }
+                app_state
+                    .slack_client
+                    .post_ephemeral(&message, &channel.id, &user.id, &token, None)
+                    .await?;
             }
 
-            let blocks = get_game_blocks(&game, &app_state.db).await?;
-
-            let _ = &app_state
-                .slack_client
-                .chat_update(
-                    "Update to today's game",
-                    &channel.id,
-                    &message.ts,
-                    &token,
-                    Some(blocks),
-                )
-                .await?;
+            app_state.game_updater.request_update(&game, &token);
 
             let guess_count = game.get_guess_count(&app_state.db).await.unwrap_or(0);
 
-            if !is_secret && top_rank > 10 && guess.rank <= 1000 && guess.rank < top_rank {
+            if local_channel.ai_enabled
+                && !is_secret
+                && top_rank > 10
+                && guess.rank <= 1000
+                && guess.rank < top_rank
+            {
                 celebrate(
                     top_rank,
                     &guess,
@@ -149,8 +307,7 @@ async fn post_events(
                     &user,
                     &top_guesses,
                     &app_state,
-                    &channel,
-                    &token,
+                    &game,
                 )
                 .await?;
             }
@@ -160,7 +317,10 @@ async fn post_events(
             let guesses_since_taunt = guess_count - game.taunt_index;
             let taunt_threshold = 40;
 
-            if top_guess.rank > 1000 && guesses_since_taunt > taunt_threshold {
+            if local_channel.ai_enabled
+                && top_guess.rank > 1000
+                && guesses_since_taunt > taunt_threshold
+            {
                 // Calculate some randomness, making it more likely with every guess above the
                 // taunt threshold that we taunt. The odds start at low and slowly increase with
                 // each guess, until a taunt is made. The odds reset then.
@@ -168,37 +328,59 @@ async fn post_events(
                     > (taunt_threshold as f64) / (1.1 * guesses_since_taunt as f64);
 
                 if should_taunt {
-                    let mut game =
-                        Game::get(channel.id.as_str(), message.ts.as_str(), &app_state.db)
-                            .await?
-                            .map_or_else(|| validation_error!("Game not found"), Ok)?;
+                    let mut game = Game::get_by_id(game.id, &app_state.db)
+                        .await?
+                        .map_or_else(|| validation_error!("Game not found"), Ok)?;
                     game.set_taunt_index(guess_count, &app_state.db).await?;
 
                     let participant_user_ids = game.get_participant_user_ids(&app_state.db).await?;
                     taunt(
                         guess_count,
-                        top_guess.word.as_str(),
-                        top_guess.rank,
+                        top_guess,
                         participant_user_ids,
                         &top_guesses,
                         &app_state,
-                        &channel,
-                        &token,
+                        &game,
                     )
                     .await?;
                 }
             }
         }
-        _ => {
-            todo!("Not handled");
+        // Such as the buttons on the Home tab, which only link to games
+        payload => {
+            log::debug!("Ignoring block actions: {:?}", payload.actions);
         }
     }
 
     Ok(HttpResponse::Ok().into())
 }
 
-// TODO: Simplify..
-#[allow(clippy::too_many_arguments)]
+/// Queue a message for the model to write, which is posted on the channel once it's ready
+///
+/// The model can take longer to respond than Slack waits for an interaction, so the messages are
+/// written in the background.
+async fn queue_ai_message(
+    app_state: &web::Data<AppState>,
+    game: &Game,
+    message: AiMessage,
+) -> Result<(), SimilariumError> {
+    let task = AiMessageTask {
+        game_id: game.id,
+        message,
+    };
+    let mut queue = app_state.queue.lock().await.clone();
+    queue.insert_task(&task as &dyn AsyncRunnable).await?;
+
+    Ok(())
+}
+
+fn get_top_guesses(top_guesses: &[GuessContext]) -> Vec<(i64, String)> {
+    top_guesses
+        .iter()
+        .map(|gc| (gc.rank, gc.word.clone()))
+        .collect()
+}
+
 async fn celebrate(
     top_rank: i64,
     guess: &Guess,
@@ -206,8 +388,7 @@ async fn celebrate(
     user: &User,
     top_guesses: &[GuessContext],
     app_state: &web::Data<AppState>,
-    channel: &Channel,
-    token: &str,
+    game: &Game,
 ) -> Result<(), SimilariumError> {
     let mut should_celebrate = false;
     let mut bucket = i64::MAX;
@@ -227,63 +408,33 @@ async fn celebrate(
         return Ok(());
     }
 
-    let top_guesses = top_guesses
-        .iter()
-        .map(|gc| (gc.rank, gc.word.as_str()))
-        .collect::<Vec<_>>();
-
-    let celebration = get_celebration(
+    let celebration = AiMessage::Celebration {
         guess_count,
-        &user.id,
-        &guess.word,
-        guess.rank,
-        top_guesses,
+        user_id: user.id.clone(),
+        word: guess.word.clone(),
+        word_rank: guess.rank,
+        top_guesses: get_top_guesses(top_guesses),
         bucket,
-    )
-    .await?;
-    log::debug!("Celebrating: {}", celebration.message);
-
-    app_state
-        .slack_client
-        .post_message(&celebration.message, &channel.id, token, None)
-        .await?;
-
-    Ok(())
+    };
+    queue_ai_message(app_state, game, celebration).await
 }
 
-// TODO: Simplify..
-#[allow(clippy::too_many_arguments)]
 async fn taunt(
     guess_count: i64,
-    top_word: &str,
-    top_word_rank: i64,
+    top_guess: &GuessContext,
     participant_user_ids: Vec<String>,
     top_guesses: &[GuessContext],
     app_state: &web::Data<AppState>,
-    channel: &Channel,
-    token: &str,
+    game: &Game,
 ) -> Result<(), SimilariumError> {
-    let top_guesses = top_guesses
-        .iter()
-        .map(|gc| (gc.rank, gc.word.as_str()))
-        .collect::<Vec<_>>();
-
-    let taunt = get_taunt(
+    let taunt = AiMessage::Taunt {
         guess_count,
-        top_word,
-        top_word_rank,
+        top_word: top_guess.word.clone(),
+        top_word_rank: top_guess.rank,
         participant_user_ids,
-        top_guesses,
-    )
-    .await?;
-    log::debug!("Taunting: {}", taunt.message);
-
-    app_state
-        .slack_client
-        .post_message(&taunt.message, &channel.id, token, None)
-        .await?;
-
-    Ok(())
+        top_guesses: get_top_guesses(top_guesses),
+    };
+    queue_ai_message(app_state, game, taunt).await
 }
 
 async fn win_message(
@@ -291,24 +442,14 @@ async fn win_message(
     user: &User,
     top_guesses: &[GuessContext],
     app_state: &web::Data<AppState>,
-    channel: &Channel,
-    token: &str,
+    game: &Game,
 ) -> Result<(), SimilariumError> {
-    let top_guesses = top_guesses
-        .iter()
-        .map(|gc| (gc.rank, gc.word.as_str()))
-        .collect::<Vec<_>>();
-
-    let win_message = get_win_message(guess_count, &user.id, top_guesses).await?;
-
-    log::debug!("Win message: {}", win_message.message);
-
-    app_state
-        .slack_client
-        .post_message(&win_message.message, &channel.id, token, None)
-        .await?;
-
-    Ok(())
+    let win_message = AiMessage::Win {
+        guess_count,
+        user_id: user.id.clone(),
+        top_guesses: get_top_guesses(top_guesses),
+    };
+    queue_ai_message(app_state, game, win_message).await
 }
 
 pub fn scope() -> Scope {
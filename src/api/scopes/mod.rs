@@ -2,6 +2,7 @@ mod auth;
 mod commands;
 mod events;
 
+use crate::{api::middleware::VerifySlackSignature, config::get_config};
 use actix_web::{get, web, Error, HttpResponse};
 
 #[get("/")]
@@ -10,11 +11,14 @@ async fn home_handler() -> Result<HttpResponse, Error> {
 }
 
 pub fn config(conf: &mut web::ServiceConfig) {
+    // Slack signs every event and slash command it sends, so anything else is rejected
+    let signing_secret = &get_config().slack_signing_secret;
+
     let home_scope = web::scope("")
         .service(home_handler)
         .service(auth::scope())
-        .service(events::scope())
-        .service(commands::scope());
+        .service(events::scope().wrap(VerifySlackSignature::new(signing_secret)))
+        .service(commands::scope().wrap(VerifySlackSignature::new(signing_secret)));
 
     conf.service(home_scope);
 }
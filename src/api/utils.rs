@@ -1,4 +1,4 @@
-use crate::SimilariumError;
+use crate::{game::leaderboard::LeaderboardPeriod, SimilariumError};
 use chrono::NaiveTime;
 
 #[derive(Debug, Eq, PartialEq)]
@@ -9,6 +9,30 @@ pub enum Command {
     Debug,
     Start(NaiveTime),
     Stop,
+    Hint,
+    /// Stats for a user, or the user running the command if not provided
+    Stats(Option<String>),
+    ChannelStats,
+    Leaderboard(LeaderboardPeriod),
+    /// Opt the channel in or out of the leaderboards being posted automatically
+    SetLeaderboardEnabled(bool),
+    PoolList,
+    /// Switch the channel to the named secret pool
+    PoolUse(String),
+    PersonaList,
+    /// Switch the channel to the named persona, or turn the AI messages off if None
+    PersonaUse(Option<String>),
+}
+
+/// Parse a user ID out of an escaped Slack mention, such as `<@U123|name>` or `<@U123>`
+fn parse_user_mention(text: &str) -> Option<String> {
+    let mention = text.trim().strip_prefix("<@")?.strip_suffix('>')?;
+    let user_id = mention.split('|').next()?;
+    if user_id.is_empty() {
+        return None;
+    }
+
+    Some(user_id.to_string())
 }
 
 pub fn parse_command(text: &str) -> Result<Command, SimilariumError> {
@@ -27,6 +51,40 @@ pub fn parse_command(text: &str) -> Result<Command, SimilariumError> {
             }
         }
         ("stop", _) => Ok(Command::Stop),
+        ("hint", _) => Ok(Command::Hint),
+        ("leaderboard", "" | "week") => Ok(Command::Leaderboard(LeaderboardPeriod::Week)),
+        ("leaderboard", "month") => Ok(Command::Leaderboard(LeaderboardPeriod::Month)),
+        ("leaderboard", "all") => Ok(Command::Leaderboard(LeaderboardPeriod::All)),
+        ("leaderboard", "on") => Ok(Command::SetLeaderboardEnabled(true)),
+        ("leaderboard", "off") => Ok(Command::SetLeaderboardEnabled(false)),
+        ("leaderboard", _) => validation_error!(
+            ":no_entry_sign: The leaderboard can be for a \"week\", \"month\" or \"all\" time, \
+             or turned \"on\" or \"off\" for the channel"
+        ),
+        ("pool", "" | "list") => Ok(Command::PoolList),
+        ("pool", rest) => match rest.split_once(' ') {
+            Some(("use", name)) if !name.trim().is_empty() => {
+                Ok(Command::PoolUse(name.trim().to_string()))
+            }
+            _ => validation_error!(
+                ":no_entry_sign: Use \"pool list\" to see the pools, or \"pool use [name]\" to switch"
+            ),
+        },
+        ("persona", "" | "list") => Ok(Command::PersonaList),
+        ("persona", "off") => Ok(Command::PersonaUse(None)),
+        ("persona", name) if !name.contains(' ') => Ok(Command::PersonaUse(Some(name.to_string()))),
+        ("persona", _) => validation_error!(
+            ":no_entry_sign: Use \"persona list\" to see the personas, \"persona [name]\" to \
+             switch or \"persona off\" to turn the AI messages off"
+        ),
+        ("stats", "") => Ok(Command::Stats(None)),
+        ("stats", "channel") => Ok(Command::ChannelStats),
+        ("stats", user) => match parse_user_mention(user) {
+            Some(user_id) => Ok(Command::Stats(Some(user_id))),
+            None => validation_error!(
+                ":no_entry_sign: Unable to find the user, please mention them like @user"
+            ),
+        },
         ("manual", "start") => Ok(Command::ManualStart),
         ("manual", "end") => Ok(Command::ManualEnd),
         ("debug", _) => Ok(Command::Debug),
@@ -48,6 +106,110 @@ mod tests {
         assert_eq!(parse_command("help").unwrap(), Command::Help);
     }
 
+    #[test]
+    fn test_parse_command_hint() {
+        assert_eq!(parse_command("hint").unwrap(), Command::Hint);
+    }
+
+    #[test]
+    fn test_parse_command_stats() {
+        assert_eq!(parse_command("stats").unwrap(), Command::Stats(None));
+        assert_eq!(
+            parse_command("stats channel").unwrap(),
+            Command::ChannelStats
+        );
+    }
+
+    #[test]
+    fn test_parse_command_stats_for_user() {
+        assert_eq!(
+            parse_command("stats <@U123|someone>").unwrap(),
+            Command::Stats(Some("U123".to_string()))
+        );
+        assert_eq!(
+            parse_command("stats <@U123>").unwrap(),
+            Command::Stats(Some("U123".to_string()))
+        );
+        assert_eq!(
+            parse_command("stats @someone").unwrap_err().error_type,
+            SimilariumErrorType::ValidationError
+        );
+    }
+
+    #[test]
+    fn test_parse_command_leaderboard() {
+        assert_eq!(
+            parse_command("leaderboard").unwrap(),
+            Command::Leaderboard(LeaderboardPeriod::Week)
+        );
+        assert_eq!(
+            parse_command("leaderboard week").unwrap(),
+            Command::Leaderboard(LeaderboardPeriod::Week)
+        );
+        assert_eq!(
+            parse_command("leaderboard month").unwrap(),
+            Command::Leaderboard(LeaderboardPeriod::Month)
+        );
+        assert_eq!(
+            parse_command("leaderboard all").unwrap(),
+            Command::Leaderboard(LeaderboardPeriod::All)
+        );
+        assert_eq!(
+            parse_command("leaderboard year").unwrap_err().error_type,
+            SimilariumErrorType::ValidationError
+        );
+    }
+
+    #[test]
+    fn test_parse_command_leaderboard_opt_out() {
+        assert_eq!(
+            parse_command("leaderboard off").unwrap(),
+            Command::SetLeaderboardEnabled(false)
+        );
+        assert_eq!(
+            parse_command("leaderboard on").unwrap(),
+            Command::SetLeaderboardEnabled(true)
+        );
+    }
+
+    #[test]
+    fn test_parse_command_pool() {
+        assert_eq!(parse_command("pool").unwrap(), Command::PoolList);
+        assert_eq!(parse_command("pool list").unwrap(), Command::PoolList);
+        assert_eq!(
+            parse_command("pool use easy").unwrap(),
+            Command::PoolUse("easy".to_string())
+        );
+        assert_eq!(
+            parse_command("pool use").unwrap_err().error_type,
+            SimilariumErrorType::ValidationError
+        );
+        assert_eq!(
+            parse_command("pool delete easy").unwrap_err().error_type,
+            SimilariumErrorType::ValidationError
+        );
+    }
+
+    #[test]
+    fn test_parse_command_persona() {
+        assert_eq!(parse_command("persona").unwrap(), Command::PersonaList);
+        assert_eq!(parse_command("persona list").unwrap(), Command::PersonaList);
+        assert_eq!(
+            parse_command("persona friendly").unwrap(),
+            Command::PersonaUse(Some("friendly".to_string()))
+        );
+        assert_eq!(
+            parse_command("persona off").unwrap(),
+            Command::PersonaUse(None)
+        );
+        assert_eq!(
+            parse_command("persona very friendly")
+                .unwrap_err()
+                .error_type,
+            SimilariumErrorType::ValidationError
+        );
+    }
+
     #[test]
     fn test_parse_command_handles_spaces() {
         assert_eq!(parse_command("help me please").unwrap(), Command::Help);
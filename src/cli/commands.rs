@@ -0,0 +1,177 @@
+use crate::{
+    config::get_config,
+    game::{
+        end_game, get_active_games_on_channel, personas, secret_pools::DEFAULT_POOL,
+        secrets::get_upcoming_secrets, start_game_on_channel,
+    },
+    importer::read_word_list,
+    models::{Channel, Game, GuessContextOrder, Persona, SecretPool, Word2Vec},
+    similarity::{get_similarity_engine, PostgresBackend},
+    slack_client::SlackClient,
+    workers::connect_queue,
+    SimilariumError,
+};
+use std::path::Path;
+use uuid::Uuid;
+
+async fn get_channel(channel_id: &str, db: &sqlx::PgPool) -> Result<Channel, SimilariumError> {
+    match Channel::get(channel_id, db).await? {
+        Some(channel) => Ok(channel),
+        None => validation_error!("Channel {} not found", channel_id),
+    }
+}
+
+pub async fn start_game(channel_id: &str, db: &sqlx::PgPool) -> Result<(), SimilariumError> {
+    let channel = get_channel(channel_id, db).await?;
+    let token = channel.get_slack_bot_token(db).await?;
+
+    let similarity = get_similarity_engine().await?;
+
+    start_game_on_channel(db, similarity, &SlackClient::default(), &channel.id, &token).await?;
+    println!("Started a game on channel {}", channel.id);
+
+    Ok(())
+}
+
+pub async fn end_games(channel_id: &str, db: &sqlx::PgPool) -> Result<(), SimilariumError> {
+    let channel = get_channel(channel_id, db).await?;
+    let token = channel.get_slack_bot_token(db).await?;
+    let slack_client = SlackClient::default();
+
+    let active_games = get_active_games_on_channel(db, &channel.id).await?;
+    if active_games.is_empty() {
+        println!("No active games on channel {}", channel.id);
+        return Ok(());
+    }
+
+    let config = get_config();
+    let mut queue = connect_queue(&config.database_url, 1).await?;
+    for mut game in active_games {
+        end_game(db, &slack_client, &mut queue, &mut game, &token).await?;
+        println!("Ended game {} (puzzle {})", game.id, game.puzzle_number);
+    }
+
+    Ok(())
+}
+
+pub async fn list_channels(db: &sqlx::PgPool) -> Result<(), SimilariumError> {
+    let channels = Channel::get_all(db).await?;
+    if channels.is_empty() {
+        println!("No channels found");
+        return Ok(());
+    }
+
+    println!(
+        "{:<14} {:<14} {:<6} {:<24} {:<7}",
+        "CHANNEL", "TEAM", "TIME", "TIMEZONE", "ACTIVE"
+    );
+    for channel in channels {
+        println!(
+            "{:<14} {:<14} {:02}:{:02}  {:<24} {:<7}",
+            channel.id,
+            channel.team_id,
+            channel.hour,
+            channel.minute,
+            channel.timezone,
+            channel.active
+        );
+    }
+
+    Ok(())
+}
+
+pub async fn show_game(id: Uuid, db: &sqlx::PgPool) -> Result<(), SimilariumError> {
+    let game = match Game::get_by_id(id, db).await? {
+        Some(game) => game,
+        None => return validation_error!("Game {} not found", id),
+    };
+
+    println!("Game:      {}", game.id);
+    println!("Channel:   {}", game.channel_id);
+    println!("Thread:    {}", game.thread_ts.as_deref().unwrap_or("-"));
+    println!("Puzzle:    {}", game.puzzle_number);
+    println!("Date:      {}", game.date);
+    println!("Active:    {}", game.active);
+    println!("Secret:    {}", game.secret);
+    println!("Guesses:   {}", game.get_guess_count(db).await?);
+
+    let winners = game.get_winners(db).await?;
+    if !winners.is_empty() {
+        println!("Winners:");
+        for winner in winners {
+            println!("  {} on guess {}", winner.user_id, winner.guess_idx);
+        }
+    }
+
+    let top_guesses = game
+        .get_guess_contexts(GuessContextOrder::Rank, 10, db)
+        .await?;
+    if !top_guesses.is_empty() {
+        println!("Top guesses:");
+        for guess in top_guesses {
+            println!("  {:>7} {} ({})", guess.rank, guess.word, guess.username);
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn prune_similarities(db: &sqlx::PgPool) -> Result<(), SimilariumError> {
+    let deleted = PostgresBackend::new(db.clone())
+        .prune_similarities(&get_upcoming_secrets(db).await?)
+        .await?;
+    println!("Deleted similarities for {} unused secrets", deleted);
+
+    Ok(())
+}
+
+/// Create a secret pool from a word list, skipping any words that aren't in the word2vec table
+pub async fn import_pool(
+    name: &str,
+    path: &Path,
+    description: &str,
+    db: &sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    if name == DEFAULT_POOL {
+        return validation_error!("The {} pool is built in and can't be changed", DEFAULT_POOL);
+    }
+
+    let words = read_word_list(path)?
+        .into_iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>();
+    let known_words = Word2Vec::get_known_words(&words, db).await?;
+    if known_words.is_empty() {
+        return validation_error!("None of the words in {} are known", path.display());
+    }
+
+    let pool = SecretPool {
+        name: name.to_string(),
+        description: description.to_string(),
+    };
+    pool.upsert(db).await?;
+    let added = pool.add_words(&known_words, db).await?;
+
+    println!(
+        "Added {} words to the {} pool, skipped {} unknown words",
+        added,
+        name,
+        words.len() - known_words.len()
+    );
+
+    Ok(())
+}
+
+/// Add personas, or replace the prompts of existing ones, from a JSON list of personas
+pub async fn import_personas(path: &Path, db: &sqlx::PgPool) -> Result<(), SimilariumError> {
+    let personas: Vec<Persona> = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+    personas::import_personas(&personas, db).await?;
+
+    let names = personas
+        .iter()
+        .map(|persona| persona.name.as_str())
+        .collect::<Vec<_>>();
+    println!("Imported {} personas: {}", names.len(), names.join(", "));
+
+    Ok(())
+}
@@ -0,0 +1,233 @@
+mod commands;
+
+use crate::{
+    ai::{get_deny_list, get_llm_engine},
+    api,
+    config::get_config,
+    db::{get_pool, run_migrations},
+    importer::{import_vectors, ImportOptions, VectorFormat},
+    similarity::get_similarity_engine,
+    workers::{connect_queue, ensure_recurring_tasks, start_workers},
+    SimilariumError,
+};
+use clap::{Args, Parser, Subcommand};
+use sqlx::postgres::PgPoolOptions;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run the API server, along with the background workers
+    Serve {
+        /// Only run the API server, with the workers running separately
+        #[arg(long)]
+        no_workers: bool,
+    },
+    /// Run any pending database migrations
+    Migrate,
+    /// Run the background workers, without the API server
+    Worker,
+    /// Start a new game on a channel
+    StartGame {
+        /// The Slack channel ID
+        channel: String,
+    },
+    /// End all active games on a channel
+    EndGame {
+        /// The Slack channel ID
+        channel: String,
+    },
+    /// List all channels that have been registered
+    ListChannels,
+    /// Show the details of a game
+    ShowGame {
+        /// The game ID
+        id: Uuid,
+    },
+    /// Delete precomputed similarities for secrets that no active game uses
+    PruneSimilarities,
+    /// Import word vectors into the word2vec table
+    ImportVectors(ImportVectorsArgs),
+    /// Create a secret pool, or add words to an existing one, from a word list
+    ImportPool {
+        /// The name of the pool, which channels switch to with `/similarium pool use [name]`
+        name: String,
+        /// Path to the word list, with one word per line
+        path: PathBuf,
+        /// A short description shown in `/similarium pool list`
+        #[arg(long, default_value = "")]
+        description: String,
+    },
+    /// Add AI personas, or update the prompts of existing ones, from a JSON file
+    ImportPersonas {
+        /// Path to a JSON list of personas, each with a name, description and prompts
+        path: PathBuf,
+    },
+}
+
+#[derive(Args, Debug)]
+struct ImportVectorsArgs {
+    /// Path to the vectors, either in the binary word2vec format or the text format
+    path: PathBuf,
+    /// The format of the vectors file, guessed from the extension if not provided
+    #[arg(long, value_enum)]
+    format: Option<VectorFormat>,
+    /// Only import words in this list, with one word per line
+    #[arg(long)]
+    allow_list: Option<PathBuf>,
+    /// Never import words in this list, with one word per line
+    #[arg(long)]
+    deny_list: Option<PathBuf>,
+    /// How many words to insert per transaction
+    #[arg(long, default_value_t = 10_000)]
+    batch_size: usize,
+    #[arg(long, env = "DATABASE_URL", hide_env_values = true)]
+    database_url: String,
+}
+
+async fn run_import_vectors(args: ImportVectorsArgs) -> Result<(), SimilariumError> {
+    let db = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&args.database_url)
+        .await?;
+
+    let mut options = ImportOptions::new(args.path);
+    if let Some(format) = args.format {
+        options.format = format;
+    }
+    options.allow_list = args.allow_list;
+    options.deny_list = args.deny_list;
+    options.batch_size = args.batch_size;
+
+    import_vectors(&options, &db).await?;
+
+    Ok(())
+}
+
+/// Run the background workers until the process is interrupted
+async fn run_worker() -> Result<(), SimilariumError> {
+    let config = get_config();
+    run_migrations(get_pool()).await?;
+    get_similarity_engine().await?;
+    get_llm_engine()?;
+    get_deny_list()?;
+
+    let queue = connect_queue(&config.database_url, config.worker_max_pool_size).await?;
+    start_workers(&queue, config.worker_count).await?;
+    ensure_recurring_tasks(queue).await?;
+
+    actix_web::rt::signal::ctrl_c().await?;
+    log::info!("Shutting down workers");
+
+    Ok(())
+}
+
+/// Parse the command line arguments and run the requested command
+///
+/// Running without a command starts the server, to stay compatible with existing deployments
+pub async fn run() -> Result<(), SimilariumError> {
+    env_logger::init_from_env(env_logger::Env::new().default_filter_or("info"));
+    let cli = Cli::parse();
+
+    match cli.command {
+        None => api::run(true).await,
+        Some(Command::Serve { no_workers }) => api::run(!no_workers).await,
+        Some(Command::Migrate) => run_migrations(get_pool()).await,
+        Some(Command::Worker) => run_worker().await,
+        Some(Command::StartGame { channel }) => commands::start_game(&channel, get_pool()).await,
+        Some(Command::EndGame { channel }) => commands::end_games(&channel, get_pool()).await,
+        Some(Command::ListChannels) => commands::list_channels(get_pool()).await,
+        Some(Command::ShowGame { id }) => commands::show_game(id, get_pool()).await,
+        Some(Command::PruneSimilarities) => commands::prune_similarities(get_pool()).await,
+        Some(Command::ImportVectors(args)) => run_import_vectors(args).await,
+        Some(Command::ImportPool {
+            name,
+            path,
+            description,
+        }) => commands::import_pool(&name, &path, &description, get_pool()).await,
+        Some(Command::ImportPersonas { path }) => {
+            commands::import_personas(&path, get_pool()).await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli_is_valid() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_no_command_defaults_to_none() {
+        let cli = Cli::try_parse_from(["similarium"]).unwrap();
+        assert!(cli.command.is_none());
+    }
+
+    #[test]
+    fn test_parse_serve_without_workers() {
+        let cli = Cli::try_parse_from(["similarium", "serve", "--no-workers"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::Serve { no_workers: true })
+        ));
+    }
+
+    #[test]
+    fn test_parse_show_game_requires_valid_id() {
+        let id = "00000001-0000-4000-a000-000000000000";
+        let cli = Cli::try_parse_from(["similarium", "show-game", id]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::ShowGame { id: parsed }) if parsed.to_string() == id
+        ));
+
+        assert!(Cli::try_parse_from(["similarium", "show-game", "foobar"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_import_pool() {
+        let cli = Cli::try_parse_from([
+            "similarium",
+            "import-pool",
+            "easy",
+            "easy.txt",
+            "--description",
+            "Common nouns",
+        ])
+        .unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::ImportPool { name, description, .. })
+                if name == "easy" && description == "Common nouns"
+        ));
+    }
+
+    #[test]
+    fn test_parse_import_personas() {
+        let cli = Cli::try_parse_from(["similarium", "import-personas", "personas.json"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::ImportPersonas { path }) if path.to_str() == Some("personas.json")
+        ));
+    }
+
+    #[test]
+    fn test_parse_start_game() {
+        let cli = Cli::try_parse_from(["similarium", "start-game", "C123"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Command::StartGame { channel }) if channel == "C123"
+        ));
+    }
+}
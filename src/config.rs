@@ -1,22 +1,43 @@
-use crate::SimilariumError;
+use crate::{ai::LlmProviderKind, similarity::SimilarityBackendKind, SimilariumError};
 use std::env;
+use std::path::PathBuf;
 use std::sync::OnceLock;
+use std::time::Duration;
 
 #[derive(Debug, Clone)]
 pub struct Config {
     pub slack_client_id: String,
     pub slack_client_secret: String,
+    pub slack_signing_secret: String,
     pub database_url: String,
     pub host: String,
     pub port: u16,
     pub worker_count: u32,
     pub worker_max_pool_size: u32,
+    pub similarity_backend: SimilarityBackendKind,
+    pub word2vec_path: Option<PathBuf>,
+    pub similarity_cache_size: usize,
+    pub game_update_window: Duration,
+    pub llm_provider: LlmProviderKind,
+    pub llm_model: String,
+    pub llm_base_url: Option<String>,
+    pub llm_api_key: Option<String>,
+    pub llm_temperature: f32,
+    pub llm_timeout: Duration,
+    pub llm_deny_list_path: Option<PathBuf>,
 }
 
 const DEFAULT_PORT: u16 = 8080;
 const DEFAULT_HOST: &str = "127.0.0.1";
 const DEFAULT_WORKER_COUNT: u32 = 3;
 const DEFAULT_MAX_POOL_SIZE: u32 = 3;
+const DEFAULT_SIMILARITY_BACKEND: &str = "postgres";
+const DEFAULT_SIMILARITY_CACHE_SIZE: usize = 64;
+const DEFAULT_GAME_UPDATE_WINDOW_MS: u64 = 1000;
+const DEFAULT_LLM_PROVIDER: &str = "openai";
+const DEFAULT_LLM_MODEL: &str = "gpt-4o";
+const DEFAULT_LLM_TEMPERATURE: f32 = 1.0;
+const DEFAULT_LLM_TIMEOUT_SECS: u64 = 30;
 
 static CONFIG: OnceLock<Config> = OnceLock::new();
 
@@ -24,6 +45,7 @@ impl Config {
     pub fn init_from_env() -> Result<Self, SimilariumError> {
         let slack_client_id = env::var("SLACK_CLIENT_ID")?;
         let slack_client_secret = env::var("SLACK_CLIENT_SECRET")?;
+        let slack_signing_secret = env::var("SLACK_SIGNING_SECRET")?;
         let database_url = env::var("DATABASE_URL")?;
 
         let port = env::var("PORT")
@@ -36,15 +58,52 @@ impl Config {
         let worker_max_pool_size = env::var("WORKER_MAX_POOL_SIZE")
             .unwrap_or_else(|_| DEFAULT_MAX_POOL_SIZE.to_string())
             .parse::<u32>()?;
+        let similarity_backend = env::var("SIMILARITY_BACKEND")
+            .unwrap_or_else(|_| DEFAULT_SIMILARITY_BACKEND.to_string())
+            .parse::<SimilarityBackendKind>()?;
+        let word2vec_path = env::var("WORD2VEC_PATH").ok().map(PathBuf::from);
+        let similarity_cache_size = env::var("SIMILARITY_CACHE_SIZE")
+            .unwrap_or_else(|_| DEFAULT_SIMILARITY_CACHE_SIZE.to_string())
+            .parse::<usize>()?;
+        let game_update_window = env::var("GAME_UPDATE_WINDOW_MS")
+            .unwrap_or_else(|_| DEFAULT_GAME_UPDATE_WINDOW_MS.to_string())
+            .parse::<u64>()
+            .map(Duration::from_millis)?;
+        let llm_provider = env::var("LLM_PROVIDER")
+            .unwrap_or_else(|_| DEFAULT_LLM_PROVIDER.to_string())
+            .parse::<LlmProviderKind>()?;
+        let llm_model = env::var("LLM_MODEL").unwrap_or_else(|_| DEFAULT_LLM_MODEL.to_string());
+        let llm_base_url = env::var("LLM_BASE_URL").ok();
+        let llm_api_key = env::var("LLM_API_KEY").ok();
+        let llm_temperature = env::var("LLM_TEMPERATURE")
+            .unwrap_or_else(|_| DEFAULT_LLM_TEMPERATURE.to_string())
+            .parse::<f32>()?;
+        let llm_timeout = env::var("LLM_TIMEOUT_SECS")
+            .unwrap_or_else(|_| DEFAULT_LLM_TIMEOUT_SECS.to_string())
+            .parse::<u64>()
+            .map(Duration::from_secs)?;
+        let llm_deny_list_path = env::var("LLM_DENY_LIST_PATH").ok().map(PathBuf::from);
 
         Ok(Config {
             slack_client_id,
             slack_client_secret,
+            slack_signing_secret,
             database_url,
             host,
             port,
             worker_count,
             worker_max_pool_size,
+            similarity_backend,
+            word2vec_path,
+            similarity_cache_size,
+            game_update_window,
+            llm_provider,
+            llm_model,
+            llm_base_url,
+            llm_api_key,
+            llm_temperature,
+            llm_timeout,
+            llm_deny_list_path,
         })
     }
 }
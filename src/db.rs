@@ -1,4 +1,4 @@
-use crate::config::get_config;
+use crate::{config::get_config, SimilariumError};
 use sqlx::postgres::PgPoolOptions;
 use std::sync::OnceLock;
 
@@ -13,3 +13,11 @@ pub fn get_pool() -> &'static sqlx::PgPool {
             .expect("Failed to connect to Postgres")
     })
 }
+
+/// Run any pending database migrations
+pub async fn run_migrations(db: &sqlx::PgPool) -> Result<(), SimilariumError> {
+    log::info!("Running migrations");
+    sqlx::migrate!("./migrations").run(db).await?;
+
+    Ok(())
+}
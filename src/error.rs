@@ -16,6 +16,9 @@ pub enum SimilariumErrorType {
     SerialisationError,
     ValueError,
     AIError,
+    Unauthorized,
+    RateLimited,
+    TokenRevoked,
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -62,6 +65,10 @@ impl ResponseError for SimilariumError {
             | SimilariumErrorType::SlackApiError => StatusCode::INTERNAL_SERVER_ERROR,
             SimilariumErrorType::NotFound => StatusCode::NOT_FOUND,
             SimilariumErrorType::ValidationError => StatusCode::BAD_REQUEST,
+            SimilariumErrorType::Unauthorized | SimilariumErrorType::TokenRevoked => {
+                StatusCode::UNAUTHORIZED
+            }
+            SimilariumErrorType::RateLimited => StatusCode::SERVICE_UNAVAILABLE,
         }
     }
 
@@ -151,6 +158,16 @@ impl From<serde_urlencoded::ser::Error> for SimilariumError {
     }
 }
 
+impl From<serde_urlencoded::de::Error> for SimilariumError {
+    fn from(error: serde_urlencoded::de::Error) -> Self {
+        log::error!("Error parsing URL encoded data: {}", error);
+        SimilariumError {
+            message: Some("Unexpected error parsing URL encoded data".to_string()),
+            error_type: SimilariumErrorType::SerialisationError,
+        }
+    }
+}
+
 impl From<std::env::VarError> for SimilariumError {
     fn from(error: std::env::VarError) -> Self {
         log::error!("Error parsing environment variable: {}", error);
@@ -171,6 +188,16 @@ impl From<std::num::ParseIntError> for SimilariumError {
     }
 }
 
+impl From<std::num::ParseFloatError> for SimilariumError {
+    fn from(error: std::num::ParseFloatError) -> Self {
+        log::error!("Error parsing float: {}", error);
+        SimilariumError {
+            message: Some("Unexpected error parsing float".to_string()),
+            error_type: SimilariumErrorType::Error,
+        }
+    }
+}
+
 impl From<std::io::Error> for SimilariumError {
     fn from(error: std::io::Error) -> Self {
         log::error!("IO Error: {}", error);
@@ -181,6 +208,16 @@ impl From<std::io::Error> for SimilariumError {
     }
 }
 
+impl From<tokio::task::JoinError> for SimilariumError {
+    fn from(error: tokio::task::JoinError) -> Self {
+        log::error!("JoinError: {}", error);
+        SimilariumError {
+            message: Some("Unexpected error in a background thread".to_string()),
+            error_type: SimilariumErrorType::Error,
+        }
+    }
+}
+
 impl From<fang::AsyncQueueError> for SimilariumError {
     fn from(error: fang::AsyncQueueError) -> Self {
         log::error!("AsyncQueueError: {}", error);
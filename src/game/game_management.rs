@@ -1,13 +1,19 @@
 use crate::game::utils::get_header_text;
 use chrono::{NaiveTime, Timelike, Utc};
+use fang::{asynk::async_queue::AsyncQueueable, AsyncRunnable};
 use uuid::Uuid;
 
 use crate::{
-    game::utils::{get_game_blocks, get_secret},
+    game::{
+        secrets::{choose_secret, get_low_secrets_warning, record_secret},
+        utils::get_game_blocks,
+    },
     models::{Channel, Game, Word2Vec},
     payloads::CommandPayload,
+    similarity::SimilarityBackend,
     slack_client::{responses::UserInfoResponse, SlackClient, SlackMessage, SlackUserDetails},
-    utils::{get_utc_naive_time, when_human},
+    tasks::UpdateGameMessageTask,
+    utils::{get_timezone, when_human},
     SimilariumError, SimilariumErrorType,
 };
 
@@ -41,14 +47,10 @@ pub async fn schedule_game_on_channel(
         }
     };
 
-    // Convert the given time to UTC, using the timezone offset
-    let utc_time = get_utc_naive_time(time, user.tz_offset);
+    // The time is kept in the user's timezone, rather than converted to UTC, so that the game
+    // stays at the same local time when the clocks change
+    let timezone = get_timezone(&user.tz);
     let when = when_human(time);
-    let tz_offset = match user.tz_offset {
-        0 => "UTC".to_string(),
-        offset if offset < 0 => format!("UTC-{}", offset / 3600),
-        offset => format!("UTC+{}", offset / 3600),
-    };
 
     log::info!("Starting game on channel {}: {}", payload.channel_id, when);
 
@@ -58,7 +60,9 @@ pub async fn schedule_game_on_channel(
         .post_message(
             &format!(
                 "<@{}> has started a daily game of Similarium {} {}",
-                user.id, when, tz_offset
+                user.id,
+                when,
+                timezone.name()
             ),
             &payload.channel_id,
             token,
@@ -83,8 +87,12 @@ pub async fn schedule_game_on_channel(
     match channel {
         Some(mut channel) => {
             channel.active = true;
-            channel.hour = utc_time.hour() as i32;
-            channel.minute = utc_time.minute() as i32;
+            channel.hour = time.hour() as i32;
+            channel.minute = time.minute() as i32;
+            channel.timezone = timezone.name().to_string();
+            if channel.enterprise_id.is_none() {
+                channel.enterprise_id = payload.enterprise_id.clone();
+            }
             channel.update(db).await?;
         }
         None => {
@@ -92,9 +100,15 @@ pub async fn schedule_game_on_channel(
             let channel = Channel {
                 id: payload.channel_id.clone(),
                 team_id: payload.team_id.clone(),
-                hour: utc_time.hour() as i32,
-                minute: utc_time.minute() as i32,
+                hour: time.hour() as i32,
+                minute: time.minute() as i32,
                 active: true,
+                timezone: timezone.name().to_string(),
+                leaderboard_enabled: true,
+                secret_pool: None,
+                enterprise_id: payload.enterprise_id.clone(),
+                persona: None,
+                ai_enabled: true,
             };
             channel.insert(db).await?;
         }
@@ -138,9 +152,36 @@ pub async fn stop_games_on_channel(
     Ok(())
 }
 
+/// Let the channel know if its secret pool is nearly used up
+///
+/// Failing to post the warning doesn't affect the game, so the error is only logged
+async fn warn_if_low_on_secrets(
+    slack_client: &impl SlackMessage,
+    channel: &Channel,
+    remaining: usize,
+    token: &str,
+) {
+    let Some(warning) = get_low_secrets_warning(channel, remaining) else {
+        return;
+    };
+
+    log::warn!(
+        "Channel {} has {} unplayed secrets left",
+        channel.id,
+        remaining
+    );
+    if let Err(e) = slack_client
+        .post_message(&warning, &channel.id, token, None)
+        .await
+    {
+        log::error!("Unable to warn {} about low secrets: {:?}", channel.id, e);
+    }
+}
+
 pub async fn manual_start(
     payload: &CommandPayload,
     db: &sqlx::PgPool,
+    similarity: &impl SimilarityBackend,
     slack_client: &SlackClient,
     channel_id: &str,
     token: &str,
@@ -163,6 +204,12 @@ pub async fn manual_start(
                 hour: 0,
                 minute: 0,
                 active: true,
+                timezone: "UTC".to_string(),
+                leaderboard_enabled: true,
+                secret_pool: None,
+                enterprise_id: payload.enterprise_id.clone(),
+                persona: None,
+                ai_enabled: true,
             };
             channel.insert(db).await?;
             channel
@@ -173,11 +220,11 @@ pub async fn manual_start(
     let datetime = Utc::now();
     let puzzle_number = Game::get_next_puzzle_number(channel.id.clone(), db).await;
 
-    let secret = get_secret(&channel.id, puzzle_number);
+    let choice = choose_secret(&channel, puzzle_number, db).await?;
     let target_word = Word2Vec {
-        word: secret.clone(),
+        word: choice.secret.clone(),
     };
-    target_word.create_materialised_view(db).await?;
+    target_word.compute_similarities(similarity).await?;
     log::debug!("Target word: {}", target_word.word);
 
     log::debug!("Setting up the game");
@@ -193,6 +240,7 @@ pub async fn manual_start(
         secret: target_word.word.clone(),
     };
     game.insert(db).await?;
+    record_secret(&channel.id, &game.secret, puzzle_number, db).await?;
 
     log::debug!("Setting up the message");
     let blocks = get_game_blocks(&game, db).await?;
@@ -217,11 +265,14 @@ pub async fn manual_start(
 
     game.set_thread_ts(thread_ts, db).await?;
 
+    warn_if_low_on_secrets(slack_client, &channel, choice.remaining, token).await;
+
     Ok(())
 }
 
 pub async fn start_game_on_channel(
     db: &sqlx::PgPool,
+    similarity: &impl SimilarityBackend,
     slack_client: &impl SlackMessage,
     channel_id: &str,
     token: &str,
@@ -240,11 +291,11 @@ pub async fn start_game_on_channel(
     let datetime = Utc::now();
     let puzzle_number = Game::get_next_puzzle_number(channel.id.clone(), db).await;
 
-    let secret = get_secret(&channel.id, puzzle_number);
+    let choice = choose_secret(&channel, puzzle_number, db).await?;
     let target_word = Word2Vec {
-        word: secret.clone(),
+        word: choice.secret.clone(),
     };
-    target_word.create_materialised_view(db).await?;
+    target_word.compute_similarities(similarity).await?;
 
     log::debug!("Setting up the game");
     let mut game = Game {
@@ -259,6 +310,7 @@ pub async fn start_game_on_channel(
         secret: target_word.word.clone(),
     };
     game.insert(db).await?;
+    record_secret(&channel.id, &game.secret, puzzle_number, db).await?;
 
     log::debug!("Setting up the message");
     let blocks = get_game_blocks(&game, db).await?;
@@ -284,6 +336,8 @@ pub async fn start_game_on_channel(
 
     game.set_thread_ts(thread_ts, db).await?;
 
+    warn_if_low_on_secrets(slack_client, &channel, choice.remaining, token).await;
+
     Ok(())
 }
 
@@ -304,27 +358,60 @@ pub async fn get_active_games_on_channel(
 pub async fn end_game(
     db: &sqlx::PgPool,
     slack_client: &SlackClient,
+    queue: &mut dyn AsyncQueueable,
     game: &mut Game,
     token: &str,
 ) -> Result<(), SimilariumError> {
     log::debug!("Ending game: {}", game.id);
 
-    // Set game to be inactive
+    // Set game to be inactive, and update the game to say it's over
     game.set_active(false, db).await?;
-    if let Some(thread_ts) = &game.thread_ts {
-        // Update the game to say it's over
-        let blocks = get_game_blocks(game, db).await?;
-
-        slack_client
-            .chat_update(
-                "Update to today's game",
-                &game.channel_id,
-                thread_ts,
-                token,
-                Some(blocks),
-            )
-            .await?;
-    }
+    update_game_message(db, slack_client, queue, game, token).await?;
 
     Ok(())
 }
+
+/// Update the game message with the latest board
+///
+/// If Slack is still rate limiting us after retrying, the update is handed to an
+/// `UpdateGameMessageTask` instead, so that the board isn't left out of date.
+pub async fn update_game_message(
+    db: &sqlx::PgPool,
+    slack_client: &impl SlackMessage,
+    queue: &mut dyn AsyncQueueable,
+    game: &Game,
+    token: &str,
+) -> Result<(), SimilariumError> {
+    let Some(thread_ts) = &game.thread_ts else {
+        return Ok(());
+    };
+    let blocks = get_game_blocks(game, db).await?;
+
+    match slack_client
+        .chat_update(
+            "Update to today's game",
+            &game.channel_id,
+            thread_ts,
+            token,
+            Some(blocks),
+        )
+        .await
+    {
+        Err(SimilariumError {
+            error_type: SimilariumErrorType::RateLimited,
+            ..
+        }) => {
+            log::warn!(
+                "Queueing update to game {} after being rate limited",
+                game.id
+            );
+            let task = UpdateGameMessageTask {
+                channel_id: game.channel_id.clone(),
+                thread_ts: thread_ts.clone(),
+            };
+            queue.insert_task(&task as &dyn AsyncRunnable).await?;
+            Ok(())
+        }
+        result => result.map(|_| ()),
+    }
+}
@@ -1,22 +1,60 @@
 use crate::{
-    models::{Game, Guess, User, Word2Vec},
-    spelling::americanise,
+    error::SimilariumErrorType,
+    models::{Game, Guess, Similarity, User, Word2Vec},
+    normalise::{get_lemmas, normalise_guess},
+    similarity::SimilarityBackend,
+    slack_client::{Block, Button},
     SimilariumError,
 };
 use uuid::Uuid;
 
+/// Get the similarity of a guess to the secret, falling back to the guess's lemmas if the
+/// vocabulary doesn't have the exact form, so that "bakings" is taken as "baking" or "bake"
+///
+/// Returns the word that was found along with its similarity, or the `NotFound` error of the
+/// guess if none of the forms are in the vocabulary.
+async fn get_vocabulary_similarity(
+    secret: &Word2Vec,
+    guess: String,
+    similarity: &impl SimilarityBackend,
+) -> Result<(String, Similarity), SimilariumError> {
+    let err = match secret.get_similarity(&guess, similarity).await {
+        Ok(result) => return Ok((guess, result)),
+        Err(err) if err.error_type == SimilariumErrorType::NotFound => err,
+        Err(err) => return Err(err),
+    };
+
+    for lemma in get_lemmas(&guess) {
+        match secret.get_similarity(&lemma, similarity).await {
+            Ok(result) => {
+                log::debug!(
+                    "Guess {:?} isn't in the vocabulary, using {:?}",
+                    guess,
+                    lemma
+                );
+                return Ok((lemma, result));
+            }
+            Err(err) if err.error_type == SimilariumErrorType::NotFound => continue,
+            Err(err) => return Err(err),
+        }
+    }
+
+    Err(err)
+}
+
 pub async fn submit_guess(
     user: &User,
     game: &Game,
     guess: &str,
     db: &sqlx::PgPool,
+    similarity: &impl SimilarityBackend,
 ) -> Result<Guess, SimilariumError> {
     // Get the similarity for the guess
     let secret = Word2Vec {
         word: game.secret.clone(),
     };
-    let guess = americanise(guess.to_lowercase().trim());
-    let similarity = secret.get_similarity(&guess, db).await?;
+    let (guess, similarity) =
+        get_vocabulary_similarity(&secret, normalise_guess(guess), similarity).await?;
 
     if let Some(mut guess) = Guess::get(game.id, &guess, db).await? {
         log::debug!("Guess has already been made, updating timestamp");
@@ -41,3 +79,124 @@ pub async fn submit_guess(
 
     Ok(guess)
 }
+
+/// The start of the action IDs of the buttons that submit a suggestion as a guess
+pub const SUGGESTION_ACTION_ID: &str = "submit-suggestion";
+
+/// Get the value of a button that submits a suggestion as a guess
+///
+/// The suggestions are on an ephemeral message, which Slack doesn't send along with the action, so
+/// the button has to say which game it's for.
+fn get_suggestion_value(game: &Game, word: &str) -> String {
+    format!("{}:{}", game.id, word)
+}
+
+/// Get the game and word from the value of a suggestion button
+fn parse_suggestion_value(value: &str) -> Result<(Uuid, &str), SimilariumError> {
+    match value
+        .split_once(':')
+        .map(|(game_id, word)| (Uuid::parse_str(game_id), word.trim()))
+    {
+        Some((Ok(game_id), word)) if !word.is_empty() => Ok((game_id, word)),
+        _ => validation_error!("Invalid suggestion: {}", value),
+    }
+}
+
+/// Get the game and word that a suggestion button submits
+///
+/// The suggestions stay on the user's screen after the game ends, so the game is None if it has
+/// ended or isn't on the channel that the button was clicked in.
+pub async fn get_suggestion_game<'a>(
+    value: &'a str,
+    channel_id: &str,
+    db: &sqlx::PgPool,
+) -> Result<(Option<Game>, &'a str), SimilariumError> {
+    let (game_id, word) = parse_suggestion_value(value)?;
+    let game = Game::get_by_id(game_id, db)
+        .await?
+        .filter(|game| game.active && game.channel_id == channel_id);
+
+    Ok((game, word))
+}
+
+/// Generate the blocks that let a user know their guess isn't a word, with a button to guess
+/// each of the suggestions instead
+pub fn get_suggestion_blocks(guess: &str, suggestions: &[String], game: &Game) -> Vec<Block> {
+    let words = suggestions
+        .iter()
+        .map(|word| format!("*{}*", word))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let buttons = suggestions
+        .iter()
+        .map(|word| {
+            // Slack needs the buttons in a block to have different action IDs
+            let action_id = format!("{}-{}", SUGGESTION_ACTION_ID, word);
+            Button::new(&action_id, word, &get_suggestion_value(game, word))
+        })
+        .collect();
+
+    vec![
+        Block::section(
+            &format!(
+                ":warning: *\"{}\" is not a valid word!* Did you mean {}?",
+                guess, words
+            ),
+            None,
+        ),
+        Block::actions("suggestions", buttons),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn get_game() -> Game {
+        Game {
+            id: Uuid::parse_str("00000001-0000-4000-a000-000000000000").unwrap(),
+            channel_id: "channel_id".to_string(),
+            thread_ts: Some("thread_ts".to_string()),
+            puzzle_number: 1,
+            date: Utc::now(),
+            active: true,
+            secret: "secret".to_string(),
+            hint: None,
+            taunt_index: 0,
+        }
+    }
+
+    #[test]
+    fn test_suggestion_value_roundtrips() {
+        let game = get_game();
+        let value = get_suggestion_value(&game, "pear");
+
+        assert_eq!(parse_suggestion_value(&value).unwrap(), (game.id, "pear"));
+        assert!(parse_suggestion_value("pear").is_err());
+        assert!(parse_suggestion_value("not-a-uuid:pear").is_err());
+        assert!(parse_suggestion_value(&get_suggestion_value(&game, "")).is_err());
+    }
+
+    #[test]
+    fn test_get_suggestion_blocks() {
+        let game = get_game();
+        let suggestions = vec!["pear".to_string(), "peer".to_string()];
+
+        let blocks = get_suggestion_blocks("peaar", &suggestions, &game);
+        let json = serde_json::to_value(&blocks).unwrap();
+
+        assert_eq!(
+            json[0]["text"]["text"],
+            ":warning: *\"peaar\" is not a valid word!* Did you mean *pear*, *peer*?"
+        );
+        let buttons = json[1]["elements"].as_array().unwrap();
+        assert_eq!(buttons.len(), 2);
+        assert_eq!(buttons[0]["action_id"], "submit-suggestion-pear");
+        assert_eq!(buttons[0]["text"]["text"], "pear");
+        assert_eq!(
+            buttons[1]["value"],
+            "00000001-0000-4000-a000-000000000000:peer"
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{
+    models::{Channel, Game, GuessContextOrder, Similarity, Word2Vec},
+    similarity::SimilarityBackend,
+    slack_client::SlackMessage,
+    tasks::{AiMessage, AiMessageTask},
+    SimilariumError, SimilariumErrorType,
+};
+use fang::{asynk::async_queue::AsyncQueueable, AsyncRunnable};
+
+/// The rank a hint starts from, when the user has no guesses or only guesses further than this
+const HINT_MAX_RANK: i64 = 1000;
+
+/// Get the rank of the word to reveal as a hint
+///
+/// The hint is halfway between the user's best guess and the secret, so it's always an
+/// improvement on what they have, without giving the secret away.
+pub fn get_hint_rank(best_rank: Option<i64>) -> i64 {
+    best_rank.unwrap_or(HINT_MAX_RANK).min(HINT_MAX_RANK) / 2
+}
+
+/// Reveal a hint to a user, recording that they have used their hint for the game
+///
+/// Each user can only get one hint per game, and only while the game is active and they haven't
+/// found the secret yet.
+pub async fn reveal_hint(
+    game: &Game,
+    user_id: &str,
+    db: &sqlx::PgPool,
+    similarity: &impl SimilarityBackend,
+) -> Result<Similarity, SimilariumError> {
+    if !game.active {
+        return validation_error!(":no_entry_sign: The game has ended, no more hints!");
+    }
+    if game.user_already_won(user_id, db).await? {
+        return validation_error!(":no_entry_sign: You already found the secret, no hint needed!");
+    }
+    if game.user_already_hinted(user_id, db).await? {
+        return validation_error!(":no_entry_sign: You've already used your hint for this game");
+    }
+
+    let rank = get_hint_rank(game.get_user_top_guess_rank(user_id, db).await?);
+    if rank < 1 {
+        return validation_error!(
+            ":no_entry_sign: You're too close to the secret for a hint to help!"
+        );
+    }
+
+    let secret = Word2Vec {
+        word: game.secret.clone(),
+    };
+    let hint = secret.get_word_at_rank(rank, similarity).await?;
+
+    // Another request for the user's hint may have got here first, so the hint is only given if
+    // it's the one that's recorded
+    let guess_count = game.get_guess_count(db).await?;
+    if !game.add_hint(user_id, guess_count as i32, db).await? {
+        return validation_error!(":no_entry_sign: You've already used your hint for this game");
+    }
+
+    Ok(hint)
+}
+
+/// Queue a clue about the secret for the model to write, which is posted privately to the user
+/// once it's ready
+///
+/// The model can take longer to respond than Slack waits for an interaction, so the clue follows
+/// the hint rather than holding it up.
+async fn queue_clue(
+    game: &Game,
+    user_id: &str,
+    db: &sqlx::PgPool,
+    similarity: &impl SimilarityBackend,
+    queue: &mut dyn AsyncQueueable,
+) -> Result<(), SimilariumError> {
+    let secret = Word2Vec {
+        word: game.secret.clone(),
+    };
+    let top_guesses = game
+        .get_guess_contexts(GuessContextOrder::Rank, 10, db)
+        .await?;
+
+    let task = AiMessageTask {
+        game_id: game.id,
+        message: AiMessage::Hint {
+            guess_count: game.get_guess_count(db).await?,
+            user_id: user_id.to_string(),
+            top_guesses: top_guesses
+                .iter()
+                .map(|guess| (guess.rank, guess.word.clone()))
+                .collect(),
+            closest_words: secret.get_closest_words(10, similarity).await?,
+        },
+    };
+    queue.insert_task(&task as &dyn AsyncRunnable).await?;
+
+    Ok(())
+}
+
+/// Give a user their hint for a game, letting them know privately on the channel
+///
+/// If the channel has the AI messages on, a clue about the secret follows the hint.
+pub async fn request_hint(
+    db: &sqlx::PgPool,
+    similarity: &impl SimilarityBackend,
+    slack_client: &impl SlackMessage,
+    queue: &mut dyn AsyncQueueable,
+    game: &Game,
+    user_id: &str,
+    token: &str,
+) -> Result<(), SimilariumError> {
+    let hint = match reveal_hint(game, user_id, db, similarity).await {
+        Ok(hint) => hint,
+        Err(SimilariumError {
+            error_type: SimilariumErrorType::ValidationError,
+            message: Some(message),
+        }) => {
+            slack_client
+                .post_ephemeral(&message, &game.channel_id, user_id, token, None)
+                .await?;
+            return Ok(());
+        }
+        Err(e) => return Err(e),
+    };
+
+    let message = format!(
+        ":bulb: Your hint: *{}* is ranked {} :bulb:",
+        hint.word, hint.rank
+    );
+    slack_client
+        .post_ephemeral(&message, &game.channel_id, user_id, token, None)
+        .await?;
+
+    // The user already has their hint, so a clue that can't be queued is only logged
+    let channel = Channel::get(&game.channel_id, db).await?;
+    if channel.is_some_and(|channel| channel.ai_enabled) {
+        if let Err(e) = queue_clue(game, user_id, db, similarity, queue).await {
+            log::warn!("Unable to queue a clue for game {}: {:?}", game.id, e);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_hint_rank_is_halfway_to_the_secret() {
+        assert_eq!(get_hint_rank(Some(100)), 50);
+        assert_eq!(get_hint_rank(Some(11)), 5);
+        assert_eq!(get_hint_rank(Some(2)), 1);
+        assert_eq!(get_hint_rank(Some(1)), 0);
+    }
+
+    #[test]
+    fn test_get_hint_rank_starts_from_max_rank() {
+        assert_eq!(get_hint_rank(None), 500);
+        assert_eq!(get_hint_rank(Some(50_000)), 500);
+    }
+}
@@ -0,0 +1,300 @@
+use crate::{
+    game::stats::get_streaks,
+    models::{Game, GameResult, GameWinnerAssociation, PlayedGame, RecentWin, Similarity},
+    slack_client::{Block, Button, SlackViews, View},
+    SimilariumError,
+};
+
+/// The action ID prefix of the buttons that link to a game thread
+pub const OPEN_GAME_ACTION: &str = "open-game";
+
+/// How many wins are listed on the Home tab
+const RECENT_WINS_COUNT: i64 = 5;
+
+/// An active game on a channel that the user plays on
+#[derive(Debug)]
+pub struct HomeGame {
+    pub channel_id: String,
+    pub thread_ts: Option<String>,
+    pub puzzle_number: i64,
+    pub best_guess: Option<Similarity>,
+    pub won: bool,
+}
+
+/// The win streaks of the user on a channel
+#[derive(Debug, PartialEq)]
+pub struct ChannelStreak {
+    pub channel_id: String,
+    pub current: i64,
+    pub longest: i64,
+}
+
+/// Everything that's shown to a user on their Home tab
+#[derive(Debug)]
+pub struct HomeDashboard {
+    pub games: Vec<HomeGame>,
+    pub streaks: Vec<ChannelStreak>,
+    pub recent_wins: Vec<RecentWin>,
+}
+
+fn get_best_guess(game: &PlayedGame) -> Option<Similarity> {
+    match (&game.best_word, game.best_rank, game.best_similarity) {
+        (Some(word), Some(rank), Some(similarity)) => Some(Similarity {
+            word: word.clone(),
+            rank,
+            similarity,
+        }),
+        _ => None,
+    }
+}
+
+/// Collect the dashboard for a user, across the channels on the team that they've played on
+pub async fn get_home_dashboard(
+    user_id: &str,
+    enterprise_id: Option<&str>,
+    team_id: &str,
+    db: &sqlx::PgPool,
+) -> Result<HomeDashboard, SimilariumError> {
+    let mut games = vec![];
+    let mut streaks = vec![];
+
+    let played = Game::get_played_by_user(user_id, enterprise_id, team_id, db).await?;
+    for channel_games in played.chunk_by(|a, b| a.channel_id == b.channel_id) {
+        let results = channel_games
+            .iter()
+            .map(|game| GameResult {
+                puzzle_number: game.puzzle_number,
+                active: game.active,
+                won: game.won,
+            })
+            .collect::<Vec<_>>();
+        let (current, longest) = get_streaks(&results);
+        streaks.push(ChannelStreak {
+            channel_id: channel_games[0].channel_id.clone(),
+            current,
+            longest,
+        });
+
+        games.extend(
+            channel_games
+                .iter()
+                .filter(|game| game.active)
+                .map(|game| HomeGame {
+                    channel_id: game.channel_id.clone(),
+                    thread_ts: game.thread_ts.clone(),
+                    puzzle_number: game.puzzle_number,
+                    best_guess: get_best_guess(game),
+                    won: game.won,
+                }),
+        );
+    }
+
+    let recent_wins = GameWinnerAssociation::get_recent_for_user(
+        user_id,
+        enterprise_id,
+        team_id,
+        RECENT_WINS_COUNT,
+        db,
+    )
+    .await?;
+
+    Ok(HomeDashboard {
+        games,
+        streaks,
+        recent_wins,
+    })
+}
+
+/// Get a link to a message, which Slack redirects to the workspace that it's in
+fn get_message_url(channel_id: &str, ts: &str) -> String {
+    format!(
+        "https://slack.com/archives/{}/p{}",
+        channel_id,
+        ts.replace('.', "")
+    )
+}
+
+fn get_game_blocks(game: &HomeGame) -> Vec<Block> {
+    let progress = match (&game.best_guess, game.won) {
+        (_, true) => "You found the secret :tada:".to_string(),
+        (Some(guess), false) => {
+            format!("Your best guess is *{}* at rank {}", guess.word, guess.rank)
+        }
+        (None, false) => "You haven't guessed yet".to_string(),
+    };
+    let mut blocks = vec![Block::section(
+        &format!(
+            "<#{}> Puzzle #{}\n{}",
+            game.channel_id, game.puzzle_number, progress
+        ),
+        None,
+    )];
+
+    if let Some(thread_ts) = &game.thread_ts {
+        let block_id = format!("{}-{}-{}", OPEN_GAME_ACTION, game.channel_id, thread_ts);
+        blocks.push(Block::actions(
+            &block_id,
+            vec![Button::link(
+                &block_id,
+                "Go to game",
+                &get_message_url(&game.channel_id, thread_ts),
+            )],
+        ));
+    }
+
+    blocks
+}
+
+/// Generate the blocks for the Home tab of a user
+pub fn get_home_blocks(dashboard: &HomeDashboard) -> Vec<Block> {
+    let mut blocks = vec![Block::header("Similarium")];
+
+    if dashboard.streaks.is_empty() && dashboard.recent_wins.is_empty() {
+        blocks.push(Block::section(
+            "You haven't played any games yet. Join a channel with a daily game, or start one \
+             with `/similarium start`",
+            None,
+        ));
+        return blocks;
+    }
+
+    blocks.push(Block::section("*Today's games*", None));
+    if dashboard.games.is_empty() {
+        blocks.push(Block::section("There are no games running right now", None));
+    }
+    blocks.extend(dashboard.games.iter().flat_map(get_game_blocks));
+
+    blocks.push(Block::divider());
+    let streaks = dashboard
+        .streaks
+        .iter()
+        .map(|streak| {
+            format!(
+                "<#{}> :fire: {} (longest {})",
+                streak.channel_id, streak.current, streak.longest
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    blocks.push(Block::section(&format!("*Streaks*\n{}", streaks), None));
+
+    let recent_wins = match dashboard.recent_wins.as_slice() {
+        [] => "No wins yet, keep guessing!".to_string(),
+        wins => wins
+            .iter()
+            .map(|win| {
+                format!(
+                    "<#{}> Puzzle #{} in {} guesses",
+                    win.channel_id, win.puzzle_number, win.guess_idx
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    };
+    blocks.push(Block::section(
+        &format!("*Recent wins*\n{}", recent_wins),
+        None,
+    ));
+
+    blocks
+}
+
+/// Publish the Home tab of a user
+pub async fn publish_home(
+    db: &sqlx::PgPool,
+    slack_client: &impl SlackViews,
+    user_id: &str,
+    enterprise_id: Option<&str>,
+    team_id: &str,
+    token: &str,
+) -> Result<(), SimilariumError> {
+    let dashboard = get_home_dashboard(user_id, enterprise_id, team_id, db).await?;
+
+    slack_client
+        .publish_view(user_id, View::home(get_home_blocks(&dashboard)), token)
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_message_url() {
+        assert_eq!(
+            get_message_url("C123", "1700000000.123456"),
+            "https://slack.com/archives/C123/p1700000000123456"
+        );
+    }
+
+    #[test]
+    fn test_get_home_blocks_without_any_games() {
+        let dashboard = HomeDashboard {
+            games: vec![],
+            streaks: vec![],
+            recent_wins: vec![],
+        };
+
+        let blocks = get_home_blocks(&dashboard);
+        assert_eq!(blocks.len(), 2);
+    }
+
+    #[test]
+    fn test_get_home_blocks_links_to_games() {
+        let dashboard = HomeDashboard {
+            games: vec![
+                HomeGame {
+                    channel_id: "C123".to_string(),
+                    thread_ts: Some("1700000000.123456".to_string()),
+                    puzzle_number: 12,
+                    best_guess: Some(Similarity {
+                        word: "apple".to_string(),
+                        rank: 42,
+                        similarity: 51.2,
+                    }),
+                    won: false,
+                },
+                HomeGame {
+                    channel_id: "C456".to_string(),
+                    thread_ts: None,
+                    puzzle_number: 3,
+                    best_guess: None,
+                    won: false,
+                },
+            ],
+            streaks: vec![ChannelStreak {
+                channel_id: "C123".to_string(),
+                current: 2,
+                longest: 5,
+            }],
+            recent_wins: vec![],
+        };
+
+        let json = serde_json::to_value(get_home_blocks(&dashboard)).unwrap();
+
+        assert_eq!(
+            json[2]["text"]["text"],
+            "<#C123> Puzzle #12\nYour best guess is *apple* at rank 42"
+        );
+        assert_eq!(
+            json[3]["elements"][0]["url"],
+            "https://slack.com/archives/C123/p1700000000123456"
+        );
+        // The game without a thread yet has nothing to link to
+        assert_eq!(
+            json[4]["text"]["text"],
+            "<#C456> Puzzle #3\nYou haven't guessed yet"
+        );
+        assert_eq!(json[5]["type"], "divider");
+        assert_eq!(
+            json[6]["text"]["text"],
+            "*Streaks*\n<#C123> :fire: 2 (longest 5)"
+        );
+        assert_eq!(
+            json[7]["text"]["text"],
+            "*Recent wins*\nNo wins yet, keep guessing!"
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{
+    game::utils::get_medal,
+    models::{Channel, GameWinnerAssociation, SolverStats},
+    slack_client::{Block, SlackMessage},
+    SimilariumError,
+};
+use chrono::{DateTime, Duration, Months, Utc};
+use serde::{Deserialize, Serialize};
+
+/// How many players are shown on a leaderboard
+const LEADERBOARD_SIZE: i64 = 10;
+
+/// The span of games that a leaderboard covers, ending at the time it's shown
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum LeaderboardPeriod {
+    Week,
+    Month,
+    All,
+}
+
+impl LeaderboardPeriod {
+    /// Get the start of the period, if it's limited, when it ends at `now`
+    pub fn since(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            LeaderboardPeriod::Week => Some(now - Duration::weeks(1)),
+            LeaderboardPeriod::Month => now.checked_sub_months(Months::new(1)),
+            LeaderboardPeriod::All => None,
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            LeaderboardPeriod::Week => "Leaderboard for the past week",
+            LeaderboardPeriod::Month => "Leaderboard for the past month",
+            LeaderboardPeriod::All => "All time leaderboard",
+        }
+    }
+}
+
+/// Get the top players on a channel for the period ending at `now`
+pub async fn get_leaderboard(
+    channel_id: &str,
+    period: LeaderboardPeriod,
+    now: DateTime<Utc>,
+    db: &sqlx::PgPool,
+) -> Result<Vec<SolverStats>, SimilariumError> {
+    GameWinnerAssociation::get_top_solvers(channel_id, period.since(now), LEADERBOARD_SIZE, db)
+        .await
+}
+
+/// Generate the blocks for a leaderboard
+pub fn get_leaderboard_blocks(period: LeaderboardPeriod, solvers: &[SolverStats]) -> Vec<Block> {
+    if solvers.is_empty() {
+        return vec![
+            Block::header(period.title()),
+            Block::section("Nobody has found a secret yet, better get guessing!", None),
+        ];
+    }
+
+    let lines = solvers
+        .iter()
+        .enumerate()
+        .map(|(idx, solver)| {
+            let position = match get_medal(idx).as_str() {
+                "" => format!("{}.", idx + 1),
+                medal => medal.to_string(),
+            };
+            format!(
+                "{} <@{}> {} {}, {} first, averaging {:.1} guesses",
+                position,
+                solver.user_id,
+                solver.wins,
+                if solver.wins == 1 { "win" } else { "wins" },
+                solver.first_solves,
+                solver.average_guesses
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    vec![Block::header(period.title()), Block::section(&lines, None)]
+}
+
+/// Post the leaderboard for a period to every channel that hasn't opted out
+///
+/// Channels without any winners in the period are skipped, and a failure on one channel doesn't
+/// stop the leaderboard being posted to the others.
+pub async fn post_leaderboards(
+    db: &sqlx::PgPool,
+    slack_client: &impl SlackMessage,
+    period: LeaderboardPeriod,
+    now: DateTime<Utc>,
+) -> Result<(), SimilariumError> {
+    for channel in Channel::get_leaderboard_channels(db).await? {
+        if let Err(e) = post_leaderboard(db, slack_client, period, now, &channel).await {
+            log::error!("Unable to post leaderboard to {}: {:?}", channel.id, e);
+        }
+    }
+
+    Ok(())
+}
+
+async fn post_leaderboard(
+    db: &sqlx::PgPool,
+    slack_client: &impl SlackMessage,
+    period: LeaderboardPeriod,
+    now: DateTime<Utc>,
+    channel: &Channel,
+) -> Result<(), SimilariumError> {
+    let solvers = get_leaderboard(&channel.id, period, now, db).await?;
+    if solvers.is_empty() {
+        return Ok(());
+    }
+
+    let token = channel.get_slack_bot_token(db).await?;
+    let blocks = get_leaderboard_blocks(period, &solvers);
+    slack_client
+        .post_message(period.title(), &channel.id, &token, Some(blocks))
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leaderboard_period_since() {
+        let now = datetime!(2024, 3, 31, 9, 0, 0);
+
+        assert_eq!(
+            LeaderboardPeriod::Week.since(now),
+            Some(datetime!(2024, 3, 24, 9, 0, 0))
+        );
+        // There's no February 31st, so the month is clamped to the last day
+        assert_eq!(
+            LeaderboardPeriod::Month.since(now),
+            Some(datetime!(2024, 2, 29, 9, 0, 0))
+        );
+        assert_eq!(LeaderboardPeriod::All.since(now), None);
+    }
+
+    #[test]
+    fn test_get_leaderboard_blocks_without_winners() {
+        let blocks = get_leaderboard_blocks(LeaderboardPeriod::Week, &[]);
+        assert_eq!(blocks.len(), 2);
+    }
+
+    #[test]
+    fn test_get_leaderboard_blocks() {
+        let solvers = (0..4)
+            .map(|idx| SolverStats {
+                user_id: format!("U{}", idx),
+                wins: 4 - idx,
+                average_guesses: 12.34,
+                best_guess: 5,
+                first_solves: 1,
+            })
+            .collect::<Vec<_>>();
+
+        let blocks = get_leaderboard_blocks(LeaderboardPeriod::Month, &solvers);
+        let json = serde_json::to_value(&blocks).unwrap();
+
+        assert_eq!(json[0]["text"]["text"], "Leaderboard for the past month");
+        assert_eq!(
+            json[1]["text"]["text"],
+            ":first_place_medal: <@U0> 4 wins, 1 first, averaging 12.3 guesses\n\
+             :second_place_medal: <@U1> 3 wins, 1 first, averaging 12.3 guesses\n\
+             :third_place_medal: <@U2> 2 wins, 1 first, averaging 12.3 guesses\n\
+             4. <@U3> 1 win, 1 first, averaging 12.3 guesses"
+        );
+    }
+}
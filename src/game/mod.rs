@@ -1,11 +1,20 @@
 mod game_management;
 mod guess;
+mod hint;
+pub mod home;
+pub mod leaderboard;
+pub mod personas;
+pub mod secret_pools;
+pub mod secrets;
+pub mod stats;
 mod target_words;
+pub mod updates;
 pub mod utils;
 
 pub use game_management::{
     end_game, get_active_games_on_channel, manual_start, schedule_game_on_channel,
-    start_game_on_channel, stop_games_on_channel,
+    start_game_on_channel, stop_games_on_channel, update_game_message,
 };
-pub use guess::submit_guess;
+pub use guess::{get_suggestion_blocks, get_suggestion_game, submit_guess, SUGGESTION_ACTION_ID};
+pub use hint::{request_hint, reveal_hint};
 pub use target_words::TARGET_WORDS;
@@ -0,0 +1,185 @@
+use crate::{
+    models::{Channel, Persona, DEFAULT_PERSONA},
+    slack_client::Block,
+    SimilariumError,
+};
+
+/// Switch the persona that the AI messages on a channel are written in
+///
+/// Turns the AI messages off if the name is None, and back on when a persona is chosen.
+pub async fn use_persona(
+    channel_id: &str,
+    persona_name: Option<&str>,
+    db: &sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let Some(mut channel) = Channel::get(channel_id, db).await? else {
+        return validation_error!(
+            ":no_entry_sign: No game is registered for the channel, did you mean to run \"start\"?"
+        );
+    };
+
+    match persona_name {
+        Some(name) => {
+            let Some(persona) = Persona::get(name, db).await? else {
+                return validation_error!(
+                    ":no_entry_sign: There's no persona called \"{}\", use \"persona list\" to see them",
+                    name
+                );
+            };
+            channel.persona = Some(persona.name);
+            channel.ai_enabled = true;
+        }
+        None => channel.ai_enabled = false,
+    }
+    channel.update(db).await?;
+
+    Ok(())
+}
+
+/// Check that a persona can be chosen with `/similarium persona [name]` and has all its prompts
+fn validate_persona(persona: &Persona) -> Result<(), SimilariumError> {
+    let name = persona.name.as_str();
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return validation_error!("Persona names must be a single word, not {:?}", name);
+    }
+    if ["list", "off"].contains(&name) {
+        return validation_error!("\"{}\" is a persona command, so it can't be a name", name);
+    }
+    for (prompt, template) in [
+        ("celebration_prompt", &persona.celebration_prompt),
+        ("taunt_prompt", &persona.taunt_prompt),
+        ("win_prompt", &persona.win_prompt),
+    ] {
+        if template.trim().is_empty() {
+            return validation_error!("The {} persona has an empty {}", name, prompt);
+        }
+    }
+
+    Ok(())
+}
+
+/// Add the personas, replacing the prompts of any that already exist
+///
+/// Nothing is imported unless every persona is valid.
+pub async fn import_personas(
+    personas: &[Persona],
+    db: &sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    for persona in personas {
+        validate_persona(persona)?;
+    }
+    for persona in personas {
+        persona.upsert(db).await?;
+    }
+
+    Ok(())
+}
+
+fn get_marker(is_current: bool) -> &'static str {
+    if is_current {
+        ":point_right:"
+    } else {
+        ":white_small_square:"
+    }
+}
+
+/// Generate the blocks listing the personas, marking the one that the channel uses
+///
+/// The current persona is None when the channel has turned the AI messages off.
+pub fn get_personas_blocks(personas: &[Persona], current: Option<&str>) -> Vec<Block> {
+    let lines = personas
+        .iter()
+        .map(|persona| {
+            let marker = get_marker(current == Some(persona.name.as_str()));
+            match persona.description.as_str() {
+                "" => format!("{} *{}*", marker, persona.name),
+                description => format!("{} *{}* - {}", marker, persona.name, description),
+            }
+        })
+        .chain([format!(
+            "{} *off* - No AI messages at all",
+            get_marker(current.is_none())
+        )])
+        .collect::<Vec<_>>();
+
+    vec![
+        Block::header("AI personas"),
+        Block::section(&lines.join("\n"), None),
+        Block::section(
+            "Switch this channel to another persona with `/similarium persona [name|off]`",
+            None,
+        ),
+    ]
+}
+
+/// Get the name of the persona that a channel uses, or None if the AI messages are off
+pub fn get_current_persona(channel: &Channel) -> Option<&str> {
+    match channel.ai_enabled {
+        true => Some(channel.persona.as_deref().unwrap_or(DEFAULT_PERSONA)),
+        false => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_persona(name: &str, description: &str) -> Persona {
+        Persona {
+            name: name.to_string(),
+            description: description.to_string(),
+            celebration_prompt: "".to_string(),
+            taunt_prompt: "".to_string(),
+            win_prompt: "".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_get_personas_blocks_marks_current_persona() {
+        let personas = vec![get_persona("friendly", "Warm"), get_persona("witty", "")];
+
+        let blocks = get_personas_blocks(&personas, Some("witty"));
+        let json = serde_json::to_value(&blocks).unwrap();
+        let lines = json[1]["text"]["text"]
+            .as_str()
+            .unwrap()
+            .lines()
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            lines,
+            vec![
+                ":white_small_square: *friendly* - Warm",
+                ":point_right: *witty*",
+                ":white_small_square: *off* - No AI messages at all",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_persona() {
+        let mut persona = get_persona("pirate", "Arr");
+        assert!(validate_persona(&persona).is_err());
+
+        persona.celebration_prompt = "Celebrate {user}".to_string();
+        persona.taunt_prompt = "Taunt".to_string();
+        persona.win_prompt = "Win".to_string();
+        assert!(validate_persona(&persona).is_ok());
+
+        for name in ["", "sea dog", "off", "list"] {
+            persona.name = name.to_string();
+            assert!(validate_persona(&persona).is_err(), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_get_personas_blocks_when_off() {
+        let blocks = get_personas_blocks(&[get_persona("witty", "")], None);
+        let json = serde_json::to_value(&blocks).unwrap();
+
+        assert!(json[1]["text"]["text"]
+            .as_str()
+            .unwrap()
+            .ends_with(":point_right: *off* - No AI messages at all"));
+    }
+}
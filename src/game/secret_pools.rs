@@ -0,0 +1,125 @@
+use crate::{
+    game::TARGET_WORDS,
+    models::{Channel, SecretPool, SecretPoolSummary},
+    slack_client::Block,
+    SimilariumError,
+};
+
+/// The name of the built-in target words, which channels use unless they choose another pool
+pub const DEFAULT_POOL: &str = "default";
+
+/// Switch the pool that a channel draws its secrets from, starting with the next game
+pub async fn use_secret_pool(
+    channel_id: &str,
+    pool_name: &str,
+    db: &sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let Some(mut channel) = Channel::get(channel_id, db).await? else {
+        return validation_error!(
+            ":no_entry_sign: No game is registered for the channel, did you mean to run \"start\"?"
+        );
+    };
+
+    channel.secret_pool = match pool_name {
+        DEFAULT_POOL => None,
+        _ => match SecretPool::get(pool_name, db).await? {
+            Some(pool) if !pool.get_words(db).await?.is_empty() => Some(pool.name),
+            Some(_) => {
+                return validation_error!(":no_entry_sign: The pool \"{}\" has no words", pool_name)
+            }
+            None => {
+                return validation_error!(
+                    ":no_entry_sign: There's no pool called \"{}\", use \"pool list\" to see them",
+                    pool_name
+                )
+            }
+        },
+    };
+    channel.update(db).await?;
+
+    Ok(())
+}
+
+fn get_marker(is_current: bool) -> &'static str {
+    if is_current {
+        ":point_right:"
+    } else {
+        ":white_small_square:"
+    }
+}
+
+/// Generate the blocks listing the secret pools, marking the one that the channel uses
+pub fn get_secret_pools_blocks(pools: &[SecretPoolSummary], current: Option<&str>) -> Vec<Block> {
+    let mut lines = vec![format!(
+        "{} *{}* ({} words) - The standard list of secrets",
+        get_marker(current.is_none()),
+        DEFAULT_POOL,
+        TARGET_WORDS.len()
+    )];
+    lines.extend(pools.iter().map(|pool| {
+        let marker = get_marker(current == Some(pool.name.as_str()));
+        match pool.description.as_str() {
+            "" => format!("{} *{}* ({} words)", marker, pool.name, pool.word_count),
+            description => format!(
+                "{} *{}* ({} words) - {}",
+                marker, pool.name, pool.word_count, description
+            ),
+        }
+    }));
+
+    vec![
+        Block::header("Secret pools"),
+        Block::section(&lines.join("\n"), None),
+        Block::section(
+            "Switch this channel to another pool with `/similarium pool use [name]`",
+            None,
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_secret_pools_blocks_marks_current_pool() {
+        let pools = vec![
+            SecretPoolSummary {
+                name: "easy".to_string(),
+                description: "Common nouns".to_string(),
+                word_count: 100,
+            },
+            SecretPoolSummary {
+                name: "offsite".to_string(),
+                description: "".to_string(),
+                word_count: 20,
+            },
+        ];
+
+        let blocks = get_secret_pools_blocks(&pools, Some("offsite"));
+        let json = serde_json::to_value(&blocks).unwrap();
+        let lines = json[1]["text"]["text"]
+            .as_str()
+            .unwrap()
+            .lines()
+            .collect::<Vec<_>>();
+
+        assert!(lines[0].starts_with(":white_small_square: *default*"));
+        assert_eq!(
+            lines[1],
+            ":white_small_square: *easy* (100 words) - Common nouns"
+        );
+        assert_eq!(lines[2], ":point_right: *offsite* (20 words)");
+    }
+
+    #[test]
+    fn test_get_secret_pools_blocks_defaults_to_target_words() {
+        let blocks = get_secret_pools_blocks(&[], None);
+        let json = serde_json::to_value(&blocks).unwrap();
+
+        assert!(json[1]["text"]["text"]
+            .as_str()
+            .unwrap()
+            .starts_with(":point_right: *default*"));
+    }
+}
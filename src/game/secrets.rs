@@ -0,0 +1,349 @@
+use crate::{
+    game::{secret_pools::DEFAULT_POOL, TARGET_WORDS},
+    models::{Channel, ChannelSecret, SecretPool},
+    SimilariumError,
+};
+use rand::seq::SliceRandom;
+use rand_pcg::Pcg64;
+use rand_seeder::Seeder;
+use std::collections::HashMap;
+
+/// Channels are warned when they have this many unused secrets left, about a week of games
+pub const LOW_SECRETS_WARNING: usize = 7;
+
+/// The secret picked for a puzzle, along with how many unused secrets are left after it
+#[derive(Debug, Eq, PartialEq)]
+pub struct SecretChoice {
+    pub secret: String,
+    pub remaining: usize,
+}
+
+/// Randomly sort the words, using the seed so the order is always the same for the same seed
+fn shuffle_words<'a, T: AsRef<str>>(seed: &str, words: &'a [T]) -> Vec<&'a T> {
+    let mut rng: Pcg64 = Seeder::from(seed).make_rng();
+
+    let mut words = words.iter().collect::<Vec<_>>();
+    words.shuffle(&mut rng);
+
+    words
+}
+
+/// Get the secret word from the built-in target words for a seed and puzzle number
+///
+/// The channel_id is used as a random seed, then the puzzle number is used to pick the randomly
+/// sorted target words list. This doesn't know which secrets have been used already, see
+/// `choose_secret` for that.
+pub fn get_secret(seed: &str, puzzle_number: i64) -> String {
+    let words = shuffle_words(seed, TARGET_WORDS);
+
+    words[puzzle_number as usize % words.len()].to_string()
+}
+
+/// Pick a secret for a puzzle number from the words, skipping any that have been used
+///
+/// The words are randomly sorted with the seed and the search for an unused word starts at the
+/// puzzle number, so a channel without any history gets the same secret as `get_secret` would
+/// give. If every word has been used, the one used longest ago is picked again.
+pub fn choose_from_words<T: AsRef<str>>(
+    seed: &str,
+    words: &[T],
+    puzzle_number: i64,
+    used: &HashMap<String, i64>,
+) -> SecretChoice {
+    let words = shuffle_words(seed, words);
+    let unused_count = words
+        .iter()
+        .filter(|word| !used.contains_key(word.as_ref()))
+        .count();
+
+    let start = puzzle_number as usize % words.len();
+    let unused = words
+        .iter()
+        .cycle()
+        .skip(start)
+        .take(words.len())
+        .find(|word| !used.contains_key(word.as_ref()));
+
+    match unused {
+        Some(word) => SecretChoice {
+            secret: word.as_ref().to_string(),
+            remaining: unused_count - 1,
+        },
+        None => {
+            let word = words
+                .iter()
+                .min_by_key(|word| used[word.as_ref()])
+                .expect("The words are never empty");
+            SecretChoice {
+                secret: word.as_ref().to_string(),
+                remaining: 0,
+            }
+        }
+    }
+}
+
+/// Check that the words of a secret pool can be picked from
+///
+/// Channels without a pool use the built-in target words.
+fn get_words_to_pick_from(
+    pool_name: Option<&str>,
+    words: Vec<String>,
+) -> Result<Vec<String>, SimilariumError> {
+    let Some(pool_name) = pool_name else {
+        return Ok(TARGET_WORDS.iter().map(|word| word.to_string()).collect());
+    };
+    if words.is_empty() {
+        return validation_error!("The secret pool {} has no words", pool_name);
+    }
+
+    Ok(words)
+}
+
+/// Get the words in the channel's secret pool, see `get_words_to_pick_from`
+async fn get_pool_words(
+    channel: &Channel,
+    db: &sqlx::PgPool,
+) -> Result<Vec<String>, SimilariumError> {
+    let words = match &channel.secret_pool {
+        Some(pool_name) => match SecretPool::get(pool_name, db).await? {
+            Some(pool) => pool.get_words(db).await?,
+            None => vec![],
+        },
+        None => vec![],
+    };
+
+    get_words_to_pick_from(channel.secret_pool.as_deref(), words)
+}
+
+/// Pick the secret for a puzzle on a channel, from the channel's secret pool
+///
+/// Secrets that the channel has already played are skipped, until the whole pool has been used.
+/// The secret isn't recorded as used until `record_secret` is called, so this can be used to
+/// look ahead at the secret of the next game.
+pub async fn choose_secret(
+    channel: &Channel,
+    puzzle_number: i64,
+    db: &sqlx::PgPool,
+) -> Result<SecretChoice, SimilariumError> {
+    let words = get_pool_words(channel, db).await?;
+    let used = ChannelSecret::get_used(&channel.id, db).await?;
+
+    Ok(choose_from_words(&channel.id, &words, puzzle_number, &used))
+}
+
+/// Get the secret for a puzzle on a channel, see `choose_secret`
+pub async fn get_channel_secret(
+    channel: &Channel,
+    puzzle_number: i64,
+    db: &sqlx::PgPool,
+) -> Result<String, SimilariumError> {
+    Ok(choose_secret(channel, puzzle_number, db).await?.secret)
+}
+
+/// Get the secret of the next game on every active channel
+///
+/// Their similarities are computed ahead of the games, so they shouldn't be pruned. Channels that
+/// a secret can't be picked for are skipped.
+pub async fn get_upcoming_secrets(db: &sqlx::PgPool) -> Result<Vec<String>, SimilariumError> {
+    let mut secrets = vec![];
+    for next_game in Channel::get_next_games(db).await? {
+        let words =
+            match get_words_to_pick_from(next_game.secret_pool.as_deref(), next_game.pool_words) {
+                Ok(words) => words,
+                Err(e) => {
+                    log::error!(
+                        "Unable to get the upcoming secret of {}: {:?}",
+                        next_game.channel_id,
+                        e
+                    );
+                    continue;
+                }
+            };
+        let used = next_game
+            .used_secrets
+            .into_iter()
+            .zip(next_game.used_puzzle_numbers)
+            .collect();
+        secrets.push(
+            choose_from_words(
+                &next_game.channel_id,
+                &words,
+                next_game.puzzle_number,
+                &used,
+            )
+            .secret,
+        );
+    }
+
+    Ok(secrets)
+}
+
+/// Record that a secret has been played on a channel, so it isn't picked again
+pub async fn record_secret(
+    channel_id: &str,
+    secret: &str,
+    puzzle_number: i64,
+    db: &sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    ChannelSecret {
+        channel_id: channel_id.to_string(),
+        secret: secret.to_string(),
+        puzzle_number,
+    }
+    .upsert(db)
+    .await
+}
+
+/// Get a warning for the channel if the secret pool is nearly used up
+pub fn get_low_secrets_warning(channel: &Channel, remaining: usize) -> Option<String> {
+    if remaining > LOW_SECRETS_WARNING {
+        return None;
+    }
+
+    let pool_name = channel.secret_pool.as_deref().unwrap_or(DEFAULT_POOL);
+    Some(match remaining {
+        0 => format!(
+            ":warning: Every secret in the *{}* pool has been played, so secrets will start \
+             repeating. Switch to another pool with `/similarium pool use [name]`",
+            pool_name
+        ),
+        remaining => format!(
+            ":warning: Only {} unplayed {} left in the *{}* pool. Switch to another pool with \
+             `/similarium pool use [name]` before secrets start repeating",
+            remaining,
+            if remaining == 1 { "secret" } else { "secrets" },
+            pool_name
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORDS: [&str; 4] = ["apple", "banana", "cherry", "grape"];
+
+    fn used(secrets: &[(&str, i64)]) -> HashMap<String, i64> {
+        secrets
+            .iter()
+            .map(|(secret, puzzle_number)| (secret.to_string(), *puzzle_number))
+            .collect()
+    }
+
+    fn get_channel(secret_pool: Option<&str>) -> Channel {
+        Channel {
+            id: "channel_id".to_string(),
+            team_id: "team_id".to_string(),
+            hour: 0,
+            minute: 0,
+            active: true,
+            timezone: "UTC".to_string(),
+            leaderboard_enabled: true,
+            secret_pool: secret_pool.map(|name| name.to_string()),
+            enterprise_id: None,
+            persona: None,
+            ai_enabled: true,
+        }
+    }
+
+    #[test]
+    fn test_get_secret_is_consistent() {
+        let seed = "foobar";
+
+        let secret1 = get_secret(seed, 0);
+        let secret2 = get_secret(seed, 0);
+
+        assert_eq!(secret1, secret2);
+    }
+
+    #[test]
+    fn test_get_secret_gives_different_values_for_different_seeds() {
+        let seed1 = "foobar";
+        let seed2 = "bazqux";
+
+        let secret1 = get_secret(seed1, 0);
+        let secret2 = get_secret(seed2, 0);
+
+        assert_ne!(secret1, secret2);
+    }
+
+    #[test]
+    fn test_get_secret_wraps_around() {
+        let total_target_words = TARGET_WORDS.len();
+        let seed = "foobar";
+
+        let secret1 = get_secret(seed, 0);
+        let secret2 = get_secret(seed, total_target_words as i64);
+
+        assert_eq!(secret1, secret2);
+    }
+
+    #[test]
+    fn test_choose_from_words_without_history_matches_get_secret() {
+        for puzzle_number in [0, 1, 42, 9001] {
+            assert_eq!(
+                choose_from_words("foobar", TARGET_WORDS, puzzle_number, &HashMap::new()).secret,
+                get_secret("foobar", puzzle_number)
+            );
+        }
+    }
+
+    #[test]
+    fn test_choose_from_words_uses_every_word_before_repeating() {
+        let mut history = HashMap::new();
+        let mut secrets = vec![];
+        for puzzle_number in 0..4 {
+            let choice = choose_from_words("foobar", &WORDS, puzzle_number, &history);
+            assert_eq!(choice.remaining, 3 - puzzle_number as usize);
+            history.insert(choice.secret.clone(), puzzle_number);
+            secrets.push(choice.secret);
+        }
+
+        let mut sorted = secrets.clone();
+        sorted.sort();
+        assert_eq!(sorted, WORDS);
+
+        // Once every word is used, the one used longest ago comes back first
+        let choice = choose_from_words("foobar", &WORDS, 4, &history);
+        assert_eq!(choice.secret, secrets[0]);
+        assert_eq!(choice.remaining, 0);
+    }
+
+    #[test]
+    fn test_choose_from_words_skips_used_words() {
+        let first = choose_from_words("foobar", &WORDS, 0, &HashMap::new()).secret;
+
+        // Even when the puzzle number wraps around, a used word isn't picked again
+        let choice = choose_from_words("foobar", &WORDS, 4, &used(&[(&first, 0)]));
+        assert_ne!(choice.secret, first);
+        assert_eq!(choice.remaining, 2);
+    }
+
+    #[test]
+    fn test_choose_from_words_ignores_history_outside_the_pool() {
+        let history = used(&[("zebra", 0), ("yak", 1)]);
+        let choice = choose_from_words("foobar", &WORDS, 0, &history);
+
+        assert_eq!(
+            choice.secret,
+            choose_from_words("foobar", &WORDS, 0, &HashMap::new()).secret
+        );
+        assert_eq!(choice.remaining, 3);
+    }
+
+    #[test]
+    fn test_get_low_secrets_warning() {
+        let channel = get_channel(None);
+        assert_eq!(
+            get_low_secrets_warning(&channel, LOW_SECRETS_WARNING + 1),
+            None
+        );
+
+        let warning = get_low_secrets_warning(&channel, 1).unwrap();
+        assert!(warning.contains("Only 1 unplayed secret left in the *default* pool"));
+
+        let channel = get_channel(Some("offsite"));
+        let warning = get_low_secrets_warning(&channel, 0).unwrap();
+        assert!(warning.contains("Every secret in the *offsite* pool has been played"));
+    }
+}
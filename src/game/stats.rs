@@ -0,0 +1,293 @@
+use crate::{
+    models::{Game, GameResult, GameWinnerAssociation, Guess, SolverStats},
+    slack_client::Block,
+    SimilariumError,
+};
+
+/// The buckets that winning guess numbers are grouped into, as inclusive ranges
+const DISTRIBUTION_BUCKETS: [(i64, i64); 6] = [
+    (1, 10),
+    (11, 25),
+    (26, 50),
+    (51, 100),
+    (101, 200),
+    (201, i64::MAX),
+];
+
+/// The widest a bar in the guess distribution can be
+const DISTRIBUTION_BAR_WIDTH: i64 = 20;
+
+#[derive(Debug, PartialEq)]
+pub struct PlayerStats {
+    pub games_played: i64,
+    pub games_won: i64,
+    pub average_guesses: Option<f64>,
+    pub best_guess: Option<i64>,
+    pub current_streak: i64,
+    pub longest_streak: i64,
+    /// How many wins took a number of guesses within each of the `DISTRIBUTION_BUCKETS`
+    pub guess_distribution: Vec<i64>,
+}
+
+impl PlayerStats {
+    pub fn win_rate(&self) -> f64 {
+        if self.games_played == 0 {
+            return 0.0;
+        }
+        self.games_won as f64 / self.games_played as f64 * 100.0
+    }
+}
+
+/// Get the current and longest win streaks from the results of every game on a channel
+///
+/// A streak is broken by any game that wasn't won, including games that weren't played. A game
+/// that is still active doesn't break the current streak, as it can still be won.
+pub fn get_streaks(results: &[GameResult]) -> (i64, i64) {
+    let mut longest = 0;
+    let mut streak = 0;
+    for result in results {
+        if result.won {
+            streak += 1;
+            longest = longest.max(streak);
+        } else if !result.active {
+            streak = 0;
+        }
+    }
+
+    let current = results
+        .iter()
+        .rev()
+        .skip_while(|result| result.active && !result.won)
+        .take_while(|result| result.won)
+        .count() as i64;
+
+    (current, longest)
+}
+
+/// Group the guess numbers of wins into the `DISTRIBUTION_BUCKETS`
+pub fn get_guess_distribution(guess_numbers: &[i64]) -> Vec<i64> {
+    DISTRIBUTION_BUCKETS
+        .iter()
+        .map(|(low, high)| {
+            guess_numbers
+                .iter()
+                .filter(|guess_num| (low..=high).contains(guess_num))
+                .count() as i64
+        })
+        .collect()
+}
+
+fn get_bucket_label(low: i64, high: i64) -> String {
+    match high {
+        i64::MAX => format!("{}+", low),
+        _ => format!("{}-{}", low, high),
+    }
+}
+
+/// Collect the stats for a user on a channel
+pub async fn get_player_stats(
+    channel_id: &str,
+    user_id: &str,
+    db: &sqlx::PgPool,
+) -> Result<PlayerStats, SimilariumError> {
+    let games_played = Guess::count_games_played(channel_id, user_id, db).await?;
+    let wins = GameWinnerAssociation::get_for_user_on_channel(channel_id, user_id, db).await?;
+    let results = Game::get_results_for_user(channel_id, user_id, db).await?;
+
+    let guess_numbers = wins.iter().map(|win| win.guess_idx).collect::<Vec<_>>();
+    let (current_streak, longest_streak) = get_streaks(&results);
+
+    Ok(PlayerStats {
+        games_played,
+        games_won: wins.len() as i64,
+        average_guesses: match guess_numbers.len() {
+            0 => None,
+            len => Some(guess_numbers.iter().sum::<i64>() as f64 / len as f64),
+        },
+        best_guess: guess_numbers.iter().min().copied(),
+        current_streak,
+        longest_streak,
+        guess_distribution: get_guess_distribution(&guess_numbers),
+    })
+}
+
+/// Generate the blocks for the stats of a user
+pub fn get_player_stats_blocks(user_id: &str, stats: &PlayerStats) -> Vec<Block> {
+    if stats.games_played == 0 {
+        return vec![Block::section(
+            &format!("<@{}> hasn't played any games on this channel yet", user_id),
+            None,
+        )];
+    }
+
+    let average_guesses = stats
+        .average_guesses
+        .map_or("-".to_string(), |average| format!("{:.1}", average));
+    let best_guess = stats
+        .best_guess
+        .map_or("-".to_string(), |best| best.to_string());
+
+    let mut blocks = vec![
+        Block::header("Player stats"),
+        Block::section(
+            &format!("Stats for <@{}> on this channel", user_id),
+            Some(vec![
+                &format!("*Games played*\n{}", stats.games_played),
+                &format!("*Games won*\n{}", stats.games_won),
+                &format!("*Win rate*\n{:.0}%", stats.win_rate()),
+                &format!("*Average guesses to win*\n{}", average_guesses),
+                &format!("*Best win*\n{}", best_guess),
+                &format!(
+                    "*Streak*\n{} (longest {})",
+                    stats.current_streak, stats.longest_streak
+                ),
+            ]),
+        ),
+    ];
+
+    if stats.games_won > 0 {
+        let max = stats.guess_distribution.iter().max().copied().unwrap_or(0);
+        let lines = DISTRIBUTION_BUCKETS
+            .iter()
+            .zip(&stats.guess_distribution)
+            .map(|((low, high), count)| {
+                let width = match max {
+                    0 => 0,
+                    max => (count * DISTRIBUTION_BAR_WIDTH + max - 1) / max,
+                };
+                format!(
+                    "`{:>7}` {} {}",
+                    get_bucket_label(*low, *high),
+                    "█".repeat(width as usize),
+                    count
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        blocks.push(Block::section(
+            &format!("*Guesses to win*\n{}", lines),
+            None,
+        ));
+    }
+
+    blocks
+}
+
+/// Generate the blocks for the top solvers on a channel
+pub fn get_channel_stats_blocks(solvers: &[SolverStats]) -> Vec<Block> {
+    if solvers.is_empty() {
+        return vec![Block::section(
+            "Nobody has found a secret on this channel yet",
+            None,
+        )];
+    }
+
+    let lines = solvers
+        .iter()
+        .enumerate()
+        .map(|(idx, solver)| {
+            format!(
+                "{}. <@{}> with {} {}, averaging {:.1} guesses (best {})",
+                idx + 1,
+                solver.user_id,
+                solver.wins,
+                if solver.wins == 1 { "win" } else { "wins" },
+                solver.average_guesses,
+                solver.best_guess
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    vec![Block::header("Top solvers"), Block::section(&lines, None)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(puzzle_number: i64, active: bool, won: bool) -> GameResult {
+        GameResult {
+            puzzle_number,
+            active,
+            won,
+        }
+    }
+
+    #[test]
+    fn test_get_streaks_with_no_games() {
+        assert_eq!(get_streaks(&[]), (0, 0));
+    }
+
+    #[test]
+    fn test_get_streaks() {
+        let results = vec![
+            result(1, false, true),
+            result(2, false, true),
+            result(3, false, true),
+            result(4, false, false),
+            result(5, false, true),
+            result(6, false, true),
+        ];
+
+        assert_eq!(get_streaks(&results), (2, 3));
+    }
+
+    #[test]
+    fn test_get_streaks_ignores_active_game_that_is_not_won_yet() {
+        let results = vec![
+            result(1, false, true),
+            result(2, false, true),
+            result(3, true, false),
+        ];
+
+        assert_eq!(get_streaks(&results), (2, 2));
+    }
+
+    #[test]
+    fn test_get_streaks_counts_active_game_that_is_won() {
+        let results = vec![result(1, false, true), result(2, true, true)];
+
+        assert_eq!(get_streaks(&results), (2, 2));
+    }
+
+    #[test]
+    fn test_get_streaks_broken_by_last_game() {
+        let results = vec![result(1, false, true), result(2, false, false)];
+
+        assert_eq!(get_streaks(&results), (0, 1));
+    }
+
+    #[test]
+    fn test_get_guess_distribution() {
+        assert_eq!(
+            get_guess_distribution(&[1, 10, 11, 50, 51, 150, 9001]),
+            vec![2, 1, 1, 1, 1, 1]
+        );
+        assert_eq!(get_guess_distribution(&[]), vec![0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_get_bucket_label() {
+        assert_eq!(get_bucket_label(1, 10), "1-10");
+        assert_eq!(get_bucket_label(201, i64::MAX), "201+");
+    }
+
+    #[test]
+    fn test_win_rate() {
+        let mut stats = PlayerStats {
+            games_played: 0,
+            games_won: 0,
+            average_guesses: None,
+            best_guess: None,
+            current_streak: 0,
+            longest_streak: 0,
+            guess_distribution: vec![],
+        };
+        assert_eq!(stats.win_rate(), 0.0);
+
+        stats.games_played = 4;
+        stats.games_won = 3;
+        assert_eq!(stats.win_rate(), 75.0);
+    }
+}
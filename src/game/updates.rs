@@ -0,0 +1,82 @@
+use crate::{
+    game::update_game_message,
+    models::Game,
+    slack_client::{SlackClient, SlackMessage},
+    SimilariumError,
+};
+use fang::{asynk::async_queue::AsyncQueue, NoTls};
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use uuid::Uuid;
+
+/// Coalesces bursts of updates to a game message into a single `chat_update`
+///
+/// The first guess on a game schedules an update once the window has passed, and any guesses in
+/// the meantime are picked up by that same update, since the board is only generated when the
+/// update goes out. The updates are coalesced per process, so each API process sends its own.
+pub struct GameMessageUpdater<S = SlackClient> {
+    window: Duration,
+    pending: Mutex<HashSet<Uuid>>,
+    db: sqlx::PgPool,
+    slack_client: S,
+    queue: AsyncQueue<NoTls>,
+}
+
+impl<S: SlackMessage + 'static> GameMessageUpdater<S> {
+    pub fn new(
+        window: Duration,
+        db: sqlx::PgPool,
+        slack_client: S,
+        queue: AsyncQueue<NoTls>,
+    ) -> Self {
+        GameMessageUpdater {
+            window,
+            pending: Mutex::new(HashSet::new()),
+            db,
+            slack_client,
+            queue,
+        }
+    }
+
+    /// Mark the game as having an update pending
+    ///
+    /// Returns false if there's already one pending, which will include this update as well
+    fn begin_update(&self, game_id: Uuid) -> bool {
+        self.pending.lock().unwrap().insert(game_id)
+    }
+
+    /// Send the pending update for a game, with the board as it is now
+    pub async fn flush(&self, game_id: Uuid, token: &str) -> Result<(), SimilariumError> {
+        // Clear the pending update before generating the board, so that a guess that comes in
+        // after this point schedules another update rather than being missed
+        self.pending.lock().unwrap().remove(&game_id);
+
+        let Some(game) = Game::get_by_id(game_id, &self.db).await? else {
+            return Ok(());
+        };
+        let mut queue = self.queue.clone();
+        update_game_message(&self.db, &self.slack_client, &mut queue, &game, token).await
+    }
+
+    /// Update the game message once the window has passed, along with any other updates that
+    /// are requested in the meantime
+    pub fn request_update(self: &Arc<Self>, game: &Game, token: &str) {
+        if !self.begin_update(game.id) {
+            log::debug!("Update already pending for game {}", game.id);
+            return;
+        }
+
+        let updater = self.clone();
+        let game_id = game.id;
+        let token = token.to_string();
+        actix_web::rt::spawn(async move {
+            tokio::time::sleep(updater.window).await;
+            if let Err(e) = updater.flush(game_id, &token).await {
+                log::error!("Error updating game {}: {}", game_id, e);
+            }
+        });
+    }
+}
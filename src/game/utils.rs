@@ -1,13 +1,9 @@
 use crate::{
-    game::TARGET_WORDS,
     models::{Game, GuessContextOrder},
     slack_client::Block,
     SimilariumError,
 };
 use chrono::{DateTime, Utc};
-use rand::seq::SliceRandom;
-use rand_pcg::Pcg64;
-use rand_seeder::Seeder;
 
 /// Generate the header for the puzzle of the day
 pub fn get_header_text(date: DateTime<Utc>, puzzle_number: i64) -> String {
@@ -15,7 +11,7 @@ pub fn get_header_text(date: DateTime<Utc>, puzzle_number: i64) -> String {
     format!("{puzzle_date} - Puzzle number {puzzle_number}")
 }
 
-fn get_medal(idx: usize) -> String {
+pub fn get_medal(idx: usize) -> String {
     match idx {
         0 => ":first_place_medal:",
         1 => ":second_place_medal:",
@@ -28,11 +24,12 @@ fn get_medal(idx: usize) -> String {
 /// Generate header body of a game for Slack message
 ///
 /// If the game is active, the guess count is shown
-/// If there are any winners, they are always shown
+/// If there are any winners, they are always shown, marking those that used a hint
 /// If the game is not active, the secret is shown
 pub async fn get_header_body(game: &Game, db: &sqlx::PgPool) -> String {
     let guesses = game.get_guess_count(db).await.unwrap_or(0);
     let winners = game.get_winners(db).await.unwrap_or(vec![]);
+    let hinted_user_ids = game.get_hinted_user_ids(db).await.unwrap_or(vec![]);
 
     let mut lines: Vec<String> = vec![];
 
@@ -45,11 +42,17 @@ pub async fn get_header_body(game: &Game, db: &sqlx::PgPool) -> String {
     if !winners.is_empty() {
         lines.push("*Winners*".to_string());
         for (idx, winner) in winners.iter().enumerate() {
+            let hinted = if hinted_user_ids.contains(&winner.user_id) {
+                " with a hint :bulb:"
+            } else {
+                ""
+            };
             lines.push(format!(
-                "{} <@{}> on guess {}",
+                "{} <@{}> on guess {}{}",
                 get_medal(idx),
                 winner.user_id,
-                winner.guess_idx
+                winner.guess_idx,
+                hinted
             ));
         }
         lines.join("\n");
@@ -101,6 +104,7 @@ pub async fn get_game_blocks(
     // Show input
     if game.active {
         blocks.push(Block::guess_input());
+        blocks.push(Block::hint_button());
     }
 
     Ok(blocks)
@@ -125,6 +129,48 @@ pub fn get_help_blocks() -> Vec<Block> {
             if there is one",
             Some(vec!["Stop a daily puzzle", "`/similarium stop`"]),
         ),
+        Block::section(
+            "*Get a hint*\nReveal a word halfway between your best guess \
+            and the secret. You can only get one hint per game, and it's \
+            marked next to your name if you win",
+            Some(vec!["Get a hint", "`/similarium hint`"]),
+        ),
+        Block::section(
+            "*See your stats*\nShow how many games you've played and won on \
+            this channel, along with your streaks. Mention someone to see their \
+            stats instead, or use \"channel\" to see the top solvers",
+            Some(vec![
+                "See stats",
+                "`/similarium stats [@user]`\n`/similarium stats channel`",
+            ]),
+        ),
+        Block::section(
+            "*See the leaderboard*\nShow the top players on this channel for \
+            the past week, month or all time. The weekly and monthly leaderboards \
+            are also posted to the channel, unless turned off",
+            Some(vec![
+                "See the leaderboard",
+                "`/similarium leaderboard [week|month|all]`\n`/similarium leaderboard [on|off]`",
+            ]),
+        ),
+        Block::section(
+            "*Choose the secrets*\nList the pools of words that secrets can \
+            be drawn from, or switch this channel to another pool, starting \
+            with the next game",
+            Some(vec![
+                "Choose the secrets",
+                "`/similarium pool list`\n`/similarium pool use [name]`",
+            ]),
+        ),
+        Block::section(
+            "*Choose the AI persona*\nList the personas that the celebrations \
+            and taunts on this channel can be written in, switch to another \
+            one, or turn the AI messages off",
+            Some(vec![
+                "Choose the AI persona",
+                "`/similarium persona list`\n`/similarium persona [name|off]`",
+            ]),
+        ),
         Block::section(
             "*About*",
             Some(vec![
@@ -135,23 +181,6 @@ pub fn get_help_blocks() -> Vec<Block> {
     ]
 }
 
-/// Get the secret word for a channel and puzzle number
-///
-/// The channel_id is used as a random seed, then the puzzle number is used to pick the randomly
-/// sorted target words list.
-pub fn get_secret(seed: &str, puzzle_number: i64) -> String {
-    let mut rng: Pcg64 = Seeder::from(seed).make_rng();
-
-    // Get a copy of the target words
-    let mut target_words = TARGET_WORDS.to_vec();
-
-    // Shuffle..
-    target_words.shuffle(&mut rng);
-
-    // Then return the puzzle_number-th word, making sure to wrap around
-    target_words[puzzle_number as usize % target_words.len()].to_string()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,38 +195,6 @@ mod tests {
         );
     }
 
-    #[test]
-    fn test_get_secret_is_consistent() {
-        let seed = "foobar";
-
-        let secret1 = get_secret(seed, 0);
-        let secret2 = get_secret(seed, 0);
-
-        assert_eq!(secret1, secret2);
-    }
-
-    #[test]
-    fn test_get_secret_gives_different_values_for_different_seeds() {
-        let seed1 = "foobar";
-        let seed2 = "bazqux";
-
-        let secret1 = get_secret(seed1, 0);
-        let secret2 = get_secret(seed2, 0);
-
-        assert_ne!(secret1, secret2);
-    }
-
-    #[test]
-    fn test_get_secret_wraps_around() {
-        let total_target_words = TARGET_WORDS.len();
-        let seed = "foobar";
-
-        let secret1 = get_secret(seed, 0);
-        let secret2 = get_secret(seed, total_target_words as i64);
-
-        assert_eq!(secret1, secret2);
-    }
-
     #[test]
     fn test_get_medal() {
         assert_eq!(get_medal(0), ":first_place_medal:");
@@ -0,0 +1,252 @@
+mod reader;
+
+use crate::SimilariumError;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+pub use reader::{VectorFormat, VectorReader};
+
+/// The dimensions of the vectors in the `word2vec` table
+pub const VECTOR_DIMENSIONS: usize = 300;
+
+const DEFAULT_BATCH_SIZE: usize = 10_000;
+
+#[derive(Debug)]
+pub struct ImportOptions {
+    pub path: PathBuf,
+    pub format: VectorFormat,
+    pub allow_list: Option<PathBuf>,
+    pub deny_list: Option<PathBuf>,
+    pub batch_size: usize,
+}
+
+impl ImportOptions {
+    pub fn new(path: PathBuf) -> Self {
+        ImportOptions {
+            format: VectorFormat::from_path(&path),
+            path,
+            allow_list: None,
+            deny_list: None,
+            batch_size: DEFAULT_BATCH_SIZE,
+        }
+    }
+}
+
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct ImportSummary {
+    /// How many entries were read from the vector file
+    pub read: usize,
+    /// How many new words were inserted into the database
+    pub inserted: usize,
+    /// How many words were already in the database from a previous import
+    pub existing: usize,
+    /// How many words were skipped by the allow and deny lists
+    pub filtered: usize,
+}
+
+/// Read a word list, with one word per line
+///
+/// This is the format of the lists that `scripts/download-wordlists.sh` produces
+pub fn read_word_list(path: &Path) -> Result<HashSet<String>, SimilariumError> {
+    let file = BufReader::new(File::open(path)?);
+    let mut words = HashSet::new();
+    for line in file.lines() {
+        let line = line?;
+        let word = line.trim();
+        if !word.is_empty() {
+            words.insert(word.to_string());
+        }
+    }
+
+    Ok(words)
+}
+
+/// Format a vector as a Postgres array literal, such as '{0.1,0.2,0.3}'
+fn format_vector(vec: &[f32]) -> String {
+    let values = vec
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    format!("{{{}}}", values)
+}
+
+/// Escape a value for the COPY text format
+fn escape_copy_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+/// Insert a batch of rows, in the COPY text format, into the word2vec table
+///
+/// The rows are copied into a temporary staging table first so that words that already exist are
+/// skipped, rather than failing the whole batch. Each batch is committed on its own, which means
+/// an interrupted import keeps everything up to the last full batch.
+async fn insert_batch(batch: &[u8], db: &sqlx::PgPool) -> Result<u64, SimilariumError> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query("CREATE TEMPORARY TABLE word2vec_import (LIKE word2vec) ON COMMIT DROP")
+        .execute(&mut *tx)
+        .await?;
+
+    let mut copy = tx
+        .copy_in_raw("COPY word2vec_import (word, vec) FROM STDIN")
+        .await?;
+    copy.send(batch).await?;
+    copy.finish().await?;
+
+    let inserted = sqlx::query(
+        r#"
+        INSERT INTO
+            word2vec (word, vec)
+        SELECT
+            word, vec
+        FROM
+            word2vec_import
+        ON CONFLICT (word) DO NOTHING
+        "#,
+    )
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    tx.commit().await?;
+
+    Ok(inserted)
+}
+
+/// Import word vectors from a word2vec file into the database
+///
+/// Words are only imported if they are in the allow list (when provided) and not in the deny
+/// list. Words that are already in the database are skipped, so an import can safely be re-run
+/// to resume after a failure.
+pub async fn import_vectors(
+    options: &ImportOptions,
+    db: &sqlx::PgPool,
+) -> Result<ImportSummary, SimilariumError> {
+    let allow_list = match &options.allow_list {
+        Some(path) => {
+            log::info!("Loading allow list from {}", path.display());
+            Some(read_word_list(path)?)
+        }
+        None => None,
+    };
+    let deny_list = match &options.deny_list {
+        Some(path) => {
+            log::info!("Loading deny list from {}", path.display());
+            read_word_list(path)?
+        }
+        None => HashSet::new(),
+    };
+
+    let existing_words: HashSet<String> = sqlx::query_scalar!(
+        r#"
+        SELECT
+            word
+        FROM
+            word2vec
+        "#
+    )
+    .fetch_all(db)
+    .await?
+    .into_iter()
+    .collect();
+    if !existing_words.is_empty() {
+        log::info!(
+            "Found {} words already imported, they will be skipped",
+            existing_words.len()
+        );
+    }
+
+    log::info!(
+        "Reading {:?} vectors from {}",
+        options.format,
+        options.path.display()
+    );
+    let file = BufReader::new(File::open(&options.path)?);
+    let reader = VectorReader::new(file, options.format)?;
+    if reader.dimensions != VECTOR_DIMENSIONS {
+        return value_error!(
+            "Vectors have {} dimensions, but the database expects {}",
+            reader.dimensions,
+            VECTOR_DIMENSIONS
+        );
+    }
+    let total = reader.word_count;
+
+    let mut summary = ImportSummary::default();
+    let mut batch: Vec<u8> = vec![];
+    let mut batch_rows = 0;
+
+    for entry in reader {
+        let (word, vec) = entry?;
+        summary.read += 1;
+
+        if deny_list.contains(&word)
+            || allow_list
+                .as_ref()
+                .is_some_and(|allow_list| !allow_list.contains(&word))
+        {
+            summary.filtered += 1;
+            continue;
+        }
+        if existing_words.contains(&word) {
+            summary.existing += 1;
+            continue;
+        }
+
+        batch.extend_from_slice(escape_copy_value(&word).as_bytes());
+        batch.push(b'\t');
+        batch.extend_from_slice(format_vector(&vec).as_bytes());
+        batch.push(b'\n');
+        batch_rows += 1;
+
+        if batch_rows >= options.batch_size {
+            summary.inserted += insert_batch(&batch, db).await? as usize;
+            batch.clear();
+            batch_rows = 0;
+            log::info!(
+                "Progress: {}/{} read, {} inserted",
+                summary.read,
+                total,
+                summary.inserted
+            );
+        }
+    }
+
+    if batch_rows > 0 {
+        summary.inserted += insert_batch(&batch, db).await? as usize;
+    }
+
+    log::info!(
+        "Import finished: {} read, {} inserted, {} already imported, {} filtered",
+        summary.read,
+        summary.inserted,
+        summary.existing,
+        summary.filtered
+    );
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_vector() {
+        assert_eq!(format_vector(&[0.5, -1.0, 0.125]), "{0.5,-1,0.125}");
+    }
+
+    #[test]
+    fn test_escape_copy_value() {
+        assert_eq!(escape_copy_value("apple"), "apple");
+        assert_eq!(escape_copy_value("a\\b"), "a\\\\b");
+        assert_eq!(escape_copy_value("a\tb\nc"), "a\\tb\\nc");
+    }
+}
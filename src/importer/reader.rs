@@ -0,0 +1,244 @@
+use crate::SimilariumError;
+use std::io::{BufRead, ErrorKind};
+use std::path::Path;
+
+/// The on-disk format of a word vector file
+#[derive(Debug, Clone, Copy, Eq, PartialEq, clap::ValueEnum)]
+pub enum VectorFormat {
+    /// The binary word2vec format, as used by the GoogleNews vectors
+    Binary,
+    /// The text format, with one word and its values per line
+    Text,
+}
+
+impl VectorFormat {
+    /// Guess the format from the file extension, falling back to the text format
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("bin") => VectorFormat::Binary,
+            _ => VectorFormat::Text,
+        }
+    }
+}
+
+/// Streaming reader over a word2vec file
+///
+/// Both formats start with a header line of "<word count> <dimensions>", which is read when the
+/// reader is created. The entries are then read one at a time by iterating over the reader, so
+/// the full model never has to be held in memory.
+pub struct VectorReader<R: BufRead> {
+    reader: R,
+    format: VectorFormat,
+    pub word_count: usize,
+    pub dimensions: usize,
+}
+
+impl<R: BufRead> VectorReader<R> {
+    pub fn new(mut reader: R, format: VectorFormat) -> Result<Self, SimilariumError> {
+        let mut header = String::new();
+        reader.read_line(&mut header)?;
+
+        let (word_count, dimensions) = match header.split_whitespace().collect::<Vec<_>>()[..] {
+            [word_count, dimensions] => (word_count.parse()?, dimensions.parse()?),
+            _ => return value_error!("Invalid vector file header: {:?}", header.trim()),
+        };
+
+        Ok(VectorReader {
+            reader,
+            format,
+            word_count,
+            dimensions,
+        })
+    }
+
+    fn read_binary_entry(&mut self) -> Result<Option<(String, Vec<f32>)>, SimilariumError> {
+        // Words are terminated by a space, while the previous entry may have been terminated by a
+        // newline that we need to skip
+        let mut word = vec![];
+        self.reader.read_until(b' ', &mut word)?;
+        if word.iter().all(u8::is_ascii_whitespace) && word.last() != Some(&b' ') {
+            return Ok(None);
+        }
+        if word.last() != Some(&b' ') {
+            return value_error!("Unexpected end of file while reading a word");
+        }
+        word.pop();
+        let word = String::from_utf8_lossy(&word).trim_start().to_string();
+
+        let mut buffer = vec![0u8; self.dimensions * 4];
+        if let Err(e) = self.reader.read_exact(&mut buffer) {
+            return match e.kind() {
+                ErrorKind::UnexpectedEof => {
+                    value_error!("Unexpected end of file while reading vector for {}", word)
+                }
+                _ => Err(e.into()),
+            };
+        }
+        let vec = buffer
+            .chunks_exact(4)
+            .map(|bytes| f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            .collect();
+
+        Ok(Some((word, vec)))
+    }
+
+    fn read_text_entry(&mut self) -> Result<Option<(String, Vec<f32>)>, SimilariumError> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if self.reader.read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+            if !line.trim().is_empty() {
+                break;
+            }
+        }
+
+        let mut parts = line.split_whitespace();
+        let word = parts.next().unwrap_or_default().to_string();
+        let vec = parts
+            .map(|value| value.parse::<f32>())
+            .collect::<Result<Vec<f32>, _>>();
+        let vec = match vec {
+            Ok(vec) => vec,
+            Err(_) => return value_error!("Invalid vector values for {}", word),
+        };
+
+        if vec.len() != self.dimensions {
+            return value_error!(
+                "Expected {} dimensions for {}, got {}",
+                self.dimensions,
+                word,
+                vec.len()
+            );
+        }
+
+        Ok(Some((word, vec)))
+    }
+}
+
+impl<R: BufRead> Iterator for VectorReader<R> {
+    type Item = Result<(String, Vec<f32>), SimilariumError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.format {
+            VectorFormat::Binary => self.read_binary_entry(),
+            VectorFormat::Text => self.read_text_entry(),
+        }
+        .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn binary_fixture(entries: &[(&str, [f32; 2])]) -> Vec<u8> {
+        let mut data = format!("{} 2\n", entries.len()).into_bytes();
+        for (word, vec) in entries {
+            data.extend_from_slice(word.as_bytes());
+            data.push(b' ');
+            for value in vec {
+                data.extend_from_slice(&value.to_le_bytes());
+            }
+            data.push(b'\n');
+        }
+        data
+    }
+
+    #[test]
+    fn test_vector_format_from_path() {
+        assert_eq!(
+            VectorFormat::from_path(Path::new("GoogleNews-vectors-negative300.bin")),
+            VectorFormat::Binary
+        );
+        assert_eq!(
+            VectorFormat::from_path(Path::new("wiki.en.vec")),
+            VectorFormat::Text
+        );
+        assert_eq!(
+            VectorFormat::from_path(Path::new("vectors")),
+            VectorFormat::Text
+        );
+    }
+
+    #[test]
+    fn test_reading_binary_format() {
+        let data = binary_fixture(&[("apple", [0.5, -1.0]), ("pear", [2.0, 0.25])]);
+        let reader = VectorReader::new(data.as_slice(), VectorFormat::Binary).unwrap();
+
+        assert_eq!(reader.word_count, 2);
+        assert_eq!(reader.dimensions, 2);
+
+        let entries = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                ("apple".to_string(), vec![0.5, -1.0]),
+                ("pear".to_string(), vec![2.0, 0.25]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reading_binary_format_without_newlines() {
+        let mut data = b"2 1\n".to_vec();
+        data.extend_from_slice(b"apple ");
+        data.extend_from_slice(&1.5f32.to_le_bytes());
+        data.extend_from_slice(b"pear ");
+        data.extend_from_slice(&(-0.5f32).to_le_bytes());
+
+        let reader = VectorReader::new(data.as_slice(), VectorFormat::Binary).unwrap();
+        let entries = reader.collect::<Result<Vec<_>, _>>().unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                ("apple".to_string(), vec![1.5]),
+                ("pear".to_string(), vec![-0.5]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reading_truncated_binary_format_errors() {
+        let mut data = binary_fixture(&[("apple", [0.5, -1.0])]);
+        data.truncate(data.len() - 3);
+
+        let mut reader = VectorReader::new(data.as_slice(), VectorFormat::Binary).unwrap();
+
+        assert!(reader.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_reading_text_format() {
+        let data = "2 3\napple 0.1 0.2 0.3\n\npear -1 0 1\n";
+        let reader = VectorReader::new(data.as_bytes(), VectorFormat::Text).unwrap();
+
+        assert_eq!(reader.word_count, 2);
+        assert_eq!(reader.dimensions, 3);
+
+        let entries = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                ("apple".to_string(), vec![0.1, 0.2, 0.3]),
+                ("pear".to_string(), vec![-1.0, 0.0, 1.0]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reading_text_format_with_wrong_dimensions_errors() {
+        let data = "1 3\napple 0.1 0.2\n";
+        let mut reader = VectorReader::new(data.as_bytes(), VectorFormat::Text).unwrap();
+
+        assert!(reader.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_invalid_header_errors() {
+        assert!(VectorReader::new("apple 0.1 0.2\n".as_bytes(), VectorFormat::Text).is_err());
+        assert!(VectorReader::new("".as_bytes(), VectorFormat::Text).is_err());
+    }
+}
@@ -2,14 +2,19 @@
 mod macros;
 pub mod ai;
 pub mod api;
+pub mod cli;
 mod config;
 mod db;
 mod error;
 pub mod game;
+pub mod importer;
 pub mod models;
+mod normalise;
 pub mod payloads;
+pub mod similarity;
 pub mod slack_client;
 mod spelling;
+pub mod suggestions;
 mod tasks;
 pub mod utils;
 pub mod workers;
@@ -43,6 +43,33 @@ macro_rules! ai_error {
     };
 }
 
+macro_rules! unauthorized_error {
+    ($($t:tt)*) => {
+        Err($crate::error::SimilariumError {
+            message: Some(format!($($t)*)),
+            error_type: $crate::error::SimilariumErrorType::Unauthorized,
+        })
+    };
+}
+
+macro_rules! rate_limited_error {
+    ($($t:tt)*) => {
+        Err($crate::error::SimilariumError {
+            message: Some(format!($($t)*)),
+            error_type: $crate::error::SimilariumErrorType::RateLimited,
+        })
+    };
+}
+
+macro_rules! token_revoked_error {
+    ($($t:tt)*) => {
+        Err($crate::error::SimilariumError {
+            message: Some(format!($($t)*)),
+            error_type: $crate::error::SimilariumErrorType::TokenRevoked,
+        })
+    };
+}
+
 #[cfg(test)]
 macro_rules! datetime {
     ($year:expr, $month:expr, $day:expr) => {
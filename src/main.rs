@@ -3,5 +3,5 @@ use dotenvy::dotenv;
 #[actix_web::main]
 async fn main() -> Result<(), similarium::SimilariumError> {
     dotenv().ok();
-    similarium::api::run().await
+    similarium::cli::run().await
 }
@@ -1,5 +1,7 @@
-use crate::models::Game;
+use crate::models::{Game, Persona, SlackBot, DEFAULT_PERSONA};
+use crate::utils::get_timezone;
 use crate::SimilariumError;
+use chrono::{DateTime, Duration, DurationRound, LocalResult, NaiveTime, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
@@ -9,6 +11,32 @@ pub struct Channel {
     pub hour: i32,
     pub minute: i32,
     pub active: bool,
+    /// The IANA timezone that `hour` and `minute` are in, such as "Europe/London"
+    pub timezone: String,
+    /// Whether the weekly and monthly leaderboards are posted to the channel
+    pub leaderboard_enabled: bool,
+    /// The name of the pool that secrets are drawn from, or the built-in target words if None
+    pub secret_pool: Option<String>,
+    /// The Enterprise Grid org of the team, which lets a channel shared between the org's
+    /// workspaces use the org-wide install
+    pub enterprise_id: Option<String>,
+    /// The name of the persona that AI messages are written in, or the default persona if None
+    pub persona: Option<String>,
+    /// Whether AI messages, such as celebrations and taunts, are posted to the channel
+    pub ai_enabled: bool,
+}
+
+/// What's needed to pick the secret of the next game on a channel
+#[derive(Debug, sqlx::FromRow)]
+pub struct NextGame {
+    pub channel_id: String,
+    pub secret_pool: Option<String>,
+    pub puzzle_number: i64,
+    /// The words in the channel's secret pool, sorted, or empty if it doesn't have one
+    pub pool_words: Vec<String>,
+    /// The secrets that the channel has played, with the puzzle numbers of `used_puzzle_numbers`
+    pub used_secrets: Vec<String>,
+    pub used_puzzle_numbers: Vec<i64>,
 }
 
 impl Channel {
@@ -34,18 +62,54 @@ impl Channel {
         Ok(channel)
     }
 
+    pub async fn get_all(db: &sqlx::PgPool) -> Result<Vec<Channel>, SimilariumError> {
+        let channels = sqlx::query_as!(
+            Channel,
+            r#"
+            SELECT
+                *
+            FROM
+                channel
+            ORDER BY
+                team_id, id
+            "#
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(channels)
+    }
+
     pub async fn insert(&self, db: &sqlx::PgPool) -> Result<(), SimilariumError> {
         sqlx::query!(
             r#"
             INSERT INTO
-                channel(id, team_id, hour, minute, active)
-            VALUES ($1, $2, $3, $4, $5);
+                channel(
+                    id,
+                    team_id,
+                    hour,
+                    minute,
+                    active,
+                    timezone,
+                    leaderboard_enabled,
+                    secret_pool,
+                    enterprise_id,
+                    persona,
+                    ai_enabled
+                )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11);
             "#,
             self.id,
             self.team_id,
             self.hour,
             self.minute,
             self.active,
+            self.timezone,
+            self.leaderboard_enabled,
+            self.secret_pool,
+            self.enterprise_id,
+            self.persona,
+            self.ai_enabled,
         )
         .execute(db)
         .await?;
@@ -59,6 +123,12 @@ impl Channel {
     ///     * active
     ///     * hour
     ///     * minute
+    ///     * timezone
+    ///     * leaderboard_enabled
+    ///     * secret_pool
+    ///     * enterprise_id
+    ///     * persona
+    ///     * ai_enabled
     ///
     /// Does not update:
     ///     * id
@@ -71,13 +141,25 @@ impl Channel {
             SET 
                 active = $1,
                 hour = $2,
-                minute = $3
+                minute = $3,
+                timezone = $4,
+                leaderboard_enabled = $5,
+                secret_pool = $6,
+                enterprise_id = $7,
+                persona = $8,
+                ai_enabled = $9
             WHERE
-                id = $4
+                id = $10
             "#,
             self.active,
             self.hour,
             self.minute,
+            self.timezone,
+            self.leaderboard_enabled,
+            self.secret_pool,
+            self.enterprise_id,
+            self.persona,
+            self.ai_enabled,
             self.id,
         )
         .execute(db)
@@ -86,6 +168,171 @@ impl Channel {
         Ok(())
     }
 
+    /// Stop the games on every channel on a team, other than those that an org-wide install still
+    /// serves
+    ///
+    /// Returns how many channels were deactivated
+    pub async fn deactivate_for_team(
+        team_id: &str,
+        db: &sqlx::PgPool,
+    ) -> Result<u64, SimilariumError> {
+        let deactivated = sqlx::query!(
+            r#"
+            UPDATE
+                channel
+            SET
+                active = false
+            WHERE
+                team_id = $1 AND
+                active = true AND
+                NOT EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        slack_bots
+                    WHERE
+                        is_enterprise_install AND
+                        slack_bots.enterprise_id = channel.enterprise_id AND
+                        revoked_at IS NULL
+                )
+            "#,
+            team_id
+        )
+        .execute(db)
+        .await?
+        .rows_affected();
+
+        Ok(deactivated)
+    }
+
+    /// Stop the games on every channel in an Enterprise Grid org, other than those on teams that
+    /// still have their own install
+    ///
+    /// Returns how many channels were deactivated
+    pub async fn deactivate_for_enterprise(
+        enterprise_id: &str,
+        db: &sqlx::PgPool,
+    ) -> Result<u64, SimilariumError> {
+        let deactivated = sqlx::query!(
+            r#"
+            UPDATE
+                channel
+            SET
+                active = false
+            WHERE
+                enterprise_id = $1 AND
+                active = true AND
+                NOT EXISTS (
+                    SELECT
+                        1
+                    FROM
+                        slack_bots
+                    WHERE
+                        slack_bots.team_id = channel.team_id AND
+                        revoked_at IS NULL
+                )
+            "#,
+            enterprise_id
+        )
+        .execute(db)
+        .await?
+        .rows_affected();
+
+        Ok(deactivated)
+    }
+
+    /// Get the next game of every active channel, along with their secret pools and the secrets
+    /// they've played
+    pub async fn get_next_games(db: &sqlx::PgPool) -> Result<Vec<NextGame>, SimilariumError> {
+        let next_games = sqlx::query_as!(
+            NextGame,
+            r#"
+            SELECT
+                channel.id AS channel_id,
+                channel.secret_pool,
+                COALESCE(
+                    (SELECT max(puzzle_number) FROM game WHERE game.channel_id = channel.id),
+                    0
+                ) + 1 AS "puzzle_number!",
+                ARRAY(
+                    SELECT
+                        word
+                    FROM
+                        secret_pool_word
+                    WHERE
+                        pool_name = channel.secret_pool
+                    ORDER BY
+                        word
+                ) AS "pool_words!",
+                ARRAY(
+                    SELECT
+                        secret
+                    FROM
+                        channel_secret
+                    WHERE
+                        channel_secret.channel_id = channel.id
+                    ORDER BY
+                        secret
+                ) AS "used_secrets!",
+                ARRAY(
+                    SELECT
+                        puzzle_number
+                    FROM
+                        channel_secret
+                    WHERE
+                        channel_secret.channel_id = channel.id
+                    ORDER BY
+                        secret
+                ) AS "used_puzzle_numbers!"
+            FROM
+                channel
+            WHERE
+                active = true
+            ORDER BY
+                id
+            "#
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(next_games)
+    }
+
+    /// Get the token of the install that serves the channel
+    pub async fn get_slack_bot_token(&self, db: &sqlx::PgPool) -> Result<String, SimilariumError> {
+        SlackBot::get_slack_bot_token(self.enterprise_id.as_deref(), &self.team_id, db).await
+    }
+
+    /// Get the persona that AI messages on the channel are written in
+    pub async fn get_persona(&self, db: &sqlx::PgPool) -> Result<Persona, SimilariumError> {
+        let name = self.persona.as_deref().unwrap_or(DEFAULT_PERSONA);
+        Persona::get(name, db)
+            .await?
+            .map_or_else(|| value_error!("Persona {} not found", name), Ok)
+    }
+
+    /// Get all active channels that haven't opted out of leaderboards
+    pub async fn get_leaderboard_channels(
+        db: &sqlx::PgPool,
+    ) -> Result<Vec<Channel>, SimilariumError> {
+        let channels = sqlx::query_as!(
+            Channel,
+            r#"
+            SELECT
+                *
+            FROM
+                channel
+            WHERE
+                active = true AND
+                leaderboard_enabled = true
+            "#
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(channels)
+    }
+
     pub async fn get_active_games(&self, db: &sqlx::PgPool) -> Result<Vec<Game>, SimilariumError> {
         log::debug!("Fetching active games for channel: {}", self.id);
 
@@ -108,9 +355,40 @@ impl Channel {
         Ok(games)
     }
 
+    /// Check if the daily game is due to start in the minute of `now`
+    ///
+    /// The scheduled time is in the channel's own timezone, so it stays at the same local time
+    /// across daylight saving changes. When the clocks go forward past the scheduled time, the game
+    /// is started at the first minute after the gap. When they go back, it's only started the first
+    /// time the scheduled time comes around.
+    pub fn is_due_at(&self, now: DateTime<Utc>) -> bool {
+        let Some(time) = NaiveTime::from_hms_opt(self.hour as u32, self.minute as u32, 0) else {
+            return false;
+        };
+        let tz = get_timezone(&self.timezone);
+
+        let now = now.duration_trunc(Duration::minutes(1)).unwrap_or(now);
+        let local_now = now.with_timezone(&tz).naive_local();
+        let local_previous = (now - Duration::minutes(1))
+            .with_timezone(&tz)
+            .naive_local();
+        let scheduled = local_now.date().and_time(time);
+
+        if scheduled <= local_previous || scheduled > local_now {
+            return false;
+        }
+
+        match tz.from_local_datetime(&local_now) {
+            LocalResult::Ambiguous(earliest, _) => earliest == now,
+            _ => true,
+        }
+    }
+
+    /// Get all of the active channels that have a game due in the minute of `now`
+    ///
+    /// Each channel is matched against its own hour and minute, in its own timezone.
     pub async fn get_channels_for_hour_minute(
-        hour: i32,
-        minute: i32,
+        now: DateTime<Utc>,
         db: &sqlx::PgPool,
     ) -> Result<Vec<Channel>, SimilariumError> {
         let channels = sqlx::query_as!(
@@ -121,16 +399,129 @@ impl Channel {
             FROM
                 channel
             WHERE
-                hour = $1 AND
-                minute = $2 AND
                 active = true
-            "#,
-            hour,
-            minute
+            "#
         )
         .fetch_all(db)
         .await?;
 
-        Ok(channels)
+        Ok(channels
+            .into_iter()
+            .filter(|channel| channel.is_due_at(now))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_channel(id: &str, hour: i32, minute: i32, timezone: &str) -> Channel {
+        Channel {
+            id: id.to_string(),
+            team_id: "team_id".to_string(),
+            hour,
+            minute,
+            active: true,
+            timezone: timezone.to_string(),
+            leaderboard_enabled: true,
+            secret_pool: None,
+            enterprise_id: None,
+            persona: None,
+            ai_enabled: true,
+        }
+    }
+
+    #[test]
+    fn test_is_due_at_in_utc() {
+        let channel = get_channel("channel_id", 9, 30, "UTC");
+
+        assert!(channel.is_due_at(datetime!(2024, 1, 15, 9, 30, 0)));
+        assert!(channel.is_due_at(datetime!(2024, 1, 15, 9, 30, 59)));
+        assert!(!channel.is_due_at(datetime!(2024, 1, 15, 9, 29, 59)));
+        assert!(!channel.is_due_at(datetime!(2024, 1, 15, 9, 31, 0)));
+    }
+
+    #[test]
+    fn test_is_due_at_stays_at_local_time_across_daylight_saving() {
+        let channel = get_channel("channel_id", 9, 0, "Europe/London");
+
+        // GMT in winter, BST (UTC+1) in summer
+        assert!(channel.is_due_at(datetime!(2024, 1, 15, 9, 0, 0)));
+        assert!(!channel.is_due_at(datetime!(2024, 7, 15, 9, 0, 0)));
+        assert!(channel.is_due_at(datetime!(2024, 7, 15, 8, 0, 0)));
+    }
+
+    #[test]
+    fn test_is_due_at_when_clocks_skip_the_scheduled_time() {
+        // On 2024-03-31 London skips from 01:00 GMT straight to 02:00 BST
+        let channel = get_channel("channel_id", 1, 30, "Europe/London");
+
+        assert!(!channel.is_due_at(datetime!(2024, 3, 31, 0, 59, 0)));
+        assert!(channel.is_due_at(datetime!(2024, 3, 31, 1, 0, 0)));
+        assert!(!channel.is_due_at(datetime!(2024, 3, 31, 1, 30, 0)));
+    }
+
+    #[test]
+    fn test_is_due_at_when_clocks_repeat_the_scheduled_time() {
+        // On 2024-10-27 London goes from 02:00 BST back to 01:00 GMT, so 01:30 happens twice
+        let channel = get_channel("channel_id", 1, 30, "Europe/London");
+
+        assert!(channel.is_due_at(datetime!(2024, 10, 27, 0, 30, 0)));
+        assert!(!channel.is_due_at(datetime!(2024, 10, 27, 1, 30, 0)));
+    }
+
+    #[test]
+    fn test_is_due_at_with_invalid_timezone_uses_utc() {
+        let channel = get_channel("channel_id", 9, 0, "Not/A_Timezone");
+
+        assert!(channel.is_due_at(datetime!(2024, 7, 15, 9, 0, 0)));
+    }
+
+    #[sqlx::test]
+    async fn test_get_channels_for_hour_minute_uses_each_channel_timezone(
+        pool: sqlx::PgPool,
+    ) -> Result<(), SimilariumError> {
+        get_channel("london", 9, 0, "Europe/London")
+            .insert(&pool)
+            .await?;
+        get_channel("new_york", 9, 0, "America/New_York")
+            .insert(&pool)
+            .await?;
+        get_channel("utc", 9, 0, "UTC").insert(&pool).await?;
+        let mut inactive = get_channel("inactive", 8, 0, "UTC");
+        inactive.active = false;
+        inactive.insert(&pool).await?;
+
+        let channels =
+            Channel::get_channels_for_hour_minute(datetime!(2024, 7, 15, 8, 0, 0), &pool).await?;
+        let ids = channels.iter().map(|c| c.id.as_str()).collect::<Vec<_>>();
+        assert_eq!(ids, vec!["london"]);
+
+        let channels =
+            Channel::get_channels_for_hour_minute(datetime!(2024, 7, 15, 13, 0, 0), &pool).await?;
+        let ids = channels.iter().map(|c| c.id.as_str()).collect::<Vec<_>>();
+        assert_eq!(ids, vec!["new_york"]);
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn test_get_leaderboard_channels_skips_opted_out_channels(
+        pool: sqlx::PgPool,
+    ) -> Result<(), SimilariumError> {
+        get_channel("opted_in", 9, 0, "UTC").insert(&pool).await?;
+        let mut opted_out = get_channel("opted_out", 9, 0, "UTC");
+        opted_out.leaderboard_enabled = false;
+        opted_out.insert(&pool).await?;
+        let mut inactive = get_channel("inactive", 9, 0, "UTC");
+        inactive.active = false;
+        inactive.insert(&pool).await?;
+
+        let channels = Channel::get_leaderboard_channels(&pool).await?;
+        let ids = channels.iter().map(|c| c.id.as_str()).collect::<Vec<_>>();
+        assert_eq!(ids, vec!["opted_in"]);
+
+        Ok(())
     }
 }
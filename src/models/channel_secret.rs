@@ -0,0 +1,61 @@
+use crate::SimilariumError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A secret that has been played on a channel
+#[derive(Serialize, Deserialize, Debug, sqlx::FromRow)]
+pub struct ChannelSecret {
+    pub channel_id: String,
+    pub secret: String,
+    /// The puzzle number that the secret was last used for
+    pub puzzle_number: i64,
+}
+
+impl ChannelSecret {
+    /// Get the secrets used on a channel, along with the puzzle number each was last used for
+    pub async fn get_used(
+        channel_id: &str,
+        db: &sqlx::PgPool,
+    ) -> Result<HashMap<String, i64>, SimilariumError> {
+        let used = sqlx::query_as!(
+            ChannelSecret,
+            r#"
+            SELECT
+                *
+            FROM
+                channel_secret
+            WHERE
+                channel_id = $1
+            "#,
+            channel_id
+        )
+        .fetch_all(db)
+        .await?
+        .into_iter()
+        .map(|used| (used.secret, used.puzzle_number))
+        .collect();
+
+        Ok(used)
+    }
+
+    /// Record that a secret was used, updating the puzzle number if it was used before
+    pub async fn upsert(&self, db: &sqlx::PgPool) -> Result<(), SimilariumError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO
+                channel_secret (channel_id, secret, puzzle_number)
+            VALUES
+                ($1, $2, $3)
+            ON CONFLICT (channel_id, secret) DO UPDATE SET
+                puzzle_number = EXCLUDED.puzzle_number
+            "#,
+            self.channel_id,
+            self.secret,
+            self.puzzle_number,
+        )
+        .execute(db)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::models::GameWinnerAssociation;
+use crate::models::{GameHintAssociation, GameWinnerAssociation};
 use crate::SimilariumError;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -28,6 +28,28 @@ pub struct GuessContext {
     pub is_secret: bool,
 }
 
+/// The outcome of a game for a single user
+#[derive(Debug, Deserialize, Serialize, sqlx::FromRow)]
+pub struct GameResult {
+    pub puzzle_number: i64,
+    pub active: bool,
+    pub won: bool,
+}
+
+/// A game on a channel that a user has played on, with how they're doing on it
+#[derive(Debug, Deserialize, Serialize, sqlx::FromRow)]
+pub struct PlayedGame {
+    pub channel_id: String,
+    pub thread_ts: Option<String>,
+    pub puzzle_number: i64,
+    pub active: bool,
+    pub won: bool,
+    /// The best guess that the user has made, only fetched for active games
+    pub best_word: Option<String>,
+    pub best_rank: Option<i64>,
+    pub best_similarity: Option<f64>,
+}
+
 pub enum GuessContextOrder {
     Rank,
     GuessUpdated,
@@ -285,6 +307,31 @@ impl Game {
         .map_or(Ok(None), |g| Ok(Some(g.rank)))
     }
 
+    /// Get the best rank out of the guesses that a user was the first to make
+    pub async fn get_user_top_guess_rank(
+        &self,
+        user_id: &str,
+        db: &sqlx::PgPool,
+    ) -> Result<Option<i64>, SimilariumError> {
+        let rank = sqlx::query_scalar!(
+            r#"
+            SELECT
+                min(rank)
+            FROM
+                guess
+            WHERE
+                game_id = $1 AND
+                user_id = $2
+            "#,
+            self.id,
+            user_id
+        )
+        .fetch_one(db)
+        .await?;
+
+        Ok(rank)
+    }
+
     pub async fn get_participant_user_ids(
         &self,
         db: &sqlx::PgPool,
@@ -309,6 +356,124 @@ impl Game {
         Ok(user_ids)
     }
 
+    /// Get whether a user won each game on a channel, ordered by puzzle number
+    pub async fn get_results_for_user(
+        channel_id: &str,
+        user_id: &str,
+        db: &sqlx::PgPool,
+    ) -> Result<Vec<GameResult>, SimilariumError> {
+        let results = sqlx::query_as!(
+            GameResult,
+            r#"
+            SELECT
+                game.puzzle_number,
+                game.active,
+                (w.user_id IS NOT NULL) AS "won!"
+            FROM
+                game
+            LEFT JOIN
+                game_user_winner_association w ON w.game_id = game.id AND w.user_id = $2
+            WHERE
+                game.channel_id = $1
+            ORDER BY
+                game.puzzle_number ASC
+            "#,
+            channel_id,
+            user_id
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(results)
+    }
+
+    /// Get every game on the active channels of a team that a user has made a guess on
+    ///
+    /// Channels in the same Enterprise Grid org are included, as they may be shared with the team.
+    /// The best guess of the user counts guesses that someone else made first.
+    pub async fn get_played_by_user(
+        user_id: &str,
+        enterprise_id: Option<&str>,
+        team_id: &str,
+        db: &sqlx::PgPool,
+    ) -> Result<Vec<PlayedGame>, SimilariumError> {
+        let games = sqlx::query_as!(
+            PlayedGame,
+            r#"
+            SELECT
+                game.channel_id,
+                game.thread_ts,
+                game.puzzle_number,
+                game.active,
+                (w.user_id IS NOT NULL) AS "won!",
+                best.word AS "best_word?",
+                best.rank AS "best_rank?",
+                best.similarity AS "best_similarity?"
+            FROM
+                channel
+            JOIN
+                game ON game.channel_id = channel.id
+            LEFT JOIN
+                game_user_winner_association w ON w.game_id = game.id AND w.user_id = $1
+            LEFT JOIN LATERAL (
+                SELECT
+                    word, rank, similarity
+                FROM
+                    guess
+                WHERE
+                    guess.game_id = game.id AND
+                    game.active = true AND
+                    (guess.user_id = $1 OR guess.latest_guess_user_id = $1)
+                ORDER BY
+                    rank ASC
+                LIMIT 1
+            ) best ON true
+            WHERE
+                (channel.team_id = $2 OR channel.enterprise_id = $3) AND
+                channel.active = true AND
+                channel.id IN (
+                    SELECT
+                        played.channel_id
+                    FROM
+                        guess
+                    JOIN
+                        game played ON played.id = guess.game_id
+                    WHERE
+                        guess.user_id = $1 OR
+                        guess.latest_guess_user_id = $1
+                )
+            ORDER BY
+                channel.id ASC,
+                game.puzzle_number ASC
+            "#,
+            user_id,
+            team_id,
+            enterprise_id
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(games)
+    }
+
+    /// Get the distinct secrets of all active games
+    pub async fn get_active_secrets(db: &sqlx::PgPool) -> Result<Vec<String>, SimilariumError> {
+        let secrets = sqlx::query_scalar!(
+            r#"
+            SELECT DISTINCT
+                secret
+            FROM
+                game
+            WHERE
+                active = true
+            "#
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(secrets)
+    }
+
     pub async fn get_next_puzzle_number(channel_id: String, db: &sqlx::PgPool) -> i64 {
         let last_puzzle_number = match sqlx::query!(
             r#"
@@ -361,6 +526,55 @@ impl Game {
         Ok(())
     }
 
+    pub async fn user_already_hinted(
+        &self,
+        user_id: &str,
+        db: &sqlx::PgPool,
+    ) -> Result<bool, SimilariumError> {
+        Ok(GameHintAssociation::get(self.id, user_id, db)
+            .await?
+            .is_some())
+    }
+
+    /// Record that the user has used their hint, returning false if they already had
+    pub async fn add_hint(
+        &self,
+        user_id: &str,
+        guess_idx: i32,
+        db: &sqlx::PgPool,
+    ) -> Result<bool, SimilariumError> {
+        let game_hint = GameHintAssociation {
+            game_id: self.id,
+            user_id: user_id.to_string(),
+            created: chrono::Utc::now().timestamp_millis(),
+            guess_idx,
+        };
+
+        game_hint.insert(db).await
+    }
+
+    /// Get the IDs of all users that have used a hint in this game
+    pub async fn get_hinted_user_ids(
+        &self,
+        db: &sqlx::PgPool,
+    ) -> Result<Vec<String>, SimilariumError> {
+        let user_ids = sqlx::query_scalar!(
+            r#"
+            SELECT
+                user_id
+            FROM
+                game_user_hint_association
+            WHERE
+                game_id = $1
+            "#,
+            self.id
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(user_ids)
+    }
+
     pub async fn get_winners(
         &self,
         db: &sqlx::PgPool,
@@ -416,6 +630,12 @@ mod tests {
             hour: 0,
             minute: 0,
             active: true,
+            timezone: "UTC".to_string(),
+            leaderboard_enabled: true,
+            secret_pool: None,
+            enterprise_id: None,
+            persona: None,
+            ai_enabled: true,
         };
         channel.insert(&pool).await?;
 
@@ -0,0 +1,63 @@
+use crate::SimilariumError;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Serialize, Deserialize, Debug, sqlx::FromRow)]
+pub struct GameHintAssociation {
+    pub game_id: Uuid,
+    pub user_id: String,
+    pub created: i64,
+    /// How many guesses had been made in the game when the hint was given
+    pub guess_idx: i32,
+}
+
+impl GameHintAssociation {
+    pub async fn get(
+        game_id: Uuid,
+        user_id: &str,
+        db: &sqlx::PgPool,
+    ) -> Result<Option<GameHintAssociation>, SimilariumError> {
+        let association = sqlx::query_as!(
+            GameHintAssociation,
+            r#"
+            SELECT
+                *
+            FROM
+                game_user_hint_association
+            WHERE
+                game_id = $1 AND
+                user_id = $2
+            "#,
+            game_id,
+            user_id
+        )
+        .fetch_optional(db)
+        .await?;
+
+        Ok(association)
+    }
+
+    /// Insert the hint, unless the user already has one for the game
+    ///
+    /// Returns whether it was inserted, so that only one of two hints requested at the same time
+    /// is given.
+    pub async fn insert(&self, db: &sqlx::PgPool) -> Result<bool, SimilariumError> {
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO
+                game_user_hint_association(game_id, user_id, created, guess_idx)
+            VALUES
+                ($1, $2, $3, $4)
+            ON CONFLICT (game_id, user_id) DO NOTHING;
+            "#,
+            self.game_id,
+            self.user_id,
+            self.created,
+            self.guess_idx,
+        )
+        .execute(db)
+        .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+}
@@ -1,7 +1,27 @@
 use crate::SimilariumError;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// Aggregated wins of a single user on a channel
+#[derive(Serialize, Deserialize, Debug, sqlx::FromRow)]
+pub struct SolverStats {
+    pub user_id: String,
+    pub wins: i64,
+    pub average_guesses: f64,
+    pub best_guess: i64,
+    /// How many games the user was the first to find the secret in
+    pub first_solves: i64,
+}
+
+/// A game that a user won, along with where it was played
+#[derive(Serialize, Deserialize, Debug, sqlx::FromRow)]
+pub struct RecentWin {
+    pub channel_id: String,
+    pub puzzle_number: i64,
+    pub guess_idx: i64,
+}
+
 #[derive(Serialize, Deserialize, Debug, sqlx::FromRow)]
 pub struct GameWinnerAssociation {
     pub game_id: Uuid,
@@ -54,4 +74,125 @@ impl GameWinnerAssociation {
 
         Ok(())
     }
+
+    /// Get all the wins of a user on a channel, oldest first
+    pub async fn get_for_user_on_channel(
+        channel_id: &str,
+        user_id: &str,
+        db: &sqlx::PgPool,
+    ) -> Result<Vec<GameWinnerAssociation>, SimilariumError> {
+        let associations = sqlx::query_as!(
+            GameWinnerAssociation,
+            r#"
+            SELECT
+                w.*
+            FROM
+                game_user_winner_association w
+            JOIN
+                game ON game.id = w.game_id
+            WHERE
+                game.channel_id = $1 AND
+                w.user_id = $2
+            ORDER BY
+                game.puzzle_number ASC
+            "#,
+            channel_id,
+            user_id
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(associations)
+    }
+
+    /// Get the users with the most wins on a channel, for games started after `since` if given
+    ///
+    /// Ties are broken by who was first to find the secret most often, and then by the average
+    /// number of guesses it took to win
+    pub async fn get_top_solvers(
+        channel_id: &str,
+        since: Option<DateTime<Utc>>,
+        count: i64,
+        db: &sqlx::PgPool,
+    ) -> Result<Vec<SolverStats>, SimilariumError> {
+        let solvers = sqlx::query_as!(
+            SolverStats,
+            r#"
+            SELECT
+                w.user_id,
+                count(*) AS "wins!",
+                avg(w.guess_idx)::float8 AS "average_guesses!",
+                min(w.guess_idx) AS "best_guess!",
+                count(*) FILTER (
+                    WHERE w.created = (
+                        SELECT
+                            min(f.created)
+                        FROM
+                            game_user_winner_association f
+                        WHERE
+                            f.game_id = w.game_id
+                    )
+                ) AS "first_solves!"
+            FROM
+                game_user_winner_association w
+            JOIN
+                game ON game.id = w.game_id
+            WHERE
+                game.channel_id = $1 AND
+                ($2::timestamptz IS NULL OR game.date >= $2)
+            GROUP BY
+                w.user_id
+            ORDER BY
+                2 DESC, 5 DESC, 3 ASC
+            LIMIT $3
+            "#,
+            channel_id,
+            since,
+            count
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(solvers)
+    }
+
+    /// Get the latest wins of a user across every channel on a team, and any shared with it in
+    /// its Enterprise Grid org, newest first
+    pub async fn get_recent_for_user(
+        user_id: &str,
+        enterprise_id: Option<&str>,
+        team_id: &str,
+        count: i64,
+        db: &sqlx::PgPool,
+    ) -> Result<Vec<RecentWin>, SimilariumError> {
+        let wins = sqlx::query_as!(
+            RecentWin,
+            r#"
+            SELECT
+                game.channel_id,
+                game.puzzle_number,
+                w.guess_idx
+            FROM
+                game_user_winner_association w
+            JOIN
+                game ON game.id = w.game_id
+            JOIN
+                channel ON channel.id = game.channel_id
+            WHERE
+                w.user_id = $1 AND
+                (channel.team_id = $2 OR channel.enterprise_id = $4)
+            ORDER BY
+                w.created DESC
+            LIMIT $3
+            "#,
+            user_id,
+            team_id,
+            count,
+            enterprise_id
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(wins)
+    }
 }
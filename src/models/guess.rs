@@ -149,6 +149,35 @@ impl Guess {
         Ok(())
     }
 
+    /// Count the games on a channel that a user has made at least one guess in
+    ///
+    /// A guess counts for the user that made it first, as well as for the last user to make it
+    pub async fn count_games_played(
+        channel_id: &str,
+        user_id: &str,
+        db: &sqlx::PgPool,
+    ) -> Result<i64, SimilariumError> {
+        let count = sqlx::query_scalar!(
+            r#"
+            SELECT
+                count(DISTINCT guess.game_id) AS "count!"
+            FROM
+                guess
+            JOIN
+                game ON game.id = guess.game_id
+            WHERE
+                game.channel_id = $1 AND
+                (guess.user_id = $2 OR guess.latest_guess_user_id = $2)
+            "#,
+            channel_id,
+            user_id
+        )
+        .fetch_one(db)
+        .await?;
+
+        Ok(count)
+    }
+
     pub fn is_secret(&self) -> bool {
         self.rank == 0
     }
@@ -1,16 +1,24 @@
 mod channel;
+mod channel_secret;
 mod game;
+mod game_hint;
 mod game_winner;
 mod guess;
+mod persona;
+mod secret_pool;
 mod slack_bot;
 mod user;
 mod word2vec;
 
 // Expose the models directly
-pub use channel::Channel;
-pub use game::{Game, GuessContext, GuessContextOrder};
-pub use game_winner::GameWinnerAssociation;
+pub use channel::{Channel, NextGame};
+pub use channel_secret::ChannelSecret;
+pub use game::{Game, GameResult, GuessContext, GuessContextOrder, PlayedGame};
+pub use game_hint::GameHintAssociation;
+pub use game_winner::{GameWinnerAssociation, RecentWin, SolverStats};
 pub use guess::Guess;
+pub use persona::{Persona, DEFAULT_PERSONA};
+pub use secret_pool::{SecretPool, SecretPoolSummary};
 pub use slack_bot::SlackBot;
 pub use user::User;
 pub use word2vec::{Similarity, Word2Vec};
@@ -0,0 +1,83 @@
+use crate::SimilariumError;
+use serde::{Deserialize, Serialize};
+
+/// The persona that channels use unless they choose another one
+pub const DEFAULT_PERSONA: &str = "witty";
+
+/// The personality that AI messages on a channel are written in
+///
+/// The prompts are templates for the model, see `ai::render_template` for the placeholders.
+#[derive(Serialize, Deserialize, Debug, Clone, sqlx::FromRow)]
+pub struct Persona {
+    pub name: String,
+    pub description: String,
+    pub celebration_prompt: String,
+    pub taunt_prompt: String,
+    pub win_prompt: String,
+}
+
+impl Persona {
+    pub async fn get(name: &str, db: &sqlx::PgPool) -> Result<Option<Persona>, SimilariumError> {
+        let persona = sqlx::query_as!(
+            Persona,
+            r#"
+            SELECT
+                *
+            FROM
+                persona
+            WHERE
+                name = $1
+            "#,
+            name
+        )
+        .fetch_optional(db)
+        .await?;
+
+        Ok(persona)
+    }
+
+    /// Get every persona, ordered by name
+    pub async fn get_all(db: &sqlx::PgPool) -> Result<Vec<Persona>, SimilariumError> {
+        let personas = sqlx::query_as!(
+            Persona,
+            r#"
+            SELECT
+                *
+            FROM
+                persona
+            ORDER BY
+                name
+            "#
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(personas)
+    }
+
+    /// Insert the persona, or update it if it already exists
+    pub async fn upsert(&self, db: &sqlx::PgPool) -> Result<(), SimilariumError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO
+                persona (name, description, celebration_prompt, taunt_prompt, win_prompt)
+            VALUES
+                ($1, $2, $3, $4, $5)
+            ON CONFLICT (name) DO UPDATE SET
+                description = EXCLUDED.description,
+                celebration_prompt = EXCLUDED.celebration_prompt,
+                taunt_prompt = EXCLUDED.taunt_prompt,
+                win_prompt = EXCLUDED.win_prompt
+            "#,
+            self.name,
+            self.description,
+            self.celebration_prompt,
+            self.taunt_prompt,
+            self.win_prompt,
+        )
+        .execute(db)
+        .await?;
+
+        Ok(())
+    }
+}
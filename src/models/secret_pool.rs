@@ -0,0 +1,135 @@
+use crate::SimilariumError;
+use serde::{Deserialize, Serialize};
+
+/// A named list of words that a channel draws its secrets from
+#[derive(Serialize, Deserialize, Debug, sqlx::FromRow)]
+pub struct SecretPool {
+    pub name: String,
+    pub description: String,
+}
+
+/// A secret pool along with how many words are in it
+#[derive(Serialize, Deserialize, Debug, sqlx::FromRow)]
+pub struct SecretPoolSummary {
+    pub name: String,
+    pub description: String,
+    pub word_count: i64,
+}
+
+impl SecretPool {
+    pub async fn get(name: &str, db: &sqlx::PgPool) -> Result<Option<SecretPool>, SimilariumError> {
+        let pool = sqlx::query_as!(
+            SecretPool,
+            r#"
+            SELECT
+                *
+            FROM
+                secret_pool
+            WHERE
+                name = $1
+            "#,
+            name
+        )
+        .fetch_optional(db)
+        .await?;
+
+        Ok(pool)
+    }
+
+    /// Get every pool with its word count, ordered by name
+    pub async fn get_summaries(
+        db: &sqlx::PgPool,
+    ) -> Result<Vec<SecretPoolSummary>, SimilariumError> {
+        let pools = sqlx::query_as!(
+            SecretPoolSummary,
+            r#"
+            SELECT
+                p.name,
+                p.description,
+                count(w.word) AS "word_count!"
+            FROM
+                secret_pool AS p
+            LEFT JOIN
+                secret_pool_word AS w ON w.pool_name = p.name
+            GROUP BY
+                p.name
+            ORDER BY
+                p.name
+            "#
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(pools)
+    }
+
+    /// Insert the pool, or update the description if it already exists
+    pub async fn upsert(&self, db: &sqlx::PgPool) -> Result<(), SimilariumError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO
+                secret_pool (name, description)
+            VALUES
+                ($1, $2)
+            ON CONFLICT (name) DO UPDATE SET
+                description = EXCLUDED.description
+            "#,
+            self.name,
+            self.description,
+        )
+        .execute(db)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Add words to the pool, skipping any that are already in it
+    ///
+    /// Returns how many words were added
+    pub async fn add_words(
+        &self,
+        words: &[String],
+        db: &sqlx::PgPool,
+    ) -> Result<u64, SimilariumError> {
+        let added = sqlx::query!(
+            r#"
+            INSERT INTO
+                secret_pool_word (pool_name, word)
+            SELECT
+                $1, word
+            FROM
+                UNNEST($2::text[]) AS word
+            ON CONFLICT (pool_name, word) DO NOTHING
+            "#,
+            self.name,
+            words,
+        )
+        .execute(db)
+        .await?
+        .rows_affected();
+
+        Ok(added)
+    }
+
+    /// Get the words in the pool, ordered alphabetically so the order doesn't depend on the
+    /// database
+    pub async fn get_words(&self, db: &sqlx::PgPool) -> Result<Vec<String>, SimilariumError> {
+        let words = sqlx::query_scalar!(
+            r#"
+            SELECT
+                word
+            FROM
+                secret_pool_word
+            WHERE
+                pool_name = $1
+            ORDER BY
+                word
+            "#,
+            self.name
+        )
+        .fetch_all(db)
+        .await?;
+
+        Ok(words)
+    }
+}
@@ -1,14 +1,28 @@
-use crate::SimilariumError;
+use crate::{
+    config::get_config,
+    slack_client::{SlackClient, SlackOAuth},
+    SimilariumError,
+};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// How long before a rotating token expires that it's refreshed
+const TOKEN_REFRESH_MARGIN: chrono::Duration = chrono::Duration::minutes(10);
+
+/// Whether a token that expires at `expires_at` should be refreshed before it's used
+fn needs_refresh(expires_at: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+    expires_at.is_some_and(|expires_at| expires_at - TOKEN_REFRESH_MARGIN <= now)
+}
+
 #[derive(Debug, Deserialize, Serialize, sqlx::FromRow)]
 pub struct SlackBot {
     pub id: Uuid,
     pub app_id: String,
     pub enterprise_id: Option<String>,
     pub enterprise_name: Option<String>,
-    pub team_id: String,
+    /// The team that the app is installed on, which is None for org-wide installs
+    pub team_id: Option<String>,
     pub team_name: Option<String>,
     pub bot_token: Option<String>,
     pub bot_id: Option<String>,
@@ -18,10 +32,14 @@ pub struct SlackBot {
     pub bot_token_expires_at: Option<chrono::DateTime<chrono::Utc>>,
     pub is_enterprise_install: bool,
     pub installed_at: chrono::DateTime<chrono::Utc>,
+    /// When the workspace uninstalled the app or revoked the token, which stops it being used
+    pub revoked_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 impl SlackBot {
-    pub async fn insert(&self, db: &sqlx::PgPool) -> Result<(), SimilariumError> {
+    /// Insert the install, or replace the existing install of the team when the app is
+    /// reinstalled
+    pub async fn upsert(&self, db: &sqlx::PgPool) -> Result<(), SimilariumError> {
         sqlx::query!(
             r#"
             INSERT INTO 
@@ -41,7 +59,20 @@ impl SlackBot {
                     installed_at
                 )
             VALUES 
-                ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, true, $12);
+                ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            ON CONFLICT (COALESCE(team_id, ''), COALESCE(enterprise_id, '')) DO UPDATE SET
+                app_id = EXCLUDED.app_id,
+                enterprise_name = EXCLUDED.enterprise_name,
+                team_name = EXCLUDED.team_name,
+                bot_token = EXCLUDED.bot_token,
+                bot_id = EXCLUDED.bot_id,
+                bot_user_id = EXCLUDED.bot_user_id,
+                bot_scopes = EXCLUDED.bot_scopes,
+                bot_refresh_token = EXCLUDED.bot_refresh_token,
+                bot_token_expires_at = EXCLUDED.bot_token_expires_at,
+                is_enterprise_install = EXCLUDED.is_enterprise_install,
+                installed_at = EXCLUDED.installed_at,
+                revoked_at = NULL;
             "#,
             self.app_id,
             self.enterprise_id,
@@ -54,6 +85,7 @@ impl SlackBot {
             self.bot_scopes,
             self.bot_refresh_token,
             self.bot_token_expires_at,
+            self.is_enterprise_install,
             self.installed_at,
         )
         .execute(db)
@@ -61,27 +93,197 @@ impl SlackBot {
         Ok(())
     }
 
+    /// Get the token of the install that serves a team
+    ///
+    /// That's the install on the team itself, or failing that the org-wide install of its
+    /// Enterprise Grid org. Rotating tokens are refreshed first if they're about to expire.
+    /// Returns a `TokenRevoked` error if the app has since been uninstalled, until it's installed
+    /// again.
     pub async fn get_slack_bot_token(
+        enterprise_id: Option<&str>,
         team_id: &str,
         db: &sqlx::PgPool,
     ) -> Result<String, SimilariumError> {
-        // Get the bot token
-        sqlx::query_scalar!(
+        let bot = sqlx::query!(
             r#"
             SELECT
-                bot_token
+                bot_token,
+                bot_token_expires_at,
+                revoked_at
             FROM
                 slack_bots
             WHERE
-                team_id=$1
-            ORDER BY 
+                team_id = $1 OR
+                (is_enterprise_install AND enterprise_id = $2)
+            ORDER BY
+                revoked_at IS NULL DESC,
+                team_id = $1 DESC NULLS LAST,
                 installed_at DESC
             LIMIT 1;
             "#,
             team_id,
+            enterprise_id,
         )
         .fetch_one(db)
-        .await?
-        .ok_or(sqlx::Error::RowNotFound.into())
+        .await?;
+
+        if bot.revoked_at.is_some() {
+            return token_revoked_error!("The app has been uninstalled from team {}", team_id);
+        }
+
+        if needs_refresh(bot.bot_token_expires_at, Utc::now()) {
+            let config = get_config();
+            return Self::refresh_slack_bot_token(
+                enterprise_id,
+                team_id,
+                db,
+                &SlackClient::default(),
+                &config.slack_client_id,
+                &config.slack_client_secret,
+            )
+            .await;
+        }
+
+        bot.bot_token.ok_or(sqlx::Error::RowNotFound.into())
+    }
+
+    /// Refresh the rotating token of the install that serves a team if it's about to expire, and
+    /// get the token to use
+    ///
+    /// The install is locked while it's refreshed, as Slack invalidates the refresh token once
+    /// it's used, so every other process waits for the new token rather than refreshing it again.
+    pub async fn refresh_slack_bot_token(
+        enterprise_id: Option<&str>,
+        team_id: &str,
+        db: &sqlx::PgPool,
+        slack_client: &impl SlackOAuth,
+        client_id: &str,
+        client_secret: &str,
+    ) -> Result<String, SimilariumError> {
+        let mut tx = db.begin().await?;
+
+        let bot = sqlx::query!(
+            r#"
+            SELECT
+                id,
+                bot_token,
+                bot_refresh_token,
+                bot_token_expires_at,
+                revoked_at
+            FROM
+                slack_bots
+            WHERE
+                team_id = $1 OR
+                (is_enterprise_install AND enterprise_id = $2)
+            ORDER BY
+                revoked_at IS NULL DESC,
+                team_id = $1 DESC NULLS LAST,
+                installed_at DESC
+            LIMIT 1
+            FOR UPDATE;
+            "#,
+            team_id,
+            enterprise_id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if bot.revoked_at.is_some() {
+            return token_revoked_error!("The app has been uninstalled from team {}", team_id);
+        }
+
+        // Another process may have refreshed the token while we were waiting for the lock
+        let (true, Some(refresh_token)) = (
+            needs_refresh(bot.bot_token_expires_at, Utc::now()),
+            bot.bot_refresh_token,
+        ) else {
+            return bot.bot_token.ok_or(sqlx::Error::RowNotFound.into());
+        };
+
+        log::info!("Refreshing the token for team {}", team_id);
+        let response = slack_client
+            .refresh_token(&refresh_token, client_id, client_secret)
+            .await?;
+        let expires_at = response
+            .expires_in
+            .map(|expires_in| Utc::now() + chrono::Duration::seconds(expires_in));
+
+        sqlx::query!(
+            r#"
+            UPDATE
+                slack_bots
+            SET
+                bot_token = $2,
+                bot_refresh_token = $3,
+                bot_token_expires_at = $4
+            WHERE
+                id = $1
+            "#,
+            bot.id,
+            response.access_token,
+            response.refresh_token.or(Some(refresh_token)),
+            expires_at,
+        )
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        response.access_token.ok_or(sqlx::Error::RowNotFound.into())
+    }
+
+    /// Mark the install that serves a team as revoked, so that its token is no longer used
+    ///
+    /// Returns the install that was revoked, if there was one that wasn't already revoked
+    pub async fn revoke(
+        enterprise_id: Option<&str>,
+        team_id: &str,
+        db: &sqlx::PgPool,
+    ) -> Result<Option<SlackBot>, SimilariumError> {
+        let bot = sqlx::query_as!(
+            SlackBot,
+            r#"
+            UPDATE
+                slack_bots
+            SET
+                revoked_at = now()
+            WHERE
+                id = (
+                    SELECT
+                        id
+                    FROM
+                        slack_bots
+                    WHERE
+                        (team_id = $1 OR (is_enterprise_install AND enterprise_id = $2)) AND
+                        revoked_at IS NULL
+                    ORDER BY
+                        team_id = $1 DESC NULLS LAST,
+                        installed_at DESC
+                    LIMIT 1
+                )
+            RETURNING
+                *
+            "#,
+            team_id,
+            enterprise_id,
+        )
+        .fetch_optional(db)
+        .await?;
+
+        Ok(bot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_needs_refresh() {
+        let now = Utc::now();
+
+        assert!(!needs_refresh(None, now));
+        assert!(!needs_refresh(Some(now + chrono::Duration::hours(1)), now));
+        assert!(needs_refresh(Some(now + chrono::Duration::minutes(5)), now));
+        assert!(needs_refresh(Some(now - chrono::Duration::minutes(5)), now));
     }
 }
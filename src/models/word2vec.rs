@@ -1,4 +1,4 @@
-use crate::SimilariumError;
+use crate::{similarity::SimilarityBackend, SimilariumError};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
@@ -14,132 +14,80 @@ pub struct Similarity {
 }
 
 impl Word2Vec {
-    /// Create the materialised view for this word
-    ///
-    /// This will calculate the similarity for all words against the target word along with the
-    /// rank of the similarity
-    /// This is used to optimise the lookup of the rank and similarity for a given word during the
-    /// game day.
-    pub async fn create_materialised_view(&self, db: &sqlx::PgPool) -> Result<(), SimilariumError> {
-        sqlx::query(
-            format!(
-                r#"DROP MATERIALIZED VIEW IF EXISTS word2vec_{0}"#,
-                self.word
-            )
-            .as_str(),
-        )
-        .execute(db)
-        .await?;
-
-        sqlx::query(
-            format!(
-                r#"
-            CREATE MATERIALIZED VIEW word2vec_{0} AS
+    /// Get which of the words are in the word2vec table
+    pub async fn get_known_words(
+        words: &[String],
+        db: &sqlx::PgPool,
+    ) -> Result<Vec<String>, SimilariumError> {
+        let known = sqlx::query_scalar!(
+            r#"
             SELECT
-              a.word,
-              s.rank - 1 as rank,
-              s.similarity * -100 as similarity
+                word
             FROM
-              word2vec a
-            LEFT JOIN (
-              SELECT
-                a.word,
-                (a.vec <=> b.vec) AS similarity,
-                ROW_NUMBER() OVER (ORDER BY (a.vec <=> b.vec)) AS rank
-              FROM
-                word2vec AS a
-              LEFT JOIN
-                word2vec AS b on b.word = '{0}'
-            ) AS s ON a.word = s.word
-            WITH DATA
-            "#,
-                self.word
-            )
-            .as_str(),
-        )
-        .execute(db)
-        .await?;
-
-        sqlx::query(
-            format!(
-                r#"
-            CREATE UNIQUE INDEX word2vec_{0}_idx ON word2vec_{0} (word)
+                word2vec
+            WHERE
+                word = ANY($1)
+            ORDER BY
+                word
             "#,
-                self.word
-            )
-            .as_str(),
+            words
         )
-        .execute(db)
+        .fetch_all(db)
         .await?;
 
-        Ok(())
+        Ok(known)
     }
 
-    /// Cleanup materialised views
-    ///
-    /// Get a list of all materialised views and drop them, unless they have an active game that is
-    /// using them. This is intended to be ran as a nightly task.
-    pub async fn cleanup_materialised_views(db: &sqlx::PgPool) -> Result<(), SimilariumError> {
-        let inactive_matviews: Vec<String> = sqlx::query_scalar!(
+    /// Get every word in the word2vec table
+    pub async fn get_all_words(db: &sqlx::PgPool) -> Result<Vec<String>, SimilariumError> {
+        let words = sqlx::query_scalar!(
             r#"
             SELECT
-                matviewname
+                word
             FROM
-                pg_matviews
-            WHERE
-                matviewname NOT IN (
-                    SELECT DISTINCT
-                        'word2vec_'||secret
-                    FROM
-                        game
-                    WHERE
-                        active=true
-                )
-            "#,
+                word2vec
+            "#
         )
         .fetch_all(db)
-        .await?
-        .into_iter()
-        .flatten()
-        .collect();
+        .await?;
 
-        // Drop the matviews that are not in use
-        for matview in inactive_matviews {
-            log::debug!("Dropping matview {}", matview);
-            sqlx::query(format!("DROP MATERIALIZED VIEW {}", matview).as_str())
-                .execute(db)
-                .await?;
-        }
+        Ok(words)
+    }
 
-        Ok(())
+    /// Compute the similarity of every word against this word, if not computed already
+    pub async fn compute_similarities(
+        &self,
+        backend: &impl SimilarityBackend,
+    ) -> Result<(), SimilariumError> {
+        backend.compute_similarities(&self.word).await
     }
 
     /// Get the rank and similarity of a provided word against the target word
     ///
-    /// The word itself will have rank 0 and similarity at 1.0, with the next being below 1.0 and
-    /// rank 1 and so on.
+    /// The word itself will have rank 0, with the next closest word at rank 1 and so on.
     pub async fn get_similarity(
         &self,
         word: &str,
-        db: &sqlx::PgPool,
+        backend: &impl SimilarityBackend,
     ) -> Result<Similarity, SimilariumError> {
-        let q = format!(
-            r#"
-            SELECT
-                word, rank, similarity
-            FROM
-                word2vec_{}
-            WHERE
-                word = $1
-            "#,
-            self.word
-        );
-        let row: (String, i64, f64) = sqlx::query_as(q.as_str()).bind(word).fetch_one(db).await?;
+        backend.get_similarity(&self.word, word).await
+    }
 
-        Ok(Similarity {
-            word: row.0,
-            rank: row.1,
-            similarity: row.2,
-        })
+    /// Get the word at the given rank against the target word
+    pub async fn get_word_at_rank(
+        &self,
+        rank: i64,
+        backend: &impl SimilarityBackend,
+    ) -> Result<Similarity, SimilariumError> {
+        backend.get_word_at_rank(&self.word, rank).await
+    }
+
+    /// Get the closest words to the target word, excluding the target word itself
+    pub async fn get_closest_words(
+        &self,
+        count: i64,
+        backend: &impl SimilarityBackend,
+    ) -> Result<Vec<String>, SimilariumError> {
+        backend.get_closest_words(&self.word, count).await
     }
 }
@@ -0,0 +1,334 @@
+use crate::spelling::americanise;
+use phf::{phf_map, Map};
+
+/// Inflections that the suffix rules can't undo, mapped to their lemma
+static IRREGULAR_FORMS: Map<&'static str, &'static str> = phf_map! {
+  "ate"=>"eat",
+  "became"=>"become",
+  "began"=>"begin",
+  "begun"=>"begin",
+  "best"=>"good",
+  "better"=>"good",
+  "bought"=>"buy",
+  "broke"=>"break",
+  "broken"=>"break",
+  "brought"=>"bring",
+  "built"=>"build",
+  "came"=>"come",
+  "caught"=>"catch",
+  "children"=>"child",
+  "chose"=>"choose",
+  "chosen"=>"choose",
+  "did"=>"do",
+  "done"=>"do",
+  "drank"=>"drink",
+  "drawn"=>"draw",
+  "drew"=>"draw",
+  "driven"=>"drive",
+  "drove"=>"drive",
+  "drunk"=>"drink",
+  "eaten"=>"eat",
+  "fallen"=>"fall",
+  "feet"=>"foot",
+  "fell"=>"fall",
+  "felt"=>"feel",
+  "flew"=>"fly",
+  "flown"=>"fly",
+  "forgot"=>"forget",
+  "forgotten"=>"forget",
+  "fought"=>"fight",
+  "found"=>"find",
+  "froze"=>"freeze",
+  "frozen"=>"freeze",
+  "gave"=>"give",
+  "geese"=>"goose",
+  "given"=>"give",
+  "gone"=>"go",
+  "got"=>"get",
+  "gotten"=>"get",
+  "grew"=>"grow",
+  "grown"=>"grow",
+  "had"=>"have",
+  "heard"=>"hear",
+  "held"=>"hold",
+  "hid"=>"hide",
+  "hidden"=>"hide",
+  "kept"=>"keep",
+  "knew"=>"know",
+  "known"=>"know",
+  "led"=>"lead",
+  "left"=>"leave",
+  "lost"=>"lose",
+  "made"=>"make",
+  "meant"=>"mean",
+  "men"=>"man",
+  "met"=>"meet",
+  "mice"=>"mouse",
+  "paid"=>"pay",
+  "people"=>"person",
+  "ran"=>"run",
+  "rode"=>"ride",
+  "ridden"=>"ride",
+  "risen"=>"rise",
+  "rose"=>"rise",
+  "said"=>"say",
+  "sang"=>"sing",
+  "sat"=>"sit",
+  "saw"=>"see",
+  "seen"=>"see",
+  "sent"=>"send",
+  "shaken"=>"shake",
+  "shook"=>"shake",
+  "slept"=>"sleep",
+  "sold"=>"sell",
+  "spent"=>"spend",
+  "spoke"=>"speak",
+  "spoken"=>"speak",
+  "stole"=>"steal",
+  "stolen"=>"steal",
+  "stood"=>"stand",
+  "sung"=>"sing",
+  "swam"=>"swim",
+  "swum"=>"swim",
+  "taken"=>"take",
+  "taught"=>"teach",
+  "teeth"=>"tooth",
+  "thought"=>"think",
+  "threw"=>"throw",
+  "thrown"=>"throw",
+  "told"=>"tell",
+  "took"=>"take",
+  "understood"=>"understand",
+  "was"=>"be",
+  "been"=>"be",
+  "went"=>"go",
+  "were"=>"be",
+  "woke"=>"wake",
+  "woken"=>"wake",
+  "women"=>"woman",
+  "won"=>"win",
+  "wore"=>"wear",
+  "worn"=>"wear",
+  "worse"=>"bad",
+  "worst"=>"bad",
+  "written"=>"write",
+  "wrote"=>"write",
+};
+
+/// The shortest stem that is left after removing a suffix
+const MIN_STEM_LENGTH: usize = 3;
+
+fn is_vowel(c: char) -> bool {
+    "aeiou".contains(c)
+}
+
+/// Check if a stem ends consonant-vowel-consonant, such as "bak" from "baking", where the
+/// inflection most likely dropped an "e"
+fn ends_with_cvc(stem: &str) -> bool {
+    let mut chars = stem.chars().rev();
+    match (chars.next(), chars.next(), chars.next()) {
+        (Some(last), Some(middle), Some(first)) => {
+            !is_vowel(last) && !"wxy".contains(last) && is_vowel(middle) && !is_vowel(first)
+        }
+        _ => false,
+    }
+}
+
+/// Get the words that a stem could have come from once an ending such as "ing" or "ed" is
+/// removed, most likely first
+///
+/// "running" gives "run", "baking" gives "bake" and "walking" gives "walk".
+fn get_stem_candidates(stem: &str) -> Vec<String> {
+    let mut chars = stem.chars().rev();
+    if let (Some(last), Some(previous)) = (chars.next(), chars.next()) {
+        if last == previous && !is_vowel(last) && !"lsz".contains(last) {
+            let undoubled = &stem[..stem.len() - last.len_utf8()];
+            return vec![undoubled.to_string(), stem.to_string()];
+        }
+    }
+
+    let with_e = format!("{}e", stem);
+    match ends_with_cvc(stem) {
+        true => vec![with_e, stem.to_string()],
+        false => vec![stem.to_string(), with_e],
+    }
+}
+
+/// Get the lemmas that a word could be an inflection of, most likely first
+///
+/// This is a rule based lemmatizer for English, so it doesn't know which words exist and the
+/// candidates should be checked against the vocabulary. The word itself isn't included.
+pub fn get_lemmas(word: &str) -> Vec<String> {
+    let mut candidates = Vec::new();
+
+    if let Some(lemma) = IRREGULAR_FORMS.get(word) {
+        candidates.push(lemma.to_string());
+    }
+
+    let mut strip = |suffix: &str, replacements: &[&str]| {
+        let Some(stem) = word.strip_suffix(suffix) else {
+            return;
+        };
+        if stem.chars().count() < MIN_STEM_LENGTH {
+            return;
+        }
+        match replacements {
+            [] => candidates.extend(get_stem_candidates(stem)),
+            replacements => candidates.extend(
+                replacements
+                    .iter()
+                    .map(|replacement| format!("{}{}", stem, replacement)),
+            ),
+        }
+    };
+
+    strip("ies", &["y"]);
+    strip("ied", &["y"]);
+    strip("ves", &["f", "fe"]);
+    strip("iest", &["y"]);
+    strip("ier", &["y"]);
+    strip("ily", &["y"]);
+    strip("ing", &[]);
+    strip("ed", &[]);
+    strip("est", &[]);
+    strip("er", &[]);
+    strip("ly", &[""]);
+    strip("es", &[""]);
+    // Don't mistake words such as "glass", "bus" or "this" for plurals
+    if !["ss", "us", "is"]
+        .iter()
+        .any(|ending| word.ends_with(ending))
+    {
+        strip("s", &[""]);
+    }
+
+    let mut lemmas: Vec<String> = Vec::with_capacity(candidates.len());
+    for candidate in candidates {
+        if candidate != word && !lemmas.contains(&candidate) {
+            lemmas.push(candidate);
+        }
+    }
+    lemmas
+}
+
+/// Check if two different words are forms of the same lemma, such as "apples" and "apple" or
+/// "ran" and "running"
+pub fn is_inflection_of(word: &str, other: &str) -> bool {
+    if word == other {
+        return false;
+    }
+    let other_lemmas = get_lemmas(other);
+    let is_lemma_of_other = |lemma: &str| lemma == other || other_lemmas.iter().any(|o| o == lemma);
+
+    is_lemma_of_other(word)
+        || get_lemmas(word)
+            .iter()
+            .any(|lemma| is_lemma_of_other(lemma))
+}
+
+/// Clean up a guess before it's looked up, lowercasing and trimming it and using the American
+/// spelling that the vocabulary has
+pub fn normalise_guess(guess: &str) -> String {
+    americanise(guess.to_lowercase().trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalise_guess() {
+        assert_eq!(normalise_guess(" Colour "), "color");
+        assert_eq!(normalise_guess("PEAR"), "pear");
+    }
+
+    #[test]
+    fn test_get_lemmas_undoes_inflections() {
+        for (word, lemma) in [
+            ("apples", "apple"),
+            ("horses", "horse"),
+            ("boxes", "box"),
+            ("glasses", "glass"),
+            ("berries", "berry"),
+            ("wolves", "wolf"),
+            ("knives", "knife"),
+            ("running", "run"),
+            ("baking", "bake"),
+            ("walking", "walk"),
+            ("stopped", "stop"),
+            ("baked", "bake"),
+            ("played", "play"),
+            ("carried", "carry"),
+            ("bigger", "big"),
+            ("happier", "happy"),
+            ("happiest", "happy"),
+            ("quickly", "quick"),
+            ("happily", "happy"),
+            ("ran", "run"),
+            ("mice", "mouse"),
+            ("better", "good"),
+        ] {
+            assert!(
+                get_lemmas(word).contains(&lemma.to_string()),
+                "{word} {lemma} {:?}",
+                get_lemmas(word)
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_lemmas_puts_most_likely_first() {
+        assert_eq!(get_lemmas("running")[0], "run");
+        assert_eq!(get_lemmas("baking")[0], "bake");
+        assert_eq!(get_lemmas("walked")[0], "walk");
+        assert_eq!(get_lemmas("ran"), vec!["run"]);
+    }
+
+    #[test]
+    fn test_get_lemmas_leaves_short_and_uninflected_words_alone() {
+        for word in ["glass", "bus", "this", "bed", "sing", "apple", "cat"] {
+            assert_eq!(get_lemmas(word), Vec::<String>::new(), "{word}");
+        }
+    }
+
+    #[test]
+    fn test_get_lemmas_handles_non_ascii_letters() {
+        assert_eq!(get_lemmas("ññing"), Vec::<String>::new());
+        assert_eq!(get_lemmas("aññing"), vec!["añ", "aññ"]);
+        assert_eq!(get_lemmas("façades"), vec!["façad", "façade"]);
+        assert!(!is_inflection_of("ññing", "ñ"));
+    }
+
+    #[test]
+    fn test_is_inflection_of() {
+        for (word, other) in [
+            ("apple", "apples"),
+            ("horse", "horses"),
+            ("bake", "baking"),
+            ("bake", "baked"),
+            ("happy", "happier"),
+            ("happy", "happiest"),
+            ("run", "running"),
+            ("ran", "running"),
+            ("box", "boxes"),
+            ("glass", "glasses"),
+            ("quick", "quickly"),
+        ] {
+            assert!(is_inflection_of(word, other), "{word} {other}");
+            assert!(is_inflection_of(other, word), "{other} {word}");
+        }
+    }
+
+    #[test]
+    fn test_is_inflection_of_keeps_different_words_apart() {
+        for (word, other) in [
+            ("apple", "apple"),
+            ("apple", "application"),
+            ("cat", "category"),
+            ("sea", "seat"),
+            ("bake", "bakery"),
+        ] {
+            assert!(!is_inflection_of(word, other), "{word} {other}");
+        }
+    }
+}
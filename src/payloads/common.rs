@@ -89,13 +89,14 @@ pub struct Action {
     //r#type: String,
     //block_id: String,
     pub action_id: String,
+    /// Empty for buttons that link somewhere, as they don't have a value
+    #[serde(default)]
     pub value: String,
     //action_ts: String,
 }
 
-#[derive(Debug, Deserialize)]
-#[allow(dead_code)]
+#[derive(Debug, Deserialize, Clone)]
 pub struct Enterprise {
-    id: String,
-    name: Option<String>,
+    pub id: String,
+    pub name: Option<String>,
 }
@@ -1,9 +1,11 @@
-use crate::payloads::common::{Action, Channel, Message, User};
+use crate::payloads::common::{Action, Channel, Enterprise, Message, User};
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug)]
 pub struct CommandPayload {
     pub team_id: String,
+    /// Only sent for teams on Enterprise Grid
+    pub enterprise_id: Option<String>,
     pub channel_id: String,
     pub user_id: String,
     pub text: String,
@@ -19,10 +21,14 @@ pub struct EventPayload {
     // container: Container,
     // trigger_id: String,
     // team: Team,
-    // enterprise: Option<Enterprise>,
-    // is_enterprise_install: bool,
-    pub channel: Channel,
-    pub message: Message,
+    /// Only set for teams on Enterprise Grid
+    pub enterprise: Option<Enterprise>,
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+    /// The channel and message of the action, which aren't there for actions outside of
+    /// messages, such as on the Home tab
+    pub channel: Option<Channel>,
+    pub message: Option<Message>,
     // state: State,
     // response_url: String,
     pub actions: Vec<Action>,
@@ -32,3 +38,245 @@ pub struct EventPayload {
 pub struct Event {
     pub payload: String,
 }
+
+/// An event from the Events API
+#[derive(Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum CallbackEvent {
+    #[serde(rename = "app_home_opened")]
+    AppHomeOpened { user: String, tab: String },
+    /// Sent when the app is uninstalled from the workspace
+    #[serde(rename = "app_uninstalled")]
+    AppUninstalled,
+    /// Sent when tokens are revoked, listed by the IDs of the users they belong to
+    #[serde(rename = "tokens_revoked")]
+    TokensRevoked { tokens: RevokedTokens },
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct RevokedTokens {
+    #[serde(default)]
+    pub oauth: Vec<String>,
+    #[serde(default)]
+    pub bot: Vec<String>,
+}
+
+/// Everything that the Events API sends, see https://api.slack.com/apis/events-api
+#[derive(Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum EventEnvelope {
+    /// Sent when the request URL is set up, to check that we're the ones handling it
+    #[serde(rename = "url_verification")]
+    UrlVerification { challenge: String },
+    #[serde(rename = "event_callback")]
+    EventCallback {
+        team_id: String,
+        /// Only sent for teams on Enterprise Grid
+        enterprise_id: Option<String>,
+        event: CallbackEvent,
+    },
+    /// Sent when Slack stops sending events for a while, because there were too many of them
+    #[serde(rename = "app_rate_limited")]
+    AppRateLimited {
+        team_id: String,
+        minute_rate_limited: i64,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+/// An interaction with the app, such as clicking a button, sent as the `payload` of an `Event`
+#[derive(Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum InteractionPayload {
+    #[serde(rename = "block_actions")]
+    BlockActions(Box<EventPayload>),
+    #[serde(rename = "block_suggestion")]
+    BlockSuggestion,
+    #[serde(rename = "view_submission")]
+    ViewSubmission,
+    #[serde(rename = "view_closed")]
+    ViewClosed,
+    #[serde(rename = "shortcut")]
+    Shortcut,
+    #[serde(rename = "message_action")]
+    MessageAction,
+    #[serde(other)]
+    Unknown,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_url_verification() {
+        let envelope = serde_json::from_str::<EventEnvelope>(
+            r#"{"token": "x", "challenge": "3eZbrw1aB", "type": "url_verification"}"#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            envelope,
+            EventEnvelope::UrlVerification { challenge } if challenge == "3eZbrw1aB"
+        ));
+    }
+
+    #[test]
+    fn test_parse_event_callback() {
+        let envelope = serde_json::from_str::<EventEnvelope>(
+            r#"{
+                "type": "event_callback",
+                "team_id": "T123",
+                "api_app_id": "A123",
+                "enterprise_id": "E123",
+                "event": {"type": "app_home_opened", "user": "U123", "channel": "D123", "tab": "home"},
+                "event_id": "Ev123",
+                "event_time": 1700000000
+            }"#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            envelope,
+            EventEnvelope::EventCallback {
+                team_id,
+                enterprise_id: Some(enterprise_id),
+                event: CallbackEvent::AppHomeOpened { user, tab },
+            } if team_id == "T123" && enterprise_id == "E123" && user == "U123" && tab == "home"
+        ));
+    }
+
+    #[test]
+    fn test_parse_uninstall_events() {
+        let envelope = serde_json::from_str::<EventEnvelope>(
+            r#"{"type": "event_callback", "team_id": "T123", "event": {"type": "app_uninstalled"}}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            envelope,
+            EventEnvelope::EventCallback {
+                event: CallbackEvent::AppUninstalled,
+                ..
+            }
+        ));
+
+        let envelope = serde_json::from_str::<EventEnvelope>(
+            r#"{
+                "type": "event_callback",
+                "team_id": "T123",
+                "event": {"type": "tokens_revoked", "tokens": {"bot": ["U123"]}}
+            }"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            envelope,
+            EventEnvelope::EventCallback {
+                event: CallbackEvent::TokensRevoked { tokens },
+                ..
+            } if tokens.bot == ["U123"] && tokens.oauth.is_empty()
+        ));
+    }
+
+    #[test]
+    fn test_parse_unknown_events() {
+        let envelope = serde_json::from_str::<EventEnvelope>(
+            r#"{"type": "event_callback", "team_id": "T123", "event": {"type": "reaction_added"}}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            envelope,
+            EventEnvelope::EventCallback {
+                event: CallbackEvent::Unknown,
+                ..
+            }
+        ));
+
+        let envelope =
+            serde_json::from_str::<EventEnvelope>(r#"{"type": "something_new"}"#).unwrap();
+        assert!(matches!(envelope, EventEnvelope::Unknown));
+    }
+
+    #[test]
+    fn test_parse_block_actions_outside_of_a_message() {
+        let interaction = serde_json::from_str::<InteractionPayload>(
+            r#"{
+                "type": "block_actions",
+                "user": {"id": "U123", "username": "user", "name": "user", "team_id": "T123"},
+                "api_app_id": "A123",
+                "token": "x",
+                "view": {"type": "home"},
+                "actions": [{"action_id": "open-game-C123", "block_id": "b", "type": "button"}]
+            }"#,
+        )
+        .unwrap();
+
+        match interaction {
+            InteractionPayload::BlockActions(payload) => {
+                assert!(payload.channel.is_none());
+                assert!(payload.message.is_none());
+                assert_eq!(payload.actions.len(), 1);
+            }
+            _ => panic!("Expected block actions"),
+        }
+    }
+
+    #[test]
+    fn test_parse_block_actions_on_enterprise_grid() {
+        let interaction = serde_json::from_str::<InteractionPayload>(
+            r#"{
+                "type": "block_actions",
+                "user": {"id": "U123", "username": "user", "name": "user", "team_id": "T123"},
+                "api_app_id": "A123",
+                "token": "x",
+                "enterprise": {"id": "E123", "name": "Org"},
+                "is_enterprise_install": true,
+                "actions": []
+            }"#,
+        )
+        .unwrap();
+
+        match interaction {
+            InteractionPayload::BlockActions(payload) => {
+                assert_eq!(payload.enterprise.unwrap().id, "E123");
+                assert!(payload.is_enterprise_install);
+            }
+            _ => panic!("Expected block actions"),
+        }
+    }
+
+    #[test]
+    fn test_parse_command_on_enterprise_grid() {
+        let payload = serde_urlencoded::from_str::<CommandPayload>(
+            "team_id=T123&enterprise_id=E123&channel_id=C123&user_id=U123&text=help\
+             &api_app_id=A123",
+        )
+        .unwrap();
+        assert_eq!(payload.enterprise_id.as_deref(), Some("E123"));
+
+        let payload = serde_urlencoded::from_str::<CommandPayload>(
+            "team_id=T123&channel_id=C123&user_id=U123&text=help&api_app_id=A123",
+        )
+        .unwrap();
+        assert!(payload.enterprise_id.is_none());
+    }
+
+    #[test]
+    fn test_parse_other_interactions() {
+        for (r#type, expected) in [
+            ("block_suggestion", "BlockSuggestion"),
+            ("view_submission", "ViewSubmission"),
+            ("shortcut", "Shortcut"),
+            ("workflow_step_edit", "Unknown"),
+        ] {
+            let interaction = serde_json::from_str::<InteractionPayload>(&format!(
+                r#"{{"type": "{}", "callback_id": "x", "trigger_id": "y"}}"#,
+                r#type
+            ))
+            .unwrap();
+            assert_eq!(format!("{:?}", interaction), expected);
+        }
+    }
+}
@@ -0,0 +1,459 @@
+use crate::{
+    importer::{VectorFormat, VectorReader},
+    models::Similarity,
+    similarity::SimilarityBackend,
+    SimilariumError, SimilariumErrorType,
+};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// How many words are read from the word2vec table per query when loading the vectors
+const LOAD_BATCH_SIZE: i64 = 10_000;
+
+/// How many products are summed side by side in `dot`, so the compiler can vectorise the loop
+const LANES: usize = 8;
+
+/// The ranking of every word against a secret
+#[derive(Debug)]
+struct RankedSecret {
+    /// The index of the word at each rank, with the secret itself at rank 0
+    words_by_rank: Vec<u32>,
+    /// The rank of each word, by the index of the word
+    ranks: Vec<u32>,
+    /// The cosine similarity of each word to the secret, by the index of the word
+    similarities: Vec<f32>,
+}
+
+/// The ranked secrets, along with when each was last used so the least recently used is evicted
+#[derive(Debug, Default)]
+struct RankCache {
+    secrets: HashMap<String, (Arc<RankedSecret>, u64)>,
+    tick: u64,
+}
+
+/// Every word and its vector
+#[derive(Debug, Default)]
+struct Vocabulary {
+    words: Vec<String>,
+    index: HashMap<String, u32>,
+    dimensions: usize,
+    /// The normalised vectors of every word back to back, so cosine similarity is a dot product
+    vectors: Vec<f32>,
+}
+
+/// Similarities computed in-process from vectors held in memory
+///
+/// The vectors are loaded once at startup, either from the `word2vec` table or from a vector
+/// file, and don't need the pgvecto.rs extension. Ranking a secret compares it against the whole
+/// vocabulary, so the ranked list is cached for the secrets of active games.
+#[derive(Debug)]
+pub struct InMemoryBackend {
+    /// Shared with the blocking threads that rank the words
+    vocabulary: Arc<Vocabulary>,
+    cache_size: usize,
+    cache: Mutex<RankCache>,
+}
+
+/// Sum the products of two slices of the same length
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    let split = a.len() - a.len() % LANES;
+    let mut sums = [0.0f32; LANES];
+    for (chunk_a, chunk_b) in a[..split]
+        .chunks_exact(LANES)
+        .zip(b[..split].chunks_exact(LANES))
+    {
+        for ((sum, x), y) in sums.iter_mut().zip(chunk_a).zip(chunk_b) {
+            *sum += x * y;
+        }
+    }
+
+    let remainder = a[split..]
+        .iter()
+        .zip(&b[split..])
+        .map(|(x, y)| x * y)
+        .sum::<f32>();
+
+    sums.iter().sum::<f32>() + remainder
+}
+
+/// Scale a vector to unit length, leaving a zero vector as is
+fn normalise(vec: &mut [f32]) {
+    let norm = dot(vec, vec).sqrt();
+    if norm > 0.0 {
+        vec.iter_mut().for_each(|value| *value /= norm);
+    }
+}
+
+fn not_found(word: &str) -> SimilariumError {
+    SimilariumError {
+        message: Some(format!("{} is not in the vocabulary", word)),
+        error_type: SimilariumErrorType::NotFound,
+    }
+}
+
+impl Vocabulary {
+    fn vector(&self, idx: u32) -> &[f32] {
+        let start = idx as usize * self.dimensions;
+        &self.vectors[start..start + self.dimensions]
+    }
+
+    /// Rank every word against the secret
+    fn rank_words(&self, secret: &str) -> Result<RankedSecret, SimilariumError> {
+        let Some(&secret_idx) = self.index.get(secret) else {
+            return value_error!("{} is not in the vocabulary", secret);
+        };
+
+        let target = self.vector(secret_idx);
+        let similarities = self
+            .vectors
+            .chunks_exact(self.dimensions)
+            .map(|vec| dot(target, vec))
+            .collect::<Vec<_>>();
+
+        // The secret always comes first, even if another word has an identical vector
+        let mut words_by_rank = (0..self.words.len() as u32).collect::<Vec<_>>();
+        words_by_rank.sort_unstable_by(|&a, &b| {
+            (b == secret_idx)
+                .cmp(&(a == secret_idx))
+                .then(similarities[b as usize].total_cmp(&similarities[a as usize]))
+                .then(a.cmp(&b))
+        });
+
+        let mut ranks = vec![0; self.words.len()];
+        for (rank, &idx) in words_by_rank.iter().enumerate() {
+            ranks[idx as usize] = rank as u32;
+        }
+
+        Ok(RankedSecret {
+            words_by_rank,
+            ranks,
+            similarities,
+        })
+    }
+}
+
+impl InMemoryBackend {
+    /// Create the backend from words and their vectors
+    ///
+    /// The dimensions are taken from the first vector, and every other vector has to match. If a
+    /// word appears more than once, only the first vector is kept.
+    pub fn new(
+        entries: impl IntoIterator<Item = (String, Vec<f32>)>,
+        cache_size: usize,
+    ) -> Result<Self, SimilariumError> {
+        let mut vocabulary = Vocabulary::default();
+
+        for (word, mut vec) in entries {
+            if vocabulary.index.contains_key(&word) {
+                continue;
+            }
+            if vocabulary.words.is_empty() {
+                vocabulary.dimensions = vec.len();
+            } else if vec.len() != vocabulary.dimensions {
+                return value_error!(
+                    "Expected {} dimensions for {}, got {}",
+                    vocabulary.dimensions,
+                    word,
+                    vec.len()
+                );
+            }
+
+            normalise(&mut vec);
+            vocabulary.vectors.extend_from_slice(&vec);
+            vocabulary
+                .index
+                .insert(word.clone(), vocabulary.words.len() as u32);
+            vocabulary.words.push(word);
+        }
+
+        Ok(InMemoryBackend {
+            vocabulary: Arc::new(vocabulary),
+            cache_size: cache_size.max(1),
+            cache: Mutex::new(RankCache::default()),
+        })
+    }
+
+    /// Load the vectors from a word2vec file, in the format guessed from the extension
+    pub fn load_from_file(path: &Path, cache_size: usize) -> Result<Self, SimilariumError> {
+        log::info!("Loading vectors from {}", path.display());
+        let file = BufReader::new(File::open(path)?);
+        let reader = VectorReader::new(file, VectorFormat::from_path(path))?;
+        let entries = reader.collect::<Result<Vec<_>, _>>()?;
+
+        let backend = InMemoryBackend::new(entries, cache_size)?;
+        log::info!("Loaded {} vectors", backend.len());
+
+        Ok(backend)
+    }
+
+    /// Load the vectors from the word2vec table
+    pub async fn load_from_db(
+        db: &sqlx::PgPool,
+        cache_size: usize,
+    ) -> Result<Self, SimilariumError> {
+        log::info!("Loading vectors from the word2vec table");
+        let mut entries = vec![];
+        let mut last_word = String::new();
+        loop {
+            let rows = sqlx::query!(
+                r#"
+                SELECT
+                    word, vec
+                FROM
+                    word2vec
+                WHERE
+                    word > $1
+                ORDER BY
+                    word ASC
+                LIMIT $2
+                "#,
+                last_word,
+                LOAD_BATCH_SIZE
+            )
+            .fetch_all(db)
+            .await?;
+
+            let Some(last_row) = rows.last() else {
+                break;
+            };
+            last_word = last_row.word.clone();
+
+            for row in rows {
+                entries.push((row.word, row.vec));
+            }
+        }
+
+        let backend = InMemoryBackend::new(entries, cache_size)?;
+        log::info!("Loaded {} vectors", backend.len());
+
+        Ok(backend)
+    }
+
+    /// How many words are in the vocabulary
+    pub fn len(&self) -> usize {
+        self.vocabulary.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vocabulary.words.is_empty()
+    }
+
+    /// Get the ranked words for a secret, ranking them if they aren't cached already
+    ///
+    /// Ranking goes through the whole vocabulary, so it runs on a blocking thread rather than
+    /// holding up the other requests on the executor.
+    async fn get_ranked(&self, secret: &str) -> Result<Arc<RankedSecret>, SimilariumError> {
+        {
+            let mut cache = self.cache.lock().unwrap_or_else(PoisonError::into_inner);
+            cache.tick += 1;
+            let tick = cache.tick;
+            if let Some((ranked, last_used)) = cache.secrets.get_mut(secret) {
+                *last_used = tick;
+                return Ok(ranked.clone());
+            }
+        }
+
+        // The ranking is done without holding the lock, so lookups for other secrets aren't
+        // blocked by it
+        log::info!("Ranking words for {}", secret);
+        let vocabulary = self.vocabulary.clone();
+        let owned_secret = secret.to_string();
+        let ranked = Arc::new(
+            tokio::task::spawn_blocking(move || vocabulary.rank_words(&owned_secret)).await??,
+        );
+
+        let mut cache = self.cache.lock().unwrap_or_else(PoisonError::into_inner);
+        cache.tick += 1;
+        let tick = cache.tick;
+        cache
+            .secrets
+            .insert(secret.to_string(), (ranked.clone(), tick));
+        while cache.secrets.len() > self.cache_size {
+            let oldest = cache
+                .secrets
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(secret, _)| secret.clone());
+            if let Some(oldest) = oldest {
+                log::debug!("Evicting {} from the similarity cache", oldest);
+                cache.secrets.remove(&oldest);
+            }
+        }
+
+        Ok(ranked)
+    }
+
+    fn get_similarity_at(&self, ranked: &RankedSecret, idx: u32) -> Similarity {
+        Similarity {
+            word: self.vocabulary.words[idx as usize].clone(),
+            rank: ranked.ranks[idx as usize] as i64,
+            similarity: ranked.similarities[idx as usize] as f64 * 100.0,
+        }
+    }
+}
+
+impl SimilarityBackend for InMemoryBackend {
+    async fn compute_similarities(&self, secret: &str) -> Result<(), SimilariumError> {
+        self.get_ranked(secret).await?;
+
+        Ok(())
+    }
+
+    async fn get_similarity(
+        &self,
+        secret: &str,
+        word: &str,
+    ) -> Result<Similarity, SimilariumError> {
+        let ranked = self.get_ranked(secret).await?;
+        let Some(&idx) = self.vocabulary.index.get(word) else {
+            return Err(not_found(word));
+        };
+
+        Ok(self.get_similarity_at(&ranked, idx))
+    }
+
+    async fn get_word_at_rank(
+        &self,
+        secret: &str,
+        rank: i64,
+    ) -> Result<Similarity, SimilariumError> {
+        let ranked = self.get_ranked(secret).await?;
+        if rank < 0 {
+            return Err(not_found(&format!("Rank {}", rank)));
+        }
+        let rank = (rank as usize).min(ranked.words_by_rank.len() - 1);
+
+        Ok(self.get_similarity_at(&ranked, ranked.words_by_rank[rank]))
+    }
+
+    async fn get_closest_words(
+        &self,
+        secret: &str,
+        count: i64,
+    ) -> Result<Vec<String>, SimilariumError> {
+        let ranked = self.get_ranked(secret).await?;
+
+        Ok(ranked
+            .words_by_rank
+            .iter()
+            .skip(1)
+            .take(count.max(0) as usize)
+            .map(|&idx| self.vocabulary.words[idx as usize].clone())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_backend(cache_size: usize) -> InMemoryBackend {
+        InMemoryBackend::new(
+            vec![
+                ("cat".to_string(), vec![1.0, 0.0, 0.0]),
+                ("kitten".to_string(), vec![0.9, 0.1, 0.0]),
+                ("dog".to_string(), vec![0.5, 0.5, 0.0]),
+                ("car".to_string(), vec![0.0, 0.0, 1.0]),
+            ],
+            cache_size,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_dot() {
+        let a = (0..19).map(|x| x as f32).collect::<Vec<_>>();
+        let expected = a.iter().map(|x| x * x).sum::<f32>();
+        assert_eq!(dot(&a, &a), expected);
+        assert_eq!(dot(&[], &[]), 0.0);
+    }
+
+    #[test]
+    fn test_normalise() {
+        let mut vec = vec![3.0, 4.0];
+        normalise(&mut vec);
+        assert_eq!(vec, vec![0.6, 0.8]);
+
+        let mut zero = vec![0.0, 0.0];
+        normalise(&mut zero);
+        assert_eq!(zero, vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_new_requires_matching_dimensions() {
+        let err = InMemoryBackend::new(
+            vec![
+                ("cat".to_string(), vec![1.0, 0.0]),
+                ("dog".to_string(), vec![1.0]),
+            ],
+            1,
+        )
+        .unwrap_err();
+        assert_eq!(err.error_type, SimilariumErrorType::ValueError);
+    }
+
+    #[actix_web::test]
+    async fn test_get_similarity() {
+        let backend = get_backend(1);
+
+        let secret = backend.get_similarity("cat", "cat").await.unwrap();
+        assert_eq!(secret.rank, 0);
+        assert!((secret.similarity - 100.0).abs() < 1e-4);
+
+        let kitten = backend.get_similarity("cat", "kitten").await.unwrap();
+        assert_eq!(kitten.rank, 1);
+        let car = backend.get_similarity("cat", "car").await.unwrap();
+        assert_eq!(car.rank, 3);
+        assert_eq!(car.similarity, 0.0);
+
+        let err = backend.get_similarity("cat", "bird").await.unwrap_err();
+        assert_eq!(err.error_type, SimilariumErrorType::NotFound);
+        let err = backend.get_similarity("bird", "cat").await.unwrap_err();
+        assert_eq!(err.error_type, SimilariumErrorType::ValueError);
+    }
+
+    #[actix_web::test]
+    async fn test_get_word_at_rank() {
+        let backend = get_backend(1);
+
+        assert_eq!(
+            backend.get_word_at_rank("cat", 2).await.unwrap().word,
+            "dog"
+        );
+        // Past the end of the vocabulary the furthest word is used instead
+        assert_eq!(
+            backend.get_word_at_rank("cat", 100).await.unwrap().word,
+            "car"
+        );
+        assert!(backend.get_word_at_rank("cat", -1).await.is_err());
+    }
+
+    #[actix_web::test]
+    async fn test_get_closest_words() {
+        let backend = get_backend(1);
+
+        assert_eq!(
+            backend.get_closest_words("cat", 2).await.unwrap(),
+            vec!["kitten", "dog"]
+        );
+        assert_eq!(backend.get_closest_words("cat", 10).await.unwrap().len(), 3);
+    }
+
+    #[actix_web::test]
+    async fn test_cache_evicts_least_recently_used() {
+        let backend = get_backend(2);
+
+        backend.compute_similarities("cat").await.unwrap();
+        backend.compute_similarities("dog").await.unwrap();
+        backend.compute_similarities("cat").await.unwrap();
+        backend.compute_similarities("car").await.unwrap();
+
+        let cache = backend.cache.lock().unwrap();
+        let mut secrets = cache.secrets.keys().collect::<Vec<_>>();
+        secrets.sort();
+        assert_eq!(secrets, vec!["car", "cat"]);
+    }
+}
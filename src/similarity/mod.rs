@@ -0,0 +1,178 @@
+mod memory;
+mod postgres;
+
+use crate::{config::get_config, db::get_pool, models::Similarity, SimilariumError};
+use std::future::Future;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::OnceCell;
+
+pub use memory::InMemoryBackend;
+pub use postgres::PostgresBackend;
+
+/// Ranks words by how similar they are to a secret
+///
+/// The secret itself has rank 0, with the closest word at rank 1 and so on, and the similarity is
+/// the cosine similarity scaled to 100.
+pub trait SimilarityBackend {
+    /// Get the similarities against a secret ready, so guesses can be ranked against it
+    fn compute_similarities(
+        &self,
+        secret: &str,
+    ) -> impl Future<Output = Result<(), SimilariumError>>;
+
+    /// Get the rank and similarity of a word against a secret
+    ///
+    /// Errors with `NotFound` if the word isn't in the vocabulary
+    fn get_similarity(
+        &self,
+        secret: &str,
+        word: &str,
+    ) -> impl Future<Output = Result<Similarity, SimilariumError>>;
+
+    /// Get the word at the given rank against a secret
+    ///
+    /// If there isn't a word at exactly that rank, such as when there are fewer words than the
+    /// rank, the next word that's closer to the secret is returned instead.
+    fn get_word_at_rank(
+        &self,
+        secret: &str,
+        rank: i64,
+    ) -> impl Future<Output = Result<Similarity, SimilariumError>>;
+
+    /// Get the closest words to a secret, excluding the secret itself
+    fn get_closest_words(
+        &self,
+        secret: &str,
+        count: i64,
+    ) -> impl Future<Output = Result<Vec<String>, SimilariumError>>;
+}
+
+/// Which `SimilarityBackend` to use, set with the `SIMILARITY_BACKEND` env variable
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SimilarityBackendKind {
+    Postgres,
+    InMemory,
+}
+
+impl FromStr for SimilarityBackendKind {
+    type Err = SimilariumError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "postgres" => Ok(SimilarityBackendKind::Postgres),
+            "memory" => Ok(SimilarityBackendKind::InMemory),
+            _ => value_error!(
+                "Unknown similarity backend {:?}, expected \"postgres\" or \"memory\"",
+                value
+            ),
+        }
+    }
+}
+
+/// The configured `SimilarityBackend`, which is cheap to clone
+#[derive(Debug, Clone)]
+pub enum SimilarityEngine {
+    Postgres(PostgresBackend),
+    InMemory(Arc<InMemoryBackend>),
+}
+
+impl SimilarityBackend for SimilarityEngine {
+    async fn compute_similarities(&self, secret: &str) -> Result<(), SimilariumError> {
+        match self {
+            SimilarityEngine::Postgres(backend) => backend.compute_similarities(secret).await,
+            SimilarityEngine::InMemory(backend) => backend.compute_similarities(secret).await,
+        }
+    }
+
+    async fn get_similarity(
+        &self,
+        secret: &str,
+        word: &str,
+    ) -> Result<Similarity, SimilariumError> {
+        match self {
+            SimilarityEngine::Postgres(backend) => backend.get_similarity(secret, word).await,
+            SimilarityEngine::InMemory(backend) => backend.get_similarity(secret, word).await,
+        }
+    }
+
+    async fn get_word_at_rank(
+        &self,
+        secret: &str,
+        rank: i64,
+    ) -> Result<Similarity, SimilariumError> {
+        match self {
+            SimilarityEngine::Postgres(backend) => backend.get_word_at_rank(secret, rank).await,
+            SimilarityEngine::InMemory(backend) => backend.get_word_at_rank(secret, rank).await,
+        }
+    }
+
+    async fn get_closest_words(
+        &self,
+        secret: &str,
+        count: i64,
+    ) -> Result<Vec<String>, SimilariumError> {
+        match self {
+            SimilarityEngine::Postgres(backend) => backend.get_closest_words(secret, count).await,
+            SimilarityEngine::InMemory(backend) => backend.get_closest_words(secret, count).await,
+        }
+    }
+}
+
+static SIMILARITY_ENGINE: OnceCell<SimilarityEngine> = OnceCell::const_new();
+
+/// Get the configured similarity engine
+///
+/// The in-memory backend loads every vector the first time this is called, so it should be
+/// called at startup rather than on the first guess.
+pub async fn get_similarity_engine() -> Result<&'static SimilarityEngine, SimilariumError> {
+    SIMILARITY_ENGINE
+        .get_or_try_init(|| async {
+            let config = get_config();
+            let engine = match config.similarity_backend {
+                SimilarityBackendKind::Postgres => {
+                    let backend = PostgresBackend::new(get_pool().clone());
+                    if !backend.has_extension().await? {
+                        return value_error!(
+                            "The postgres similarity backend needs the pgvecto.rs extension, \
+                            use SIMILARITY_BACKEND=memory on plain Postgres"
+                        );
+                    }
+                    SimilarityEngine::Postgres(backend)
+                }
+                SimilarityBackendKind::InMemory => {
+                    let backend = match &config.word2vec_path {
+                        Some(path) => {
+                            InMemoryBackend::load_from_file(path, config.similarity_cache_size)?
+                        }
+                        None => {
+                            InMemoryBackend::load_from_db(get_pool(), config.similarity_cache_size)
+                                .await?
+                        }
+                    };
+                    SimilarityEngine::InMemory(Arc::new(backend))
+                }
+            };
+
+            Ok(engine)
+        })
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_similarity_backend_kind() {
+        assert_eq!(
+            "postgres".parse::<SimilarityBackendKind>().unwrap(),
+            SimilarityBackendKind::Postgres
+        );
+        assert_eq!(
+            "Memory".parse::<SimilarityBackendKind>().unwrap(),
+            SimilarityBackendKind::InMemory
+        );
+        assert!("redis".parse::<SimilarityBackendKind>().is_err());
+    }
+}
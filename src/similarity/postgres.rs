@@ -0,0 +1,213 @@
+use crate::{models::Similarity, similarity::SimilarityBackend, SimilariumError};
+
+/// Similarities computed by the pgvecto.rs extension and stored in the `secret_similarity` table
+///
+/// Computing the similarities for a secret is expensive, so it's done once per secret, ideally
+/// ahead of time by the `SecretSimilarityTask`, and shared by every game that uses it.
+#[derive(Debug, Clone)]
+pub struct PostgresBackend {
+    db: sqlx::PgPool,
+}
+
+impl PostgresBackend {
+    pub fn new(db: sqlx::PgPool) -> Self {
+        PostgresBackend { db }
+    }
+
+    /// Check if the pgvecto.rs extension is installed, which computing the similarities needs
+    pub async fn has_extension(&self) -> Result<bool, SimilariumError> {
+        let exists = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS (
+                SELECT
+                    1
+                FROM
+                    pg_extension
+                WHERE
+                    extname = 'vectors'
+            ) AS "exists!"
+            "#
+        )
+        .fetch_one(&self.db)
+        .await?;
+
+        Ok(exists)
+    }
+
+    /// Check if the similarities against a secret have already been computed
+    pub async fn has_similarities(&self, secret: &str) -> Result<bool, SimilariumError> {
+        let exists = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS (
+                SELECT
+                    1
+                FROM
+                    secret_similarity
+                WHERE
+                    secret = $1
+            ) AS "exists!"
+            "#,
+            secret
+        )
+        .fetch_one(&self.db)
+        .await?;
+
+        Ok(exists)
+    }
+
+    /// Delete the computed similarities of secrets that aren't used by any active game, or kept
+    /// for another reason such as being the secret of a channel's next game
+    ///
+    /// Returns how many secrets were deleted
+    pub async fn prune_similarities(&self, keep: &[String]) -> Result<u64, SimilariumError> {
+        let deleted = sqlx::query_scalar!(
+            r#"
+            WITH deleted AS (
+                DELETE FROM
+                    secret_similarity
+                WHERE
+                    secret NOT IN (
+                        SELECT DISTINCT
+                            secret
+                        FROM
+                            game
+                        WHERE
+                            active = true
+                    ) AND
+                    secret <> ALL($1)
+                RETURNING
+                    secret
+            )
+            SELECT
+                count(DISTINCT secret) AS "count!"
+            FROM
+                deleted
+            "#,
+            keep
+        )
+        .fetch_one(&self.db)
+        .await?;
+
+        Ok(deleted as u64)
+    }
+}
+
+impl SimilarityBackend for PostgresBackend {
+    /// Compute the similarity of every word against the secret, if not computed already
+    ///
+    /// This goes through the whole vocabulary, so it's only done once per secret.
+    async fn compute_similarities(&self, secret: &str) -> Result<(), SimilariumError> {
+        if self.has_similarities(secret).await? {
+            log::debug!("Similarities for {} already computed", secret);
+            return Ok(());
+        }
+
+        log::info!("Computing similarities for {}", secret);
+        let inserted = sqlx::query!(
+            r#"
+            INSERT INTO
+                secret_similarity (secret, word, rank, similarity)
+            SELECT
+                b.word,
+                a.word,
+                ROW_NUMBER() OVER (ORDER BY (a.vec::vector <=> b.vec::vector)) - 1,
+                (a.vec::vector <=> b.vec::vector) * -100
+            FROM
+                word2vec AS a
+            JOIN
+                word2vec AS b ON b.word = $1
+            ON CONFLICT (secret, word) DO NOTHING
+            "#,
+            secret
+        )
+        .execute(&self.db)
+        .await?
+        .rows_affected();
+
+        if inserted == 0 && !self.has_similarities(secret).await? {
+            return value_error!("{} is not in the word2vec table", secret);
+        }
+
+        Ok(())
+    }
+
+    async fn get_similarity(
+        &self,
+        secret: &str,
+        word: &str,
+    ) -> Result<Similarity, SimilariumError> {
+        let similarity = sqlx::query_as!(
+            Similarity,
+            r#"
+            SELECT
+                word, rank, similarity
+            FROM
+                secret_similarity
+            WHERE
+                secret = $1 AND
+                word = $2
+            "#,
+            secret,
+            word
+        )
+        .fetch_one(&self.db)
+        .await?;
+
+        Ok(similarity)
+    }
+
+    async fn get_word_at_rank(
+        &self,
+        secret: &str,
+        rank: i64,
+    ) -> Result<Similarity, SimilariumError> {
+        let similarity = sqlx::query_as!(
+            Similarity,
+            r#"
+            SELECT
+                word, rank, similarity
+            FROM
+                secret_similarity
+            WHERE
+                secret = $1 AND
+                rank <= $2
+            ORDER BY
+                rank DESC
+            LIMIT 1
+            "#,
+            secret,
+            rank
+        )
+        .fetch_one(&self.db)
+        .await?;
+
+        Ok(similarity)
+    }
+
+    async fn get_closest_words(
+        &self,
+        secret: &str,
+        count: i64,
+    ) -> Result<Vec<String>, SimilariumError> {
+        let words = sqlx::query_scalar!(
+            r#"
+            SELECT
+                word
+            FROM
+                secret_similarity
+            WHERE
+                secret = $1 AND
+                rank > 0
+            ORDER BY
+                rank ASC
+            LIMIT $2
+            "#,
+            secret,
+            count
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(words)
+    }
+}
@@ -16,6 +16,8 @@ enum BlockType {
     Input,
     #[serde(rename = "context")]
     Context,
+    #[serde(rename = "actions")]
+    Actions,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
@@ -111,11 +113,46 @@ impl ContextElement {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct Context {
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub struct Button {
     r#type: String,
-    block_id: String,
-    elements: Vec<ContextElement>,
+    text: Text,
+    action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+}
+
+impl Button {
+    pub fn new(action_id: &str, text: &str, value: &str) -> Self {
+        Button {
+            r#type: "button".to_string(),
+            text: Text::plain(text, true),
+            action_id: action_id.to_string(),
+            value: Some(value.to_string()),
+            url: None,
+        }
+    }
+
+    /// A button that opens a link, which Slack still sends an action for when clicked
+    pub fn link(action_id: &str, text: &str, url: &str) -> Self {
+        Button {
+            r#type: "button".to_string(),
+            text: Text::plain(text, true),
+            action_id: action_id.to_string(),
+            value: None,
+            url: Some(url.to_string()),
+        }
+    }
+}
+
+/// The elements of a block, which depend on the type of block
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(untagged)]
+pub enum BlockElement {
+    Context(ContextElement),
+    Button(Button),
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
@@ -132,7 +169,7 @@ pub struct Block {
     #[serde(skip_serializing_if = "Option::is_none")]
     label: Option<Text>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    elements: Option<Vec<ContextElement>>,
+    elements: Option<Vec<BlockElement>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     fields: Option<Vec<Text>>,
 }
@@ -177,7 +214,6 @@ impl Block {
         }
     }
 
-    #[allow(dead_code)]
     pub fn divider() -> Self {
         Block {
             ..Default::default()
@@ -201,6 +237,20 @@ impl Block {
         Block::input("guess", true, element, label)
     }
 
+    pub fn actions(block_id: &str, buttons: Vec<Button>) -> Self {
+        Block {
+            r#type: BlockType::Actions,
+            block_id: Some(block_id.to_string()),
+            elements: Some(buttons.into_iter().map(BlockElement::Button).collect()),
+            ..Default::default()
+        }
+    }
+
+    pub fn hint_button() -> Self {
+        let button = Button::new("request-hint", "Get a hint :bulb:", "hint");
+        Block::actions("hint", vec![button])
+    }
+
     pub fn guess_context(base_id: &str, context: GuessContext, game_active: bool) -> Self {
         let block_id = format!("guess-{}-{}", base_id, context.word);
 
@@ -220,7 +270,10 @@ impl Block {
             ContextElement::image(&context.profile_photo, &context.username),
             ContextElement::text(&format!("{}{}", progress_bar, formatted_rank(context.rank))),
             ContextElement::text(&word_element),
-        ];
+        ]
+        .into_iter()
+        .map(BlockElement::Context)
+        .collect();
 
         Block {
             r#type: BlockType::Context,
@@ -231,6 +284,28 @@ impl Block {
     }
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+enum ViewType {
+    #[serde(rename = "home")]
+    Home,
+}
+
+/// A view that's published to a surface outside of messages, such as the App Home tab
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub struct View {
+    r#type: ViewType,
+    blocks: Vec<Block>,
+}
+
+impl View {
+    pub fn home(blocks: Vec<Block>) -> Self {
+        View {
+            r#type: ViewType::Home,
+            blocks,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -328,6 +403,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_serialising_hint_button() {
+        let block = Block::hint_button();
+        let json = serde_json::to_string_pretty(&block).unwrap();
+        assert_eq!(
+            json,
+            r#"{
+  "type": "actions",
+  "block_id": "hint",
+  "elements": [
+    {
+      "type": "button",
+      "text": {
+        "type": "plain_text",
+        "text": "Get a hint :bulb:",
+        "emoji": true
+      },
+      "action_id": "request-hint",
+      "value": "hint"
+    }
+  ]
+}"#
+        );
+    }
+
+    #[test]
+    fn test_serialising_link_button() {
+        let block = Block::actions(
+            "game",
+            vec![Button::link(
+                "open-game",
+                "Go to game",
+                "https://example.com",
+            )],
+        );
+        let json = serde_json::to_string_pretty(&block).unwrap();
+        assert_eq!(
+            json,
+            r#"{
+  "type": "actions",
+  "block_id": "game",
+  "elements": [
+    {
+      "type": "button",
+      "text": {
+        "type": "plain_text",
+        "text": "Go to game",
+        "emoji": true
+      },
+      "action_id": "open-game",
+      "url": "https://example.com"
+    }
+  ]
+}"#
+        );
+    }
+
+    #[test]
+    fn test_serialising_home_view() {
+        let view = View::home(vec![Block::divider()]);
+        let json = serde_json::to_string(&view).unwrap();
+        assert_eq!(json, r#"{"type":"home","blocks":[{"type":"divider"}]}"#);
+    }
+
     #[test]
     fn test_guess_context() {
         let context = GuessContext {
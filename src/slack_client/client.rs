@@ -1,39 +1,196 @@
 use crate::{
     slack_client::{
+        rate_limit::{get_shared_rate_limiter, RateLimiter, RetryPolicy, DEFAULT_RETRY_AFTER},
         responses::{SlackOAuthResponse, UserInfoResponse},
-        traits::{SlackMessage, SlackOAuth, SlackUserDetails},
-        Block,
+        traits::{SlackMessage, SlackOAuth, SlackUserDetails, SlackViews},
+        Block, View,
     },
     SimilariumError,
 };
+use reqwest::{header::RETRY_AFTER, StatusCode};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 const CHAT_UPDATE_PATH: &str = "/chat.update";
 const OAUTH_API_PATH: &str = "/oauth.v2.access";
 const POST_MESSAGE_PATH: &str = "/chat.postMessage";
 const USER_DETAILS_PATH: &str = "/users.info";
 const POST_EPHEMERAL_PATH: &str = "/chat.postEphemeral";
+const VIEWS_PUBLISH_PATH: &str = "/views.publish";
 
 pub struct SlackClient {
     client: reqwest::Client,
     base_url: String,
+    rate_limiter: Arc<RateLimiter>,
+    retry_policy: RetryPolicy,
+}
+
+/// Whether a Slack API method can be called again without repeating what it does
+///
+/// A server error or a dropped connection can come after Slack has already posted a message, so
+/// only these methods are retried then. Any method is retried when it's rate limited, as Slack
+/// didn't run it.
+fn is_idempotent(path: &str) -> bool {
+    matches!(
+        path,
+        CHAT_UPDATE_PATH | VIEWS_PUBLISH_PATH | USER_DETAILS_PATH
+    )
+}
+
+/// Why a call to the Slack API should be tried again
+enum Retry {
+    /// Slack rate limited the call and asked us to wait
+    After(Duration),
+    /// Slack had a problem, so wait a little longer after each attempt
+    Backoff,
 }
 
 impl SlackClient {
+    /// Create a client with its own rate limits, which is mostly useful for testing
     pub fn new(base_url: String) -> Self {
         Self {
             client: reqwest::Client::new(),
             base_url,
+            rate_limiter: Arc::new(RateLimiter::default()),
+            retry_policy: RetryPolicy::default(),
         }
     }
 
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
     fn get_url(&self, path: &str) -> String {
         format!("{}{}", self.base_url, path)
     }
+
+    /// Post a form to a Slack API method, waiting for the method's rate limit and retrying if
+    /// Slack rate limits us, or has a problem with an idempotent method
+    ///
+    /// Errors with `RateLimited` if Slack is still rate limiting us after the last attempt, or we
+    /// would have to wait longer than the retry policy allows.
+    async fn post_form(
+        &self,
+        path: &str,
+        form: &[(&str, &str)],
+    ) -> Result<serde_json::Value, SimilariumError> {
+        let token = form
+            .iter()
+            .find_map(|(key, value)| (*key == "token").then_some(*value))
+            .unwrap_or_default();
+        let deadline = Instant::now() + self.retry_policy.max_total_delay;
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let max_wait = deadline.saturating_duration_since(Instant::now());
+            self.rate_limiter
+                .acquire(token, path, max_wait.min(self.retry_policy.max_delay))
+                .await?;
+
+            let retry = match self.client.post(self.get_url(path)).form(form).send().await {
+                Ok(res) if res.status() == StatusCode::TOO_MANY_REQUESTS => {
+                    Retry::After(get_retry_after(&res))
+                }
+                Ok(res) if res.status().is_server_error() => {
+                    log::warn!("Slack API error calling {}: {}", path, res.status());
+                    if !is_idempotent(path) {
+                        return slack_api_error!(
+                            "Error posting to Slack API: {} returned {}",
+                            path,
+                            res.status()
+                        );
+                    }
+                    Retry::Backoff
+                }
+                Ok(res) if !res.status().is_success() => {
+                    let res_text = res.text().await?;
+                    log::error!("Error posting to Slack API: {}", res_text);
+                    return slack_api_error!("Error posting to Slack API: {}", res_text);
+                }
+                Ok(res) => {
+                    let retry_after = get_retry_after(&res);
+                    let payload = res.json::<serde_json::Value>().await?;
+                    match (payload["ok"].as_bool(), payload["error"].as_str()) {
+                        (Some(true), _) => return Ok(payload),
+                        (_, Some("ratelimited")) => Retry::After(retry_after),
+                        // The workspace has uninstalled the app, or been deleted. Other auth
+                        // errors, such as `invalid_auth`, can be a mistake on our side so they
+                        // don't uninstall the team
+                        (_, Some(error @ ("token_revoked" | "account_inactive"))) => {
+                            log::warn!("Slack rejected the token calling {}: {}", path, error);
+                            return token_revoked_error!("Slack rejected the token: {}", error);
+                        }
+                        _ => {
+                            log::error!("Error posting to Slack API: {}", payload);
+                            return slack_api_error!("Error posting to Slack API: {}", payload);
+                        }
+                    }
+                }
+                Err(e) if is_idempotent(path) && attempt < self.retry_policy.max_attempts => {
+                    log::warn!("Error sending request to {}: {}", path, e);
+                    Retry::Backoff
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            let delay = match retry {
+                Retry::After(retry_after) => {
+                    log::warn!(
+                        "Rate limited calling {}, retry after {:?}",
+                        path,
+                        retry_after
+                    );
+                    self.rate_limiter.block(token, path, retry_after);
+                    if attempt >= self.retry_policy.max_attempts
+                        || retry_after > self.retry_policy.max_delay
+                        || Instant::now() + retry_after > deadline
+                    {
+                        return rate_limited_error!(
+                            "Rate limited calling {}, retry after {:?}",
+                            path,
+                            retry_after
+                        );
+                    }
+                    retry_after
+                }
+                Retry::Backoff => {
+                    let backoff = self.retry_policy.get_backoff(attempt);
+                    if attempt >= self.retry_policy.max_attempts
+                        || Instant::now() + backoff > deadline
+                    {
+                        return slack_api_error!(
+                            "Error posting to Slack API: {} failed after {} attempts",
+                            path,
+                            attempt
+                        );
+                    }
+                    backoff
+                }
+            };
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+/// Get how long Slack wants us to wait from the `Retry-After` header, which is in seconds
+fn get_retry_after(res: &reqwest::Response) -> Duration {
+    res.headers()
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map_or(DEFAULT_RETRY_AFTER, Duration::from_secs)
 }
 
 impl Default for SlackClient {
+    /// Create a client for the Slack API, sharing rate limits with every other default client
     fn default() -> Self {
-        Self::new("https://slack.com/api".to_string())
+        Self {
+            rate_limiter: get_shared_rate_limiter(),
+            ..Self::new("https://slack.com/api".to_string())
+        }
     }
 }
 
@@ -45,39 +202,13 @@ impl SlackMessage for SlackClient {
         token: &str,
         blocks: Option<Vec<Block>>,
     ) -> Result<serde_json::Value, SimilariumError> {
-        let res = if let Some(blocks) = blocks {
-            self.client
-                .post(self.get_url(POST_MESSAGE_PATH))
-                .form(&[
-                    ("token", token),
-                    ("channel", channel_id),
-                    ("text", text),
-                    ("blocks", &serde_json::to_string(&blocks).unwrap()),
-                ])
-                .send()
-                .await?
-        } else {
-            self.client
-                .post(self.get_url(POST_MESSAGE_PATH))
-                .form(&[("token", token), ("channel", channel_id), ("text", text)])
-                .send()
-                .await?
-        };
-
-        if !res.status().is_success() {
-            let res_text = res.text().await?;
-            log::error!("Error posting to Slack API: {}", res_text);
-            return slack_api_error!("Error posting to Slack API: {}", res_text);
-        }
-
-        let payload = res.json::<serde_json::Value>().await?;
-        let ok = payload["ok"].as_bool().unwrap_or(false);
-        if !ok {
-            log::error!("Error posting to Slack API: {}", payload);
-            return slack_api_error!("Error posting to Slack API: {}", payload);
+        let blocks = blocks.map(|blocks| serde_json::to_string(&blocks).unwrap());
+        let mut form = vec![("token", token), ("channel", channel_id), ("text", text)];
+        if let Some(blocks) = &blocks {
+            form.push(("blocks", blocks));
         }
 
-        Ok(payload)
+        self.post_form(POST_MESSAGE_PATH, &form).await
     }
 
     async fn post_ephemeral(
@@ -88,44 +219,18 @@ impl SlackMessage for SlackClient {
         token: &str,
         blocks: Option<Vec<Block>>,
     ) -> Result<serde_json::Value, SimilariumError> {
-        let res = if let Some(blocks) = blocks {
-            self.client
-                .post(self.get_url(POST_EPHEMERAL_PATH))
-                .form(&[
-                    ("token", token),
-                    ("channel", channel_id),
-                    ("text", text),
-                    ("user", user_id),
-                    ("blocks", &serde_json::to_string(&blocks).unwrap()),
-                ])
-                .send()
-                .await?
-        } else {
-            self.client
-                .post(self.get_url(POST_EPHEMERAL_PATH))
-                .form(&[
-                    ("token", token),
-                    ("channel", channel_id),
-                    ("text", text),
-                    ("user", user_id),
-                ])
-                .send()
-                .await?
-        };
-
-        if !res.status().is_success() {
-            log::error!("Error posting to Slack API: {}", text);
-            return slack_api_error!("Error posting to Slack API: {}", text);
+        let blocks = blocks.map(|blocks| serde_json::to_string(&blocks).unwrap());
+        let mut form = vec![
+            ("token", token),
+            ("channel", channel_id),
+            ("text", text),
+            ("user", user_id),
+        ];
+        if let Some(blocks) = &blocks {
+            form.push(("blocks", blocks));
         }
 
-        let payload = res.json::<serde_json::Value>().await?;
-        let ok = payload["ok"].as_bool().unwrap_or(false);
-        if !ok {
-            log::error!("Error posting to Slack API: {}", payload);
-            return slack_api_error!("Error posting to Slack API: {}", payload);
-        }
-
-        Ok(payload)
+        self.post_form(POST_EPHEMERAL_PATH, &form).await
     }
 
     async fn chat_update(
@@ -136,44 +241,32 @@ impl SlackMessage for SlackClient {
         token: &str,
         blocks: Option<Vec<Block>>,
     ) -> Result<serde_json::Value, SimilariumError> {
-        let res = if let Some(blocks) = blocks {
-            self.client
-                .post(self.get_url(CHAT_UPDATE_PATH))
-                .form(&[
-                    ("token", token),
-                    ("channel", channel_id),
-                    ("ts", message_ts),
-                    ("text", text),
-                    ("blocks", &serde_json::to_string(&blocks).unwrap()),
-                ])
-                .send()
-                .await?
-        } else {
-            self.client
-                .post(self.get_url(CHAT_UPDATE_PATH))
-                .form(&[
-                    ("token", token),
-                    ("channel", channel_id),
-                    ("ts", message_ts),
-                    ("text", text),
-                ])
-                .send()
-                .await?
-        };
-
-        if !res.status().is_success() {
-            log::error!("Error posting to Slack API: {}", text);
-            return slack_api_error!("Error posting to Slack API: {}", text);
+        let blocks = blocks.map(|blocks| serde_json::to_string(&blocks).unwrap());
+        let mut form = vec![
+            ("token", token),
+            ("channel", channel_id),
+            ("ts", message_ts),
+            ("text", text),
+        ];
+        if let Some(blocks) = &blocks {
+            form.push(("blocks", blocks));
         }
 
-        let payload = res.json::<serde_json::Value>().await?;
-        let ok = payload["ok"].as_bool().unwrap_or(false);
-        if !ok {
-            log::error!("Error posting to Slack API: {}", payload);
-            return slack_api_error!("Error posting to Slack API: {}", payload);
-        }
+        self.post_form(CHAT_UPDATE_PATH, &form).await
+    }
+}
+
+impl SlackViews for SlackClient {
+    async fn publish_view(
+        &self,
+        user_id: &str,
+        view: View,
+        token: &str,
+    ) -> Result<serde_json::Value, SimilariumError> {
+        let view = serde_json::to_string(&view)?;
+        let form = [("token", token), ("user_id", user_id), ("view", &view)];
 
-        Ok(payload)
+        self.post_form(VIEWS_PUBLISH_PATH, &form).await
     }
 }
 
@@ -197,6 +290,38 @@ impl SlackOAuth for SlackClient {
 
         Ok(res.json::<SlackOAuthResponse>().await?)
     }
+
+    async fn refresh_token(
+        &self,
+        refresh_token: &str,
+        client_id: &str,
+        client_secret: &str,
+    ) -> Result<SlackOAuthResponse, SimilariumError> {
+        let res = self
+            .client
+            .post(self.get_url(OAUTH_API_PATH))
+            .form(&[
+                ("grant_type", "refresh_token"),
+                ("refresh_token", refresh_token),
+                ("client_id", client_id),
+                ("client_secret", client_secret),
+            ])
+            .send()
+            .await?;
+
+        // Errors don't include the rest of the response, so check for them before parsing it
+        let payload = res.json::<serde_json::Value>().await?;
+        match (payload["ok"].as_bool(), payload["error"].as_str()) {
+            (Some(true), _) => Ok(serde_json::from_value::<SlackOAuthResponse>(payload)?),
+            (_, Some(error @ ("invalid_refresh_token" | "token_revoked"))) => {
+                token_revoked_error!("Slack rejected the refresh token: {}", error)
+            }
+            _ => {
+                log::error!("Error refreshing token: {}", payload);
+                slack_api_error!("Error refreshing token: {}", payload)
+            }
+        }
+    }
 }
 
 impl SlackUserDetails for SlackClient {
@@ -220,6 +345,7 @@ impl SlackUserDetails for SlackClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::SimilariumErrorType;
     use mockito::{Matcher, Server};
 
     #[actix_web::test]
@@ -432,6 +558,338 @@ mod tests {
         Ok(())
     }
 
+    #[actix_web::test]
+    async fn test_slack_client_chat_update_retries_after_being_rate_limited(
+    ) -> Result<(), SimilariumError> {
+        let mut server = Server::new();
+
+        let rate_limited = server
+            .mock("POST", CHAT_UPDATE_PATH)
+            .with_status(429)
+            .with_header("Retry-After", "0")
+            .expect(1)
+            .create();
+        let ok = server
+            .mock("POST", CHAT_UPDATE_PATH)
+            .with_status(200)
+            .with_body(r#"{"ok": true}"#)
+            .expect(1)
+            .create();
+
+        let slack_client = SlackClient::new(server.url());
+
+        let request = slack_client
+            .chat_update(
+                "Hello, world!",
+                "channel_x",
+                "123456.789012",
+                "token_x",
+                None,
+            )
+            .await;
+
+        rate_limited.assert();
+        ok.assert();
+
+        assert!(request.is_ok());
+
+        Ok(())
+    }
+
+    #[actix_web::test]
+    async fn test_slack_client_post_message_retries_after_ratelimited_error(
+    ) -> Result<(), SimilariumError> {
+        let mut server = Server::new();
+
+        let rate_limited = server
+            .mock("POST", POST_MESSAGE_PATH)
+            .with_status(200)
+            .with_header("Retry-After", "0")
+            .with_body(r#"{"ok": false, "error": "ratelimited"}"#)
+            .expect(1)
+            .create();
+        let ok = server
+            .mock("POST", POST_MESSAGE_PATH)
+            .with_status(200)
+            .with_body(r#"{"ok": true}"#)
+            .expect(1)
+            .create();
+
+        let slack_client = SlackClient::new(server.url());
+
+        let request = slack_client
+            .post_message("Hello, world!", "channel_x", "token_x", None)
+            .await;
+
+        rate_limited.assert();
+        ok.assert();
+
+        assert!(request.is_ok());
+
+        Ok(())
+    }
+
+    #[actix_web::test]
+    async fn test_slack_client_gives_up_when_rate_limited_for_too_long(
+    ) -> Result<(), SimilariumError> {
+        let mut server = Server::new();
+
+        let mock = server
+            .mock("POST", CHAT_UPDATE_PATH)
+            .with_status(429)
+            .with_header("Retry-After", "30")
+            .expect(1)
+            .create();
+
+        let slack_client = SlackClient::new(server.url());
+
+        let request = slack_client
+            .chat_update(
+                "Hello, world!",
+                "channel_x",
+                "123456.789012",
+                "token_x",
+                None,
+            )
+            .await;
+
+        mock.assert();
+
+        assert_eq!(
+            request.unwrap_err().error_type,
+            SimilariumErrorType::RateLimited
+        );
+
+        Ok(())
+    }
+
+    #[actix_web::test]
+    async fn test_slack_client_retries_server_errors_until_max_attempts(
+    ) -> Result<(), SimilariumError> {
+        let mut server = Server::new();
+
+        let mock = server
+            .mock("POST", CHAT_UPDATE_PATH)
+            .with_status(503)
+            .expect(2)
+            .create();
+
+        let slack_client = SlackClient::new(server.url()).with_retry_policy(RetryPolicy {
+            max_attempts: 2,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(10),
+            ..Default::default()
+        });
+
+        let request = slack_client
+            .chat_update(
+                "Hello, world!",
+                "channel_x",
+                "123456.789012",
+                "token_x",
+                None,
+            )
+            .await;
+
+        mock.assert();
+
+        assert_eq!(
+            request.unwrap_err().error_type,
+            SimilariumErrorType::SlackApiError
+        );
+
+        Ok(())
+    }
+
+    #[actix_web::test]
+    async fn test_slack_client_does_not_retry_posts_after_server_errors(
+    ) -> Result<(), SimilariumError> {
+        let mut server = Server::new();
+
+        let mock = server
+            .mock("POST", POST_MESSAGE_PATH)
+            .with_status(503)
+            .expect(1)
+            .create();
+
+        let slack_client = SlackClient::new(server.url()).with_retry_policy(RetryPolicy {
+            max_attempts: 2,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(10),
+            ..Default::default()
+        });
+
+        let request = slack_client
+            .post_message("Hello, world!", "channel_x", "token_x", None)
+            .await;
+
+        mock.assert();
+
+        assert_eq!(
+            request.unwrap_err().error_type,
+            SimilariumErrorType::SlackApiError
+        );
+
+        Ok(())
+    }
+
+    #[actix_web::test]
+    async fn test_slack_client_gives_up_rather_than_wait_past_the_total_delay(
+    ) -> Result<(), SimilariumError> {
+        let mut server = Server::new();
+
+        let mock = server
+            .mock("POST", POST_EPHEMERAL_PATH)
+            .with_status(429)
+            .with_header("Retry-After", "1")
+            .expect(1)
+            .create();
+
+        let slack_client = SlackClient::new(server.url()).with_retry_policy(RetryPolicy {
+            max_total_delay: Duration::from_millis(500),
+            ..RetryPolicy::interactive()
+        });
+
+        let request = slack_client
+            .post_ephemeral("Hello, world!", "channel_x", "user_x", "token_x", None)
+            .await;
+
+        mock.assert();
+
+        assert_eq!(
+            request.unwrap_err().error_type,
+            SimilariumErrorType::RateLimited
+        );
+
+        Ok(())
+    }
+
+    #[actix_web::test]
+    async fn test_slack_client_does_not_retry_other_errors() -> Result<(), SimilariumError> {
+        let mut server = Server::new();
+
+        let mock = server
+            .mock("POST", CHAT_UPDATE_PATH)
+            .with_status(200)
+            .with_body(r#"{"ok": false, "error": "message_not_found"}"#)
+            .expect(1)
+            .create();
+
+        let slack_client = SlackClient::new(server.url());
+
+        let request = slack_client
+            .chat_update(
+                "Hello, world!",
+                "channel_x",
+                "123456.789012",
+                "token_x",
+                None,
+            )
+            .await;
+
+        mock.assert();
+
+        assert_eq!(
+            request.unwrap_err().error_type,
+            SimilariumErrorType::SlackApiError
+        );
+
+        Ok(())
+    }
+
+    #[actix_web::test]
+    async fn test_slack_client_returns_token_revoked_for_dead_tokens() -> Result<(), SimilariumError>
+    {
+        let mut server = Server::new();
+
+        let mock = server
+            .mock("POST", CHAT_UPDATE_PATH)
+            .with_status(200)
+            .with_body(r#"{"ok": false, "error": "account_inactive"}"#)
+            .expect(1)
+            .create();
+
+        let slack_client = SlackClient::new(server.url());
+
+        let request = slack_client
+            .chat_update(
+                "Hello, world!",
+                "channel_x",
+                "123456.789012",
+                "token_x",
+                None,
+            )
+            .await;
+
+        mock.assert();
+
+        assert_eq!(
+            request.unwrap_err().error_type,
+            SimilariumErrorType::TokenRevoked
+        );
+
+        Ok(())
+    }
+
+    #[actix_web::test]
+    async fn test_slack_client_does_not_treat_invalid_auth_as_revoked(
+    ) -> Result<(), SimilariumError> {
+        let mut server = Server::new();
+
+        let mock = server
+            .mock("POST", POST_MESSAGE_PATH)
+            .with_status(200)
+            .with_body(r#"{"ok": false, "error": "invalid_auth"}"#)
+            .expect(1)
+            .create();
+
+        let slack_client = SlackClient::new(server.url());
+
+        let request = slack_client
+            .post_message("Hello, world!", "channel_x", "token_x", None)
+            .await;
+
+        mock.assert();
+
+        assert_eq!(
+            request.unwrap_err().error_type,
+            SimilariumErrorType::SlackApiError
+        );
+
+        Ok(())
+    }
+
+    #[actix_web::test]
+    async fn test_slack_client_publish_view_sends_request_to_slack() -> Result<(), SimilariumError>
+    {
+        let user_id = "user_x";
+        let token = "token_x";
+        let view = View::home(vec![Block::section("Hello, home!", None)]);
+
+        let mut server = Server::new();
+
+        let mock = server
+            .mock("POST", VIEWS_PUBLISH_PATH)
+            .with_status(200)
+            .with_body(r#"{"ok": true}"#)
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("token".into(), token.into()),
+                Matcher::UrlEncoded("user_id".into(), user_id.into()),
+                Matcher::UrlEncoded("view".into(), serde_json::to_string(&view).unwrap()),
+            ]))
+            .create();
+
+        let slack_client = SlackClient::new(server.url());
+
+        let request = slack_client.publish_view(user_id, view, token).await;
+
+        mock.assert();
+
+        assert!(request.is_ok());
+
+        Ok(())
+    }
+
     #[actix_web::test]
     async fn test_slack_client_post_oauth_code_sends_request_to_slack(
     ) -> Result<(), SimilariumError> {
@@ -465,6 +923,75 @@ mod tests {
         Ok(())
     }
 
+    #[actix_web::test]
+    async fn test_slack_client_refresh_token_sends_request_to_slack() -> Result<(), SimilariumError>
+    {
+        let mut server = Server::new();
+
+        let mock = server
+            .mock("POST", OAUTH_API_PATH)
+            .with_status(200)
+            .with_body(
+                r#"{
+                    "ok": true,
+                    "app_id": "123",
+                    "team": {"id": "team_id"},
+                    "is_enterprise_install": false,
+                    "access_token": "xoxe.xoxb-new",
+                    "refresh_token": "xoxe-refresh",
+                    "expires_in": 43200
+                }"#,
+            )
+            .match_body(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("grant_type".into(), "refresh_token".into()),
+                Matcher::UrlEncoded("refresh_token".into(), "xoxe-old".into()),
+                Matcher::UrlEncoded("client_id".into(), "client_id_x".into()),
+                Matcher::UrlEncoded("client_secret".into(), "client_secret_x".into()),
+            ]))
+            .create();
+
+        let slack_client = SlackClient::new(server.url());
+
+        let response = slack_client
+            .refresh_token("xoxe-old", "client_id_x", "client_secret_x")
+            .await?;
+
+        mock.assert();
+
+        assert_eq!(response.access_token.unwrap(), "xoxe.xoxb-new");
+        assert_eq!(response.refresh_token.unwrap(), "xoxe-refresh");
+        assert_eq!(response.expires_in, Some(43200));
+
+        Ok(())
+    }
+
+    #[actix_web::test]
+    async fn test_slack_client_refresh_token_with_invalid_refresh_token(
+    ) -> Result<(), SimilariumError> {
+        let mut server = Server::new();
+
+        let mock = server
+            .mock("POST", OAUTH_API_PATH)
+            .with_status(200)
+            .with_body(r#"{"ok": false, "error": "invalid_refresh_token"}"#)
+            .create();
+
+        let slack_client = SlackClient::new(server.url());
+
+        let request = slack_client
+            .refresh_token("xoxe-old", "client_id_x", "client_secret_x")
+            .await;
+
+        mock.assert();
+
+        assert_eq!(
+            request.unwrap_err().error_type,
+            SimilariumErrorType::TokenRevoked
+        );
+
+        Ok(())
+    }
+
     #[actix_web::test]
     async fn test_slack_client_get_user_details_sends_request_to_slack(
     ) -> Result<(), SimilariumError> {
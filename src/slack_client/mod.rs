@@ -1,9 +1,11 @@
 mod blocks;
 mod client;
+mod rate_limit;
 pub mod responses;
 mod traits;
 mod utils;
 
-pub use blocks::Block;
+pub use blocks::{Block, Button, View};
 pub use client::SlackClient;
-pub use traits::{SlackMessage, SlackOAuth, SlackUserDetails};
+pub use rate_limit::RetryPolicy;
+pub use traits::{SlackMessage, SlackOAuth, SlackUserDetails, SlackViews};
@@ -0,0 +1,326 @@
+use crate::SimilariumError;
+use rand::Rng;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, OnceLock},
+    time::{Duration, Instant},
+};
+
+/// How long to wait when Slack rate limits us without a `Retry-After` header
+pub const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// How many calls can be made in a burst, and how quickly the calls refill after that
+///
+/// These follow the Slack API tiers, see https://api.slack.com/apis/rate-limits
+fn get_method_limit(path: &str) -> (f64, f64) {
+    match path {
+        // Roughly one message per second per channel, with short bursts allowed
+        "/chat.postMessage" => (5.0, 1.0),
+        // Tier 4, 100+ per minute
+        "/chat.postEphemeral" | "/users.info" | "/views.publish" => (10.0, 100.0 / 60.0),
+        // Tier 3, 50+ per minute
+        _ => (5.0, 50.0 / 60.0),
+    }
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    capacity: f64,
+    per_second: f64,
+    tokens: f64,
+    updated_at: Instant,
+    blocked_until: Option<Instant>,
+}
+
+impl TokenBucket {
+    fn new(capacity: f64, per_second: f64, now: Instant) -> Self {
+        TokenBucket {
+            capacity,
+            per_second,
+            tokens: capacity,
+            updated_at: now,
+            blocked_until: None,
+        }
+    }
+
+    /// Take a token, or get how long to wait until there's one to take
+    fn try_take(&mut self, now: Instant) -> Result<(), Duration> {
+        if let Some(blocked_until) = self.blocked_until {
+            if now < blocked_until {
+                return Err(blocked_until - now);
+            }
+            self.blocked_until = None;
+        }
+
+        let elapsed = now.saturating_duration_since(self.updated_at);
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * self.per_second).min(self.capacity);
+        self.updated_at = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - self.tokens) / self.per_second,
+            ))
+        }
+    }
+
+    /// Check if the bucket has refilled and isn't blocked, so it's no different to a new one
+    fn is_idle(&self, now: Instant) -> bool {
+        let refilled = self.tokens
+            + now.saturating_duration_since(self.updated_at).as_secs_f64() * self.per_second;
+        self.blocked_until.is_none_or(|until| until <= now) && refilled >= self.capacity
+    }
+
+    /// Stop handing out tokens until the given time, and start from empty after that
+    fn block_until(&mut self, until: Instant) {
+        self.blocked_until = Some(
+            self.blocked_until
+                .map_or(until, |current| current.max(until)),
+        );
+        self.tokens = 0.0;
+        self.updated_at = until;
+    }
+}
+
+/// A token bucket per Slack API method and token, so that we slow down before Slack makes us
+///
+/// Slack's limits are per workspace, so each token gets its own buckets and a busy workspace
+/// doesn't hold up the others.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    buckets: Mutex<HashMap<(String, String), TokenBucket>>,
+}
+
+impl RateLimiter {
+    fn with_bucket<T>(
+        &self,
+        token: &str,
+        path: &str,
+        now: Instant,
+        f: impl FnOnce(&mut TokenBucket) -> T,
+    ) -> T {
+        let mut buckets = self.buckets.lock().unwrap();
+        let key = (token.to_string(), path.to_string());
+        if !buckets.contains_key(&key) {
+            // Forget the buckets of workspaces that have gone quiet, so they don't pile up
+            buckets.retain(|_, bucket| !bucket.is_idle(now));
+        }
+        let bucket = buckets.entry(key).or_insert_with(|| {
+            let (capacity, per_second) = get_method_limit(path);
+            TokenBucket::new(capacity, per_second, now)
+        });
+
+        f(bucket)
+    }
+
+    /// Take a token for the method, or get how long to wait until there's one to take
+    fn try_acquire(&self, token: &str, path: &str, now: Instant) -> Result<(), Duration> {
+        self.with_bucket(token, path, now, |bucket| bucket.try_take(now))
+    }
+
+    /// Wait until the method can be called with the token
+    ///
+    /// Errors with `RateLimited` rather than waiting longer than `max_wait`, so that the caller
+    /// can try again later instead.
+    pub async fn acquire(
+        &self,
+        token: &str,
+        path: &str,
+        max_wait: Duration,
+    ) -> Result<(), SimilariumError> {
+        let deadline = Instant::now() + max_wait;
+        loop {
+            let now = Instant::now();
+            match self.try_acquire(token, path, now) {
+                Ok(()) => return Ok(()),
+                Err(wait) if now + wait > deadline => {
+                    return rate_limited_error!("Holding back calls to {} for {:?}", path, wait);
+                }
+                Err(wait) => {
+                    log::debug!("Waiting {:?} before calling {}", wait, path);
+                    tokio::time::sleep(wait).await;
+                }
+            }
+        }
+    }
+
+    /// Hold back calls to the method with the token after Slack has told us to slow down
+    pub fn block(&self, token: &str, path: &str, retry_after: Duration) {
+        let now = Instant::now();
+        self.with_bucket(token, path, now, |bucket| {
+            bucket.block_until(now + retry_after)
+        });
+    }
+}
+
+static SHARED_RATE_LIMITER: OnceLock<Arc<RateLimiter>> = OnceLock::new();
+
+/// The rate limiter shared by every `SlackClient` in the process
+pub fn get_shared_rate_limiter() -> Arc<RateLimiter> {
+    SHARED_RATE_LIMITER
+        .get_or_init(|| Arc::new(RateLimiter::default()))
+        .clone()
+}
+
+/// How many times to try a Slack API call, and how long to wait in between
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    /// The longest we'll wait before retrying, if Slack asks for longer we give up instead
+    pub max_delay: Duration,
+    /// The longest a call can take with all of its attempts, after which we give up
+    pub max_total_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(5),
+            max_total_delay: Duration::from_secs(15),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy for calls made while handling an interaction, which Slack has to be answered
+    /// within 3 seconds
+    pub fn interactive() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(1),
+            max_total_delay: Duration::from_secs(2),
+        }
+    }
+
+    /// Get how long to wait after a failed attempt, doubling with each attempt
+    ///
+    /// The delay is jittered between half and all of the doubled delay, so that calls that failed
+    /// together don't all retry together.
+    pub fn get_backoff(&self, attempt: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2_u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_delay);
+        let jitter = rand::thread_rng().gen_range(0.5..=1.0);
+
+        delay.mul_f64(jitter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SimilariumErrorType;
+
+    #[test]
+    fn test_token_bucket_allows_a_burst_then_waits_for_refill() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(2.0, 1.0, now);
+
+        assert!(bucket.try_take(now).is_ok());
+        assert!(bucket.try_take(now).is_ok());
+        assert_eq!(bucket.try_take(now), Err(Duration::from_secs(1)));
+
+        assert!(bucket.try_take(now + Duration::from_millis(500)).is_err());
+        assert!(bucket.try_take(now + Duration::from_secs(1)).is_ok());
+    }
+
+    #[test]
+    fn test_token_bucket_refills_up_to_capacity() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(2.0, 1.0, now);
+        bucket.try_take(now).unwrap();
+        bucket.try_take(now).unwrap();
+
+        let later = now + Duration::from_secs(60);
+        assert!(bucket.try_take(later).is_ok());
+        assert!(bucket.try_take(later).is_ok());
+        assert!(bucket.try_take(later).is_err());
+    }
+
+    #[test]
+    fn test_token_bucket_waits_while_blocked() {
+        let now = Instant::now();
+        let mut bucket = TokenBucket::new(5.0, 1.0, now);
+        bucket.block_until(now + Duration::from_secs(30));
+
+        assert_eq!(
+            bucket.try_take(now + Duration::from_secs(10)),
+            Err(Duration::from_secs(20))
+        );
+        assert!(bucket.try_take(now + Duration::from_secs(30)).is_err());
+        assert!(bucket.try_take(now + Duration::from_secs(31)).is_ok());
+    }
+
+    #[test]
+    fn test_rate_limiter_keeps_a_bucket_per_method_and_token() {
+        let limiter = RateLimiter::default();
+        let now = Instant::now();
+
+        for _ in 0..5 {
+            assert!(limiter.try_acquire("token_a", "/chat.update", now).is_ok());
+        }
+        assert!(limiter.try_acquire("token_a", "/chat.update", now).is_err());
+        assert!(limiter
+            .try_acquire("token_a", "/chat.postEphemeral", now)
+            .is_ok());
+        assert!(limiter.try_acquire("token_b", "/chat.update", now).is_ok());
+    }
+
+    #[test]
+    fn test_rate_limiter_forgets_idle_buckets() {
+        let limiter = RateLimiter::default();
+        let now = Instant::now();
+        limiter.try_acquire("token_a", "/chat.update", now).unwrap();
+        limiter.try_acquire("token_b", "/chat.update", now).unwrap();
+
+        let later = now + Duration::from_secs(60);
+        limiter
+            .try_acquire("token_c", "/chat.update", later)
+            .unwrap();
+        assert_eq!(limiter.buckets.lock().unwrap().len(), 1);
+    }
+
+    #[actix_web::test]
+    async fn test_rate_limiter_acquire_gives_up_instead_of_waiting_too_long() {
+        let limiter = RateLimiter::default();
+        limiter.block("token_a", "/chat.update", Duration::from_secs(30));
+
+        let err = limiter
+            .acquire("token_a", "/chat.update", Duration::from_secs(5))
+            .await
+            .unwrap_err();
+        assert_eq!(err.error_type, SimilariumErrorType::RateLimited);
+        assert!(limiter
+            .acquire("token_b", "/chat.update", Duration::from_secs(5))
+            .await
+            .is_ok());
+    }
+
+    #[test]
+    fn test_retry_policy_backoff_doubles_with_jitter_up_to_max_delay() {
+        let policy = RetryPolicy {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(300),
+            ..Default::default()
+        };
+
+        for _ in 0..20 {
+            let first = policy.get_backoff(1);
+            assert!(first >= Duration::from_millis(50) && first <= Duration::from_millis(100));
+
+            let second = policy.get_backoff(2);
+            assert!(second >= Duration::from_millis(100) && second <= Duration::from_millis(200));
+
+            let capped = policy.get_backoff(5);
+            assert!(capped >= Duration::from_millis(150) && capped <= Duration::from_millis(300));
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::payloads::{Team, UserInfo};
+use crate::payloads::{Enterprise, Team, UserInfo};
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
@@ -6,10 +6,16 @@ pub struct SlackOAuthResponse {
     pub ok: bool,
     pub error: Option<String>,
     pub access_token: Option<String>,
+    /// Only set when token rotation is turned on for the app
+    pub refresh_token: Option<String>,
+    /// How many seconds until the access token expires, when token rotation is turned on
+    pub expires_in: Option<i64>,
     pub scope: Option<String>,
     pub bot_user_id: Option<String>,
     pub app_id: String,
-    pub team: Team,
+    /// The team the app was installed on, which isn't set for org-wide installs
+    pub team: Option<Team>,
+    pub enterprise: Option<Enterprise>,
     pub is_enterprise_install: bool,
 }
 
@@ -1,7 +1,7 @@
 use crate::{
     slack_client::{
         responses::{SlackOAuthResponse, UserInfoResponse},
-        Block,
+        Block, View,
     },
     SimilariumError,
 };
@@ -42,6 +42,14 @@ pub trait SlackOAuth {
         client_id: &str,
         client_secret: &str,
     ) -> impl Future<Output = Result<SlackOAuthResponse, SimilariumError>>;
+
+    /// Exchange a refresh token for a new access token, when token rotation is turned on
+    fn refresh_token(
+        &self,
+        refresh_token: &str,
+        client_id: &str,
+        client_secret: &str,
+    ) -> impl Future<Output = Result<SlackOAuthResponse, SimilariumError>>;
 }
 
 pub trait SlackUserDetails {
@@ -51,3 +59,12 @@ pub trait SlackUserDetails {
         token: &str,
     ) -> impl Future<Output = Result<UserInfoResponse, SimilariumError>>;
 }
+
+pub trait SlackViews {
+    fn publish_view(
+        &self,
+        user_id: &str,
+        view: View,
+        token: &str,
+    ) -> impl Future<Output = Result<serde_json::Value, SimilariumError>>;
+}
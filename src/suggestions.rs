@@ -0,0 +1,244 @@
+use crate::{db::get_pool, models::Word2Vec, SimilariumError};
+use std::sync::Arc;
+use tokio::sync::OnceCell;
+
+/// The most edits that a suggestion can be away from the guess
+const MAX_DISTANCE: usize = 2;
+
+/// Guesses up to this long only get suggestions a single edit away, since two edits can turn a
+/// short word into almost anything
+const SHORT_WORD_LENGTH: usize = 4;
+
+/// How many suggestions are offered for a guess
+const SUGGESTION_COUNT: usize = 3;
+
+/// Get the Levenshtein distance between two words, the number of letters that have to be
+/// inserted, deleted or substituted to turn one into the other
+pub fn get_edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+#[derive(Debug)]
+struct Node {
+    word: String,
+    /// The index of each child node, keyed by its distance to this word
+    children: Vec<(usize, usize)>,
+}
+
+/// A BK-tree over the vocabulary, to suggest words for guesses that aren't in it
+///
+/// Each node's children are keyed by their edit distance to it, so by the triangle inequality a
+/// search only has to follow the children whose key is within the max distance of the guess's
+/// distance to the node.
+#[derive(Debug, Default)]
+pub struct SuggestionIndex {
+    nodes: Vec<Node>,
+}
+
+impl SuggestionIndex {
+    pub fn new(words: impl IntoIterator<Item = String>) -> Self {
+        let mut index = SuggestionIndex::default();
+        for word in words {
+            index.insert(word);
+        }
+        index
+    }
+
+    /// Load every word in the `word2vec` table
+    pub async fn load_from_db(db: &sqlx::PgPool) -> Result<Self, SimilariumError> {
+        log::info!("Building the suggestion index from the database");
+        let index = SuggestionIndex::new(Word2Vec::get_all_words(db).await?);
+        log::info!("Indexed {} words for suggestions", index.len());
+
+        Ok(index)
+    }
+
+    /// Add a word to the index, unless it's already there
+    pub fn insert(&mut self, word: String) {
+        let mut idx = 0;
+        while let Some(node) = self.nodes.get(idx) {
+            let distance = get_edit_distance(&word, &node.word);
+            if distance == 0 {
+                return;
+            }
+            match node.children.iter().find(|(key, _)| *key == distance) {
+                Some((_, child)) => idx = *child,
+                None => {
+                    let child = self.nodes.len();
+                    self.nodes[idx].children.push((distance, child));
+                    break;
+                }
+            }
+        }
+
+        self.nodes.push(Node {
+            word,
+            children: vec![],
+        });
+    }
+
+    /// Find the words within the max distance of a word, along with their distances
+    pub fn find(&self, word: &str, max_distance: usize) -> Vec<(usize, &str)> {
+        let mut found = vec![];
+        let mut stack = match self.nodes.is_empty() {
+            true => vec![],
+            false => vec![0],
+        };
+
+        while let Some(idx) = stack.pop() {
+            let node = &self.nodes[idx];
+            let distance = get_edit_distance(word, &node.word);
+            if distance <= max_distance {
+                found.push((distance, node.word.as_str()));
+            }
+            stack.extend(
+                node.children
+                    .iter()
+                    .filter(|(key, _)| key.abs_diff(distance) <= max_distance)
+                    .map(|(_, child)| *child),
+            );
+        }
+
+        found
+    }
+
+    /// Suggest the closest words to a guess that isn't in the vocabulary, closest first
+    ///
+    /// Ties prefer words that start with the same letter, as typos are rarely in the first one.
+    pub fn suggest(&self, guess: &str) -> Vec<String> {
+        let max_distance = match guess.chars().count() {
+            length if length <= SHORT_WORD_LENGTH => 1,
+            _ => MAX_DISTANCE,
+        };
+        let first = guess.chars().next();
+
+        let mut found = self
+            .find(guess, max_distance)
+            .into_iter()
+            .filter(|(distance, _)| *distance > 0)
+            .collect::<Vec<_>>();
+        found.sort_by_key(|(distance, word)| (*distance, word.chars().next() != first, *word));
+
+        found
+            .into_iter()
+            .take(SUGGESTION_COUNT)
+            .map(|(_, word)| word.to_string())
+            .collect()
+    }
+
+    /// How many words are in the index
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+static SUGGESTION_INDEX: OnceCell<Arc<SuggestionIndex>> = OnceCell::const_new();
+
+/// Get the index of the vocabulary for suggesting words
+///
+/// The index is built from the `word2vec` table the first time this is called, so it should be
+/// called at startup rather than on the first unknown guess.
+pub async fn get_suggestion_index() -> Result<Arc<SuggestionIndex>, SimilariumError> {
+    SUGGESTION_INDEX
+        .get_or_try_init(|| async {
+            Ok(Arc::new(SuggestionIndex::load_from_db(get_pool()).await?))
+        })
+        .await
+        .cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_index(words: &[&str]) -> SuggestionIndex {
+        SuggestionIndex::new(words.iter().map(|word| word.to_string()))
+    }
+
+    #[test]
+    fn test_get_edit_distance() {
+        assert_eq!(get_edit_distance("apple", "apple"), 0);
+        assert_eq!(get_edit_distance("aple", "apple"), 1);
+        assert_eq!(get_edit_distance("appel", "apple"), 2);
+        assert_eq!(get_edit_distance("kitten", "sitting"), 3);
+        assert_eq!(get_edit_distance("", "pear"), 4);
+        assert_eq!(get_edit_distance("café", "cafe"), 1);
+    }
+
+    #[test]
+    fn test_find_matches_a_linear_search() {
+        let words = [
+            "apple", "apply", "ample", "maple", "pear", "peer", "bear", "orange", "happy", "sappy",
+            "fruit", "future",
+        ];
+        let index = get_index(&words);
+        assert_eq!(index.len(), words.len());
+
+        for guess in ["aple", "pearr", "hapy", "frute", "xyz"] {
+            for max_distance in 0..=3 {
+                let mut found = index.find(guess, max_distance);
+                found.sort();
+                let mut expected = words
+                    .iter()
+                    .map(|word| (get_edit_distance(guess, word), *word))
+                    .filter(|(distance, _)| *distance <= max_distance)
+                    .collect::<Vec<_>>();
+                expected.sort();
+
+                assert_eq!(found, expected, "{guess} {max_distance}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_insert_skips_duplicates() {
+        let index = get_index(&["pear", "pear", "bear"]);
+
+        assert_eq!(index.len(), 2);
+    }
+
+    #[test]
+    fn test_suggest_orders_closest_first() {
+        let index = get_index(&["apple", "apply", "maple", "ample", "orange"]);
+
+        assert_eq!(index.suggest("appple"), vec!["apple", "ample", "apply"]);
+        assert_eq!(index.suggest("appl"), vec!["apple", "apply"]);
+        // Ties prefer the same first letter, then the alphabet
+        assert_eq!(index.suggest("aple"), vec!["ample", "apple", "maple"]);
+        assert_eq!(index.suggest("xyzzy"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_suggest_is_stricter_for_short_words() {
+        let index = get_index(&["pear", "bean"]);
+
+        assert_eq!(index.suggest("pea"), vec!["pear"]);
+        assert_eq!(index.suggest("bea"), vec!["bean"]);
+        assert_eq!(index.suggest("pan"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_suggest_on_an_empty_index() {
+        assert_eq!(
+            SuggestionIndex::default().suggest("pear"),
+            Vec::<String>::new()
+        );
+    }
+}
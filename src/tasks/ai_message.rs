@@ -0,0 +1,324 @@
+use crate::{
+    ai::{get_deny_list, get_llm_engine, get_message_or_fallback, MessageFilter, Prompt},
+    db::get_pool,
+    models::{Channel, Game, Persona},
+    slack_client::{SlackClient, SlackMessage},
+};
+use fang::{
+    async_trait,
+    asynk::async_queue::AsyncQueueable,
+    serde::{Deserialize, Serialize},
+    typetag, AsyncRunnable, FangError,
+};
+use uuid::Uuid;
+
+/// How many times the model is asked for a message before falling back to a template
+const LLM_ATTEMPTS: u32 = 2;
+
+/// A message for the model to write, with the state of the game at the time of the guess
+///
+/// The top guesses are `(rank, word)` pairs, closest first.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "fang::serde")]
+pub enum AiMessage {
+    /// A clue about the secret for a user that asked for a hint, which only they see
+    Hint {
+        guess_count: i64,
+        user_id: String,
+        top_guesses: Vec<(i64, String)>,
+        closest_words: Vec<String>,
+    },
+    Celebration {
+        guess_count: i64,
+        user_id: String,
+        word: String,
+        word_rank: i64,
+        top_guesses: Vec<(i64, String)>,
+        bucket: i64,
+    },
+    Taunt {
+        guess_count: i64,
+        top_word: String,
+        top_word_rank: i64,
+        participant_user_ids: Vec<String>,
+        top_guesses: Vec<(i64, String)>,
+    },
+    Win {
+        guess_count: i64,
+        user_id: String,
+        top_guesses: Vec<(i64, String)>,
+    },
+}
+
+fn borrow_guesses(top_guesses: &[(i64, String)]) -> Vec<(i64, &str)> {
+    top_guesses
+        .iter()
+        .map(|(rank, word)| (*rank, word.as_str()))
+        .collect()
+}
+
+impl AiMessage {
+    /// Get the user that the message is about, if it's about one
+    pub fn get_user_id(&self) -> Option<&str> {
+        match self {
+            AiMessage::Hint { user_id, .. }
+            | AiMessage::Celebration { user_id, .. }
+            | AiMessage::Win { user_id, .. } => Some(user_id),
+            AiMessage::Taunt { .. } => None,
+        }
+    }
+
+    /// Get the prompt for the message about a game, written in the persona
+    pub fn get_prompt<'a>(&'a self, persona: &'a Persona, game: &'a Game) -> Prompt<'a> {
+        match self {
+            AiMessage::Hint {
+                guess_count,
+                top_guesses,
+                closest_words,
+                ..
+            } => {
+                let (top_word, top_rank) = top_guesses
+                    .first()
+                    .map_or(("nothing", 0), |(rank, word)| (word.as_str(), *rank));
+                Prompt::Hint {
+                    guess_count: *guess_count,
+                    top_word,
+                    top_rank,
+                    top_guesses: borrow_guesses(top_guesses),
+                    secret: &game.secret,
+                    closest_words: closest_words.iter().map(String::as_str).collect(),
+                }
+            }
+            AiMessage::Celebration {
+                guess_count,
+                user_id,
+                word,
+                word_rank,
+                top_guesses,
+                bucket,
+            } => Prompt::Celebration {
+                template: &persona.celebration_prompt,
+                guess_count: *guess_count,
+                user_id,
+                word,
+                word_rank: *word_rank,
+                top_guesses: borrow_guesses(top_guesses),
+                bucket: *bucket,
+            },
+            AiMessage::Taunt {
+                guess_count,
+                top_word,
+                top_word_rank,
+                participant_user_ids,
+                top_guesses,
+            } => Prompt::Taunt {
+                template: &persona.taunt_prompt,
+                guess_count: *guess_count,
+                top_word,
+                top_word_rank: *top_word_rank,
+                participant_user_ids: participant_user_ids.clone(),
+                top_guesses: borrow_guesses(top_guesses),
+            },
+            AiMessage::Win {
+                guess_count,
+                user_id,
+                top_guesses,
+            } => Prompt::Win {
+                template: &persona.win_prompt,
+                guess_count: *guess_count,
+                user_id,
+                top_guesses: borrow_guesses(top_guesses),
+            },
+        }
+    }
+}
+
+/// Post a message written by the model on the channel of a game, such as celebrating a guess
+///
+/// This runs in the background since the model can take longer to respond than Slack waits for
+/// an interaction. The message must not give away the secret or mention anyone that isn't
+/// playing, and if the model keeps failing at that a message from a template is posted instead,
+/// as long as that's safe too. Clues for hints are only posted to the user that asked for the
+/// hint.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "fang::serde")]
+pub struct AiMessageTask {
+    pub game_id: Uuid,
+    pub message: AiMessage,
+}
+
+#[typetag::serde]
+#[async_trait]
+impl AsyncRunnable for AiMessageTask {
+    async fn run(&self, _queue: &mut dyn AsyncQueueable) -> Result<(), FangError> {
+        log::debug!("Running AiMessageTask for game {}", self.game_id);
+        let pool = get_pool();
+
+        let Some(game) = Game::get_by_id(self.game_id, pool).await? else {
+            log::warn!("Game {} not found", self.game_id);
+            return Ok(());
+        };
+        let Some(channel) = Channel::get(&game.channel_id, pool).await? else {
+            log::warn!("Channel {} not found", game.channel_id);
+            return Ok(());
+        };
+        // The channel may have turned the AI messages off since the task was queued
+        if !channel.ai_enabled {
+            log::debug!("AI messages are off for channel {}", channel.id);
+            return Ok(());
+        }
+        let token = channel.get_slack_bot_token(pool).await?;
+        let persona = channel.get_persona(pool).await?;
+
+        let participant_user_ids = game.get_participant_user_ids(pool).await?;
+        let filter = MessageFilter {
+            secret: &game.secret,
+            allowed_user_ids: participant_user_ids
+                .iter()
+                .map(String::as_str)
+                .chain(self.message.get_user_id())
+                .collect(),
+            deny_list: get_deny_list()?,
+        };
+
+        let llm = get_llm_engine()?;
+        let prompt = self.message.get_prompt(&persona, &game);
+        let Some(message) = get_message_or_fallback(llm, &prompt, &filter, LLM_ATTEMPTS).await
+        else {
+            log::warn!("No safe message to post for game {}", game.id);
+            return Ok(());
+        };
+        log::debug!("AI message: {}", message.message);
+
+        let slack_client = SlackClient::default();
+        match &self.message {
+            AiMessage::Hint { user_id, .. } => {
+                let clue = format!("_{}_", message.message);
+                slack_client
+                    .post_ephemeral(&clue, &channel.id, user_id, &token, None)
+                    .await?;
+            }
+            _ => {
+                slack_client
+                    .post_message(&message.message, &channel.id, &token, None)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn max_retries(&self) -> i32 {
+        3
+    }
+
+    fn backoff(&self, attempt: u32) -> u32 {
+        u32::pow(2, attempt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ai_message_task_roundtrips_through_json() {
+        let task = AiMessageTask {
+            game_id: Uuid::parse_str("00000001-0000-4000-a000-000000000000").unwrap(),
+            message: AiMessage::Win {
+                guess_count: 42,
+                user_id: "U123".to_string(),
+                top_guesses: vec![(0, "apple".to_string()), (3, "pear".to_string())],
+            },
+        };
+
+        let json = serde_json::to_string(&task).unwrap();
+        let parsed: AiMessageTask = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed.game_id, task.game_id);
+        assert_eq!(parsed.message, task.message);
+    }
+
+    fn get_game() -> Game {
+        Game {
+            id: Uuid::parse_str("00000001-0000-4000-a000-000000000000").unwrap(),
+            channel_id: "C123".to_string(),
+            thread_ts: None,
+            puzzle_number: 1,
+            date: chrono::Utc::now(),
+            active: true,
+            secret: "apple".to_string(),
+            hint: None,
+            taunt_index: 0,
+        }
+    }
+
+    fn get_persona() -> Persona {
+        Persona {
+            name: "terse".to_string(),
+            description: "".to_string(),
+            celebration_prompt: "Celebrate {user}".to_string(),
+            taunt_prompt: "Taunt".to_string(),
+            win_prompt: "Win".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_get_prompt_uses_the_persona() {
+        let persona = get_persona();
+        let game = get_game();
+        let message = AiMessage::Celebration {
+            guess_count: 10,
+            user_id: "U123".to_string(),
+            word: "pear".to_string(),
+            word_rank: 8,
+            top_guesses: vec![(8, "pear".to_string())],
+            bucket: 10,
+        };
+
+        let Prompt::Celebration {
+            template,
+            user_id,
+            word,
+            top_guesses,
+            bucket,
+            ..
+        } = message.get_prompt(&persona, &game)
+        else {
+            panic!("Expected a celebration prompt");
+        };
+        assert_eq!(template, "Celebrate {user}");
+        assert_eq!(user_id, "U123");
+        assert_eq!(word, "pear");
+        assert_eq!(top_guesses, vec![(8, "pear")]);
+        assert_eq!(bucket, 10);
+    }
+
+    #[test]
+    fn test_get_prompt_for_a_hint_uses_the_game() {
+        let persona = get_persona();
+        let game = get_game();
+        let message = AiMessage::Hint {
+            guess_count: 12,
+            user_id: "U123".to_string(),
+            top_guesses: vec![(40, "pear".to_string()), (900, "car".to_string())],
+            closest_words: vec!["fruit".to_string(), "orchard".to_string()],
+        };
+
+        let Prompt::Hint {
+            top_word,
+            top_rank,
+            secret,
+            closest_words,
+            ..
+        } = message.get_prompt(&persona, &game)
+        else {
+            panic!("Expected a hint prompt");
+        };
+        assert_eq!(top_word, "pear");
+        assert_eq!(top_rank, 40);
+        assert_eq!(secret, "apple");
+        assert_eq!(closest_words, vec!["fruit", "orchard"]);
+        assert_eq!(message.get_user_id(), Some("U123"));
+    }
+}
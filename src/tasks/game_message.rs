@@ -0,0 +1,67 @@
+use crate::{
+    db::get_pool,
+    game::utils::get_game_blocks,
+    models::{Channel, Game},
+    slack_client::{SlackClient, SlackMessage},
+};
+use fang::{
+    async_trait,
+    asynk::async_queue::AsyncQueueable,
+    serde::{Deserialize, Serialize},
+    typetag, AsyncRunnable, FangError,
+};
+
+/// Update a game message with the latest board, when it couldn't be updated straight away
+///
+/// The blocks are generated when the task runs rather than when it's queued, so the message always
+/// ends up showing the latest guesses.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "fang::serde")]
+pub struct UpdateGameMessageTask {
+    pub channel_id: String,
+    pub thread_ts: String,
+}
+
+#[typetag::serde]
+#[async_trait]
+impl AsyncRunnable for UpdateGameMessageTask {
+    async fn run(&self, _queue: &mut dyn AsyncQueueable) -> Result<(), FangError> {
+        log::debug!(
+            "Running UpdateGameMessageTask for {} {}",
+            self.channel_id,
+            self.thread_ts
+        );
+        let pool = get_pool();
+
+        let Some(game) = Game::get(&self.channel_id, &self.thread_ts, pool).await? else {
+            log::warn!("Game {} {} not found", self.channel_id, self.thread_ts);
+            return Ok(());
+        };
+        let Some(channel) = Channel::get(&game.channel_id, pool).await? else {
+            log::warn!("Channel {} not found", game.channel_id);
+            return Ok(());
+        };
+        let token = channel.get_slack_bot_token(pool).await?;
+        let blocks = get_game_blocks(&game, pool).await?;
+
+        SlackClient::default()
+            .chat_update(
+                "Update to today's game",
+                &self.channel_id,
+                &self.thread_ts,
+                &token,
+                Some(blocks),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    fn uniq(&self) -> bool {
+        true
+    }
+
+    fn backoff(&self, attempt: u32) -> u32 {
+        u32::pow(2, attempt)
+    }
+}
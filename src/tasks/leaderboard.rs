@@ -0,0 +1,54 @@
+use crate::{
+    db::get_pool,
+    game::leaderboard::{post_leaderboards, LeaderboardPeriod},
+    slack_client::SlackClient,
+};
+use fang::{
+    async_trait,
+    asynk::async_queue::AsyncQueueable,
+    serde::{Deserialize, Serialize},
+    typetag, AsyncRunnable, FangError, Scheduled,
+};
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "fang::serde")]
+pub struct LeaderboardTask {
+    pub period: LeaderboardPeriod,
+}
+
+#[typetag::serde]
+#[async_trait]
+impl AsyncRunnable for LeaderboardTask {
+    async fn run(&self, _queue: &mut dyn AsyncQueueable) -> Result<(), FangError> {
+        log::debug!("Running LeaderboardTask for {:?}", self.period);
+        let pool = get_pool();
+
+        post_leaderboards(
+            pool,
+            &SlackClient::default(),
+            self.period,
+            chrono::Utc::now(),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    fn uniq(&self) -> bool {
+        true
+    }
+
+    fn cron(&self) -> Option<Scheduled> {
+        // The weekly leaderboard goes out on Monday mornings, the monthly on the 1st
+        let expression = match self.period {
+            LeaderboardPeriod::Week => "0 0 9 * * Mon",
+            LeaderboardPeriod::Month => "0 0 9 1 * *",
+            LeaderboardPeriod::All => return None,
+        };
+        Some(Scheduled::CronPattern(expression.to_string()))
+    }
+
+    fn backoff(&self, attempt: u32) -> u32 {
+        u32::pow(2, attempt)
+    }
+}
@@ -1,10 +1,15 @@
 use crate::{
     db::get_pool,
-    game::{end_game, get_active_games_on_channel, start_game_on_channel},
-    models::{Channel, SlackBot},
+    game::{
+        end_game, get_active_games_on_channel, secrets::get_channel_secret, start_game_on_channel,
+    },
+    models::{Channel, Game},
+    similarity::{get_similarity_engine, SimilarityBackend},
     slack_client::SlackClient,
+    tasks::SecretSimilarityTask,
+    utils::uninstall_team,
+    SimilariumError, SimilariumErrorType,
 };
-use chrono::Timelike;
 use fang::{
     async_trait,
     asynk::async_queue::AsyncQueueable,
@@ -19,42 +24,42 @@ pub struct GameTask;
 #[typetag::serde]
 #[async_trait]
 impl AsyncRunnable for GameTask {
-    async fn run(&self, _queue: &mut dyn AsyncQueueable) -> Result<(), FangError> {
+    async fn run(&self, queue: &mut dyn AsyncQueueable) -> Result<(), FangError> {
         log::debug!("Running GameTask");
         let pool = get_pool();
-        let now = chrono::Utc::now();
-
-        let channels = Channel::get_channels_for_hour_minute(
-            now.time().hour() as i32,
-            now.time().minute() as i32,
-            pool,
-        )
-        .await?;
+        let channels = Channel::get_channels_for_hour_minute(chrono::Utc::now(), pool).await?;
         if channels.is_empty() {
             return Ok(());
         }
 
         let slack_client = SlackClient::default();
+        let similarity = get_similarity_engine().await?;
 
         // TODO: Shift each of these into a separate task? Should be better for error handling as
         // well and not blocking this task that runs every minute
         for channel in channels {
-            // Check if there are any active games on the channel, and end them
-            let token = SlackBot::get_slack_bot_token(&channel.team_id, pool).await?;
-
-            let active_games = get_active_games_on_channel(pool, &channel.id).await?;
-            let mut should_start_game = true;
-
-            for mut game in active_games {
-                if game.get_guess_count(pool).await? == 0 {
-                    log::info!("Game with no guesses, not starting a new one");
-                    should_start_game = false;
-                    continue;
+            // One channel failing shouldn't stop the games on the others
+            match run_channel(pool, similarity, &slack_client, queue, &channel).await {
+                Ok(()) => {}
+                Err(SimilariumError {
+                    error_type: SimilariumErrorType::TokenRevoked,
+                    ..
+                }) => {
+                    // The team uninstalled the app without us hearing about it, so stop its games
+                    // rather than failing every run
+                    log::warn!(
+                        "Skipping channel {} as team {} has revoked its token",
+                        channel.id,
+                        channel.team_id
+                    );
+                    if let Err(e) =
+                        uninstall_team(channel.enterprise_id.as_deref(), &channel.team_id, pool)
+                            .await
+                    {
+                        log::error!("Unable to uninstall team {}: {:?}", channel.team_id, e);
+                    }
                 }
-                end_game(pool, &slack_client, &mut game, &token).await?;
-            }
-            if should_start_game {
-                start_game_on_channel(pool, &slack_client, &channel.id, &token).await?;
+                Err(e) => log::error!("Unable to run the games on channel {}: {:?}", channel.id, e),
             }
         }
 
@@ -74,3 +79,41 @@ impl AsyncRunnable for GameTask {
         u32::pow(2, attempt)
     }
 }
+
+/// End the active games on a channel and start the next one
+async fn run_channel(
+    pool: &sqlx::PgPool,
+    similarity: &impl SimilarityBackend,
+    slack_client: &SlackClient,
+    queue: &mut dyn AsyncQueueable,
+    channel: &Channel,
+) -> Result<(), SimilariumError> {
+    // Check if there are any active games on the channel, and end them
+    let token = channel.get_slack_bot_token(pool).await?;
+
+    let active_games = get_active_games_on_channel(pool, &channel.id).await?;
+    let mut should_start_game = true;
+
+    for mut game in active_games {
+        if game.get_guess_count(pool).await? == 0 {
+            log::info!("Game with no guesses, not starting a new one");
+            should_start_game = false;
+            continue;
+        }
+        end_game(pool, slack_client, queue, &mut game, &token).await?;
+    }
+    if should_start_game {
+        start_game_on_channel(pool, similarity, slack_client, &channel.id, &token).await?;
+
+        // Get the similarities for the next game ready in the background
+        let next_puzzle_number = Game::get_next_puzzle_number(channel.id.clone(), pool).await;
+        let similarity_task = SecretSimilarityTask {
+            secret: get_channel_secret(channel, next_puzzle_number, pool).await?,
+        };
+        queue
+            .insert_task(&similarity_task as &dyn AsyncRunnable)
+            .await?;
+    }
+
+    Ok(())
+}
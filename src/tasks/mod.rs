@@ -1,5 +1,11 @@
+mod ai_message;
+mod game_message;
+mod leaderboard;
 mod minutely;
-mod nightly;
+mod similarity;
 
+pub use ai_message::{AiMessage, AiMessageTask};
+pub use game_message::UpdateGameMessageTask;
+pub use leaderboard::LeaderboardTask;
 pub use minutely::GameTask;
-pub use nightly::MatViewCleanupTask;
+pub use similarity::{PruneSimilaritiesTask, SecretSimilarityTask};
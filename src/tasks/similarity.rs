@@ -0,0 +1,79 @@
+use crate::{
+    db::get_pool,
+    game::secrets::get_upcoming_secrets,
+    models::Word2Vec,
+    similarity::{get_similarity_engine, PostgresBackend},
+};
+use fang::{
+    async_trait,
+    asynk::async_queue::AsyncQueueable,
+    serde::{Deserialize, Serialize},
+    typetag, AsyncRunnable, FangError, Scheduled,
+};
+
+/// Compute the similarities for a secret ahead of the game that uses it
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "fang::serde")]
+pub struct SecretSimilarityTask {
+    pub secret: String,
+}
+
+#[typetag::serde]
+#[async_trait]
+impl AsyncRunnable for SecretSimilarityTask {
+    async fn run(&self, _queue: &mut dyn AsyncQueueable) -> Result<(), FangError> {
+        log::debug!("Running SecretSimilarityTask for {}", self.secret);
+        let similarity = get_similarity_engine().await?;
+
+        let secret = Word2Vec {
+            word: self.secret.clone(),
+        };
+        secret.compute_similarities(similarity).await?;
+
+        Ok(())
+    }
+
+    fn uniq(&self) -> bool {
+        true
+    }
+
+    fn backoff(&self, attempt: u32) -> u32 {
+        u32::pow(2, attempt)
+    }
+}
+
+/// Delete the similarities of secrets that no active game uses, so the table doesn't keep growing
+///
+/// The similarities computed ahead of each channel's next game are kept.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "fang::serde")]
+pub struct PruneSimilaritiesTask;
+
+#[typetag::serde]
+#[async_trait]
+impl AsyncRunnable for PruneSimilaritiesTask {
+    async fn run(&self, _queue: &mut dyn AsyncQueueable) -> Result<(), FangError> {
+        log::debug!("Running PruneSimilaritiesTask");
+        let pool = get_pool();
+        let upcoming_secrets = get_upcoming_secrets(pool).await?;
+        let deleted = PostgresBackend::new(pool.clone())
+            .prune_similarities(&upcoming_secrets)
+            .await?;
+        log::info!("Deleted similarities for {} unused secrets", deleted);
+
+        Ok(())
+    }
+
+    fn uniq(&self) -> bool {
+        true
+    }
+
+    fn cron(&self) -> Option<Scheduled> {
+        let expression = "0 0 3 * * *";
+        Some(Scheduled::CronPattern(expression.to_string()))
+    }
+
+    fn backoff(&self, attempt: u32) -> u32 {
+        u32::pow(2, attempt)
+    }
+}
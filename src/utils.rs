@@ -1,13 +1,18 @@
 use crate::{
-    models::{SlackBot, User},
+    models::{Channel, SlackBot, User},
     slack_client::{responses::UserInfoResponse, SlackUserDetails},
     SimilariumError,
 };
 use chrono::{NaiveTime, Timelike};
+use chrono_tz::Tz;
 
+/// Get the user, fetching their details from Slack if they haven't been seen before
+///
+/// The token should be the one that serves the channel the user is playing on, which can see
+/// users from other workspaces that the channel is shared with on Enterprise Grid.
 pub async fn get_or_create_user(
     user_id: &str,
-    team_id: &str,
+    token: &str,
     db: &sqlx::PgPool,
     slack_client: &impl SlackUserDetails,
 ) -> Result<User, SimilariumError> {
@@ -17,8 +22,7 @@ pub async fn get_or_create_user(
             log::debug!("Creating user");
 
             // Fetch the user details from Slack
-            let token = SlackBot::get_slack_bot_token(team_id, db).await?;
-            let response = &slack_client.get_user_details(user_id, &token).await?;
+            let response = &slack_client.get_user_details(user_id, token).await?;
 
             let user_details = match response {
                 UserInfoResponse {
@@ -51,6 +55,36 @@ pub async fn get_or_create_user(
     Ok(user)
 }
 
+/// Stop using the install that serves a team, after the app has been uninstalled or its token
+/// revoked
+///
+/// The games on the channels it served are stopped, unless another install can still serve them,
+/// so they need to be started again if the app is reinstalled.
+pub async fn uninstall_team(
+    enterprise_id: Option<&str>,
+    team_id: &str,
+    db: &sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let Some(bot) = SlackBot::revoke(enterprise_id, team_id, db).await? else {
+        log::info!("No install to revoke for team {}", team_id);
+        return Ok(());
+    };
+
+    let deactivated = match (bot.is_enterprise_install, &bot.enterprise_id) {
+        (true, Some(enterprise_id)) => {
+            Channel::deactivate_for_enterprise(enterprise_id, db).await?
+        }
+        _ => Channel::deactivate_for_team(team_id, db).await?,
+    };
+    log::info!(
+        "Uninstalled from team {}, deactivated {} channels",
+        team_id,
+        deactivated
+    );
+
+    Ok(())
+}
+
 /// Convert a naive time to a human readable time
 pub fn when_human(time: NaiveTime) -> String {
     let when_fmt = time.format("%H:%M").to_string();
@@ -66,9 +100,9 @@ pub fn when_human(time: NaiveTime) -> String {
     }
 }
 
-/// Convert a NaiveTime and a timezone_offset into a UTC NaiveTime
-pub fn get_utc_naive_time(time: NaiveTime, timezone_offset: i32) -> NaiveTime {
-    time - chrono::Duration::seconds(timezone_offset as i64)
+/// Parse an IANA timezone name, such as the `tz` from Slack's user info, falling back to UTC
+pub fn get_timezone(name: &str) -> Tz {
+    name.parse().unwrap_or(Tz::UTC)
 }
 
 #[cfg(test)]
@@ -106,20 +140,18 @@ mod tests {
     }
 
     #[test]
-    fn test_get_utc_naive_time_without_offset() {
-        assert_eq!(get_utc_naive_time(time!(0, 0), 0), time!(0, 0));
-        assert_eq!(get_utc_naive_time(time!(11, 0), 0), time!(11, 0));
-    }
-
-    #[test]
-    fn test_get_utc_naive_time_with_positive_offset() {
-        assert_eq!(get_utc_naive_time(time!(0, 0), 3600), time!(23, 0));
-        assert_eq!(get_utc_naive_time(time!(0, 0), 1800), time!(23, 30));
+    fn test_get_timezone() {
+        assert_eq!(get_timezone("Europe/London"), Tz::Europe__London);
+        assert_eq!(
+            get_timezone("America/Los_Angeles"),
+            Tz::America__Los_Angeles
+        );
+        assert_eq!(get_timezone("UTC"), Tz::UTC);
     }
 
     #[test]
-    fn test_get_utc_naive_time_with_negative_offset() {
-        assert_eq!(get_utc_naive_time(time!(0, 0), -3600), time!(1, 0));
-        assert_eq!(get_utc_naive_time(time!(0, 0), -1800), time!(0, 30));
+    fn test_get_timezone_falls_back_to_utc() {
+        assert_eq!(get_timezone(""), Tz::UTC);
+        assert_eq!(get_timezone("Not/A_Timezone"), Tz::UTC);
     }
 }
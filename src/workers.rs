@@ -1,4 +1,6 @@
-use crate::{tasks, SimilariumError};
+use crate::{
+    db::get_pool, game::leaderboard::LeaderboardPeriod, models::Game, tasks, SimilariumError,
+};
 use fang::{
     asynk::{
         async_queue::{AsyncQueue, AsyncQueueable},
@@ -7,19 +9,28 @@ use fang::{
     NoTls,
 };
 
-pub async fn start_workers(
+/// Connect to the task queue, without starting any workers
+///
+/// This is enough to insert tasks into the queue, for example from the API
+pub async fn connect_queue(
     database_url: &str,
-    worker_count: u32,
     max_pool_size: u32,
 ) -> Result<AsyncQueue<NoTls>, SimilariumError> {
-    log::info!("Starting worker pool with {} workers", worker_count);
-
     let mut queue = AsyncQueue::builder()
         .uri(database_url)
         .max_pool_size(max_pool_size)
         .build();
     queue.connect(NoTls).await.unwrap();
 
+    Ok(queue)
+}
+
+pub async fn start_workers(
+    queue: &AsyncQueue<NoTls>,
+    worker_count: u32,
+) -> Result<(), SimilariumError> {
+    log::info!("Starting worker pool with {} workers", worker_count);
+
     let mut pool: AsyncWorkerPool<AsyncQueue<NoTls>> = AsyncWorkerPool::builder()
         .number_of_workers(worker_count)
         .queue(queue.clone())
@@ -27,7 +38,7 @@ pub async fn start_workers(
 
     pool.start().await;
 
-    Ok(queue)
+    Ok(())
 }
 
 pub async fn ensure_recurring_tasks(mut queue: AsyncQueue<NoTls>) -> Result<(), SimilariumError> {
@@ -38,10 +49,26 @@ pub async fn ensure_recurring_tasks(mut queue: AsyncQueue<NoTls>) -> Result<(),
         .schedule_task(&game_task as &dyn fang::AsyncRunnable)
         .await?;
 
-    log::info!("Scheduling MatViewCleanupTask to run nightly");
-    let cleanup_task = tasks::MatViewCleanupTask {};
+    // Games that were started before the similarities were stored in a table need them computed
+    for secret in Game::get_active_secrets(get_pool()).await? {
+        let similarity_task = tasks::SecretSimilarityTask { secret };
+        queue
+            .insert_task(&similarity_task as &dyn fang::AsyncRunnable)
+            .await?;
+    }
+
+    log::info!("Scheduling LeaderboardTask to run weekly and monthly");
+    for period in [LeaderboardPeriod::Week, LeaderboardPeriod::Month] {
+        let leaderboard_task = tasks::LeaderboardTask { period };
+        queue
+            .schedule_task(&leaderboard_task as &dyn fang::AsyncRunnable)
+            .await?;
+    }
+
+    log::info!("Scheduling PruneSimilaritiesTask to run daily");
+    let prune_task = tasks::PruneSimilaritiesTask;
     queue
-        .schedule_task(&cleanup_task as &dyn fang::AsyncRunnable)
+        .schedule_task(&prune_task as &dyn fang::AsyncRunnable)
         .await?;
 
     Ok(())
@@ -0,0 +1,12 @@
+INSERT INTO
+  secret_pool (name, description)
+VALUES
+  ('fruit', 'Things that grow on trees'),
+  ('empty', '');
+
+INSERT INTO
+  secret_pool_word (pool_name, word)
+VALUES
+  ('fruit', 'apple'),
+  ('fruit', 'pear'),
+  ('fruit', 'orange');
@@ -0,0 +1,5 @@
+INSERT INTO
+  slack_bots (app_id, team_id, team_name, bot_token, bot_user_id, is_enterprise_install, installed_at)
+VALUES
+  ('app_id', 'team_id', 'Team', 'xoxb-token', 'bot_user_id', 'f', '2024-02-01 00:00:00+00'),
+  ('app_id', 'other_team_id', 'Other team', 'xoxb-other', 'bot_user_id', 'f', '2024-01-01 00:00:00+00');
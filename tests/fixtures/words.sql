@@ -3,33 +3,33 @@ INSERT INTO
 VALUES
   (
     'secret',
-    '[-0.041259766, 0.22558594, 0.026000977, -0.002105713, -0.2578125, 0.13867188, -0.18945313, 0.114746094, 0.265625, 0.25585938, -0.099121094, -0.26171875, 0.031982422, -0.037597656, -0.25976563, 0.47460938, 0.22167969, -0.04711914, -0.061767578, -0.030151367, 0.13183594, 0.13085938, -0.1171875, -0.13378906, 0.13964844, -0.09814453, 0.13769531, -0.014953613, 0.030273438, -0.24511719, 0.118652344, -0.091308594, 0.24804688, 0.15234375, -0.071777344, -0.17089844, 0.13671875, -0.052734375, -0.15820313, -0.10888672, 0.010559082, -0.07910156, -0.15039063, -0.09375, 0.110839844, -0.11279297, 0.056152344, -0.025146484, -0.16894531, 0.11376953, -0.07714844, 0.08203125, 0.19140625, -0.0007972717, -0.24902344, -0.0033721924, -0.25390625, -0.35351563, 0.17480469, -0.037109375, 0.14550781, 0.026123047, 0.013671875, 0.33007813, -0.05908203, -0.21582031, 0.080078125, 0.10888672, 0.18847656, -0.061767578, -0.0001206398, 0.14941406, 0.15722656, 0.28125, -0.115722656, 0.45507813, 0.2109375, 0.11376953, -0.08203125, 0.17285156, -0.19824219, -0.1640625, -0.0021209717, -0.12792969, -0.039794922, -0.36914063, -0.24023438, 0.095703125, 0.05444336, 0.31835938, -0.015258789, -0.07714844, 0.013366699, 0.10888672, 0.047851563, -0.110839844, 0.12402344, -0.31835938, -0.004211426, -0.13183594, -0.06689453, 0.091308594, 0.09863281, -0.021728516, 0.0047912598, -0.15820313, -0.10449219, 0.16894531, -0.059326172, 0.18457031, 0.16113281, -0.037353516, -0.22460938, 0.047607422, -0.0546875, 0.08984375, 0.2265625, 0.019897461, 0.3046875, -0.056396484, -0.06347656, 0.040039063, 0.123046875, 0.25, -0.016357422, -0.017822266, 0.2578125, 0.038330078, -0.06542969, -0.104003906, -0.20800781, 0.036865234, 0.041259766, 0.051513672, 0.27734375, -0.12597656, -0.052001953, -0.06982422, 0.008605957, -0.057128906, 0.056152344, -0.20996094, -0.087402344, -0.08496094, -0.10253906, -0.12597656, -0.28320313, 0.08105469, -0.18261719, -0.19726563, 0.45898438, 0.056884766, -0.16699219, 0.19140625, -0.061767578, 0.12109375, -0.11035156, -0.076660156, -0.15722656, 0.19433594, -0.15039063, -0.23046875, -0.09814453, 0.015563965, -0.26953125, -0.09033203, -0.27148438, -0.033447266, -0.1171875, -0.032470703, 0.20117188, 0.111816406, -0.11376953, -0.14257813, -0.038085938, -0.115722656, -0.09716797, -0.40820313, 0.15820313, -0.20605469, 0.03466797, -0.19140625, 0.27734375, -0.34179688, 0.080078125, -0.027832031, 0.19824219, 0.12158203, 0.096191406, 0.04736328, 0.23535156, 0.19921875, 0.203125, -0.056152344, -0.11035156, 0.20507813, -0.21777344, -0.16308594, -0.24023438, -0.020385742, 0.22070313, -0.14550781, -0.08642578, 0.15917969, 0.13769531, 0.009155273, -0.11230469, -0.07470703, -0.060791016, 0.091796875, -0.106933594, 0.125, 0.09375, -0.28710938, -0.05908203, 0.064453125, 0.22949219, 0.12890625, -0.19140625, 0.060058594, 0.11621094, -0.17382813, 0.19726563, 0.056152344, -0.12597656, -0.0032043457, 0.28125, 0.24804688, -0.008850098, -0.19140625, 0.17285156, -0.22851563, -0.024902344, 0.29101563, 0.31445313, -0.23925781, 0.05126953, -0.16796875, 0.1875, -0.19042969, -0.046142578, -0.12988281, 0.033691406, 0.05444336, 0.1328125, 0.16992188, -0.047607422, 0.080078125, 0.15527344, 0.18945313, -0.390625, 0.10058594, 0.34570313, 0.025390625, 0.15722656, 0.23632813, -0.19335938, -0.28125, -0.328125, 0.0024108887, 0.17285156, -0.012817383, -0.11425781, 0.19140625, -0.13378906, -0.064453125, -0.29882813, -0.09863281, 0.00970459, -0.27148438, 0.11669922, -0.12109375, 0.114746094, -0.15136719, 0.10205078, -0.14550781, 0.203125, -0.11035156, -0.14941406, -0.030151367, 0.060546875, 0.15917969, -0.12792969, 0.06689453, -0.05908203, -0.12890625, -0.14746094, 0.22460938, 0.33007813, -0.072265625, -0.06640625, 0.14550781, -0.38671875, 0.12011719, -0.3359375, -0.34960938, -0.049316406, -0.0035247803, -0.16210938, 0.15429688]'
+    '{-0.041259766, 0.22558594, 0.026000977, -0.002105713, -0.2578125, 0.13867188, -0.18945313, 0.114746094, 0.265625, 0.25585938, -0.099121094, -0.26171875, 0.031982422, -0.037597656, -0.25976563, 0.47460938, 0.22167969, -0.04711914, -0.061767578, -0.030151367, 0.13183594, 0.13085938, -0.1171875, -0.13378906, 0.13964844, -0.09814453, 0.13769531, -0.014953613, 0.030273438, -0.24511719, 0.118652344, -0.091308594, 0.24804688, 0.15234375, -0.071777344, -0.17089844, 0.13671875, -0.052734375, -0.15820313, -0.10888672, 0.010559082, -0.07910156, -0.15039063, -0.09375, 0.110839844, -0.11279297, 0.056152344, -0.025146484, -0.16894531, 0.11376953, -0.07714844, 0.08203125, 0.19140625, -0.0007972717, -0.24902344, -0.0033721924, -0.25390625, -0.35351563, 0.17480469, -0.037109375, 0.14550781, 0.026123047, 0.013671875, 0.33007813, -0.05908203, -0.21582031, 0.080078125, 0.10888672, 0.18847656, -0.061767578, -0.0001206398, 0.14941406, 0.15722656, 0.28125, -0.115722656, 0.45507813, 0.2109375, 0.11376953, -0.08203125, 0.17285156, -0.19824219, -0.1640625, -0.0021209717, -0.12792969, -0.039794922, -0.36914063, -0.24023438, 0.095703125, 0.05444336, 0.31835938, -0.015258789, -0.07714844, 0.013366699, 0.10888672, 0.047851563, -0.110839844, 0.12402344, -0.31835938, -0.004211426, -0.13183594, -0.06689453, 0.091308594, 0.09863281, -0.021728516, 0.0047912598, -0.15820313, -0.10449219, 0.16894531, -0.059326172, 0.18457031, 0.16113281, -0.037353516, -0.22460938, 0.047607422, -0.0546875, 0.08984375, 0.2265625, 0.019897461, 0.3046875, -0.056396484, -0.06347656, 0.040039063, 0.123046875, 0.25, -0.016357422, -0.017822266, 0.2578125, 0.038330078, -0.06542969, -0.104003906, -0.20800781, 0.036865234, 0.041259766, 0.051513672, 0.27734375, -0.12597656, -0.052001953, -0.06982422, 0.008605957, -0.057128906, 0.056152344, -0.20996094, -0.087402344, -0.08496094, -0.10253906, -0.12597656, -0.28320313, 0.08105469, -0.18261719, -0.19726563, 0.45898438, 0.056884766, -0.16699219, 0.19140625, -0.061767578, 0.12109375, -0.11035156, -0.076660156, -0.15722656, 0.19433594, -0.15039063, -0.23046875, -0.09814453, 0.015563965, -0.26953125, -0.09033203, -0.27148438, -0.033447266, -0.1171875, -0.032470703, 0.20117188, 0.111816406, -0.11376953, -0.14257813, -0.038085938, -0.115722656, -0.09716797, -0.40820313, 0.15820313, -0.20605469, 0.03466797, -0.19140625, 0.27734375, -0.34179688, 0.080078125, -0.027832031, 0.19824219, 0.12158203, 0.096191406, 0.04736328, 0.23535156, 0.19921875, 0.203125, -0.056152344, -0.11035156, 0.20507813, -0.21777344, -0.16308594, -0.24023438, -0.020385742, 0.22070313, -0.14550781, -0.08642578, 0.15917969, 0.13769531, 0.009155273, -0.11230469, -0.07470703, -0.060791016, 0.091796875, -0.106933594, 0.125, 0.09375, -0.28710938, -0.05908203, 0.064453125, 0.22949219, 0.12890625, -0.19140625, 0.060058594, 0.11621094, -0.17382813, 0.19726563, 0.056152344, -0.12597656, -0.0032043457, 0.28125, 0.24804688, -0.008850098, -0.19140625, 0.17285156, -0.22851563, -0.024902344, 0.29101563, 0.31445313, -0.23925781, 0.05126953, -0.16796875, 0.1875, -0.19042969, -0.046142578, -0.12988281, 0.033691406, 0.05444336, 0.1328125, 0.16992188, -0.047607422, 0.080078125, 0.15527344, 0.18945313, -0.390625, 0.10058594, 0.34570313, 0.025390625, 0.15722656, 0.23632813, -0.19335938, -0.28125, -0.328125, 0.0024108887, 0.17285156, -0.012817383, -0.11425781, 0.19140625, -0.13378906, -0.064453125, -0.29882813, -0.09863281, 0.00970459, -0.27148438, 0.11669922, -0.12109375, 0.114746094, -0.15136719, 0.10205078, -0.14550781, 0.203125, -0.11035156, -0.14941406, -0.030151367, 0.060546875, 0.15917969, -0.12792969, 0.06689453, -0.05908203, -0.12890625, -0.14746094, 0.22460938, 0.33007813, -0.072265625, -0.06640625, 0.14550781, -0.38671875, 0.12011719, -0.3359375, -0.34960938, -0.049316406, -0.0035247803, -0.16210938, 0.15429688}'
   ),
   (
     'apple',
-    '[-0.064453125, -0.16015625, -0.012084961, 0.13476563, -0.22949219, 0.16210938, 0.3046875, -0.1796875, -0.12109375, 0.25390625, -0.014282227, -0.063964844, -0.080566406, -0.056884766, -0.19628906, 0.2890625, -0.051513672, 0.14257813, -0.10498047, -0.04736328, -0.34765625, 0.35742188, 0.265625, 0.0018844604, -0.01586914, 0.001953125, -0.35546875, 0.22167969, 0.057617188, 0.15917969, 0.08691406, -0.026733398, -0.047851563, 0.23925781, -0.059814453, 0.037841797, 0.17382813, -0.41796875, 0.2890625, 0.32617188, 0.024291992, -0.016479492, -0.064941406, -0.08886719, 0.076660156, -0.15136719, 0.052490234, -0.041992188, -0.05419922, 0.001083374, -0.20117188, 0.123046875, 0.092285156, 0.10449219, -0.0040893555, -0.041992188, 0.014099121, -0.021118164, -0.13476563, -0.24316406, 0.16015625, -0.06689453, -0.08984375, -0.071777344, -0.0059509277, -0.0048217773, -0.00089263916, -0.30664063, -0.0625, 0.079589844, -0.009094238, -0.044921875, 0.099609375, -0.33398438, -0.3984375, 0.055419922, -0.06689453, -0.044677734, 0.11767578, -0.13964844, -0.26367188, 0.17480469, -0.17382813, -0.40625, -0.06738281, -0.076171875, 0.09423828, 0.20996094, -0.16308594, -0.08691406, -0.053466797, -0.103515625, -0.076171875, -0.110839844, -0.03515625, -0.14941406, 0.037841797, 0.38671875, 0.14160156, -0.2890625, -0.16894531, -0.140625, -0.041748047, 0.22753906, 0.24023438, -0.015991211, -0.067871094, 0.21875, -0.42382813, -0.5625, -0.49414063, -0.3359375, 0.13378906, 0.011413574, 0.13671875, 0.032470703, 0.068359375, -0.27539063, -0.15917969, 0.0012130737, 0.012084961, -0.0039978027, 0.004425049, -0.045410156, 0.08642578, 0.099609375, -0.04296875, -0.11328125, 0.13867188, 0.41796875, -0.28320313, -0.07373047, -0.11425781, 0.08691406, -0.021484375, 0.328125, -0.07373047, -0.0134887695, 0.17773438, -0.026245117, 0.13378906, -0.111328125, -0.12792969, -0.12792969, 0.18945313, -0.13867188, 0.29882813, -0.07714844, -0.37695313, -0.103515625, 0.16992188, -0.107421875, -0.29882813, 0.008666992, -0.27734375, -0.20996094, -0.1796875, -0.19628906, -0.22167969, 0.08886719, -0.27734375, -0.13964844, 0.15917969, 0.036376953, 0.033203125, -0.08105469, 0.25390625, -0.08691406, -0.21289063, -0.18945313, -0.22363281, 0.06542969, -0.16601563, 0.088378906, -0.359375, -0.09863281, 0.35546875, -0.0074157715, 0.19042969, 0.16992188, -0.060058594, -0.20605469, 0.08105469, 0.12988281, -0.011352539, 0.33203125, -0.08691406, 0.27539063, -0.032714844, 0.12011719, -0.0625, 0.1953125, -0.10986328, -0.11767578, 0.20996094, 0.19921875, 0.029541016, -0.16015625, 0.0027618408, -0.013671875, 0.034423828, -0.19335938, 0.0035247803, -0.06542969, -0.055664063, 0.09423828, 0.29296875, 0.040527344, -0.09326172, -0.10107422, -0.27539063, 0.043945313, -0.072753906, 0.13867188, 0.023803711, 0.13085938, 0.0023651123, -0.2265625, 0.34765625, 0.13574219, 0.052246094, 0.18164063, 0.040283203, 0.23730469, -0.16992188, 0.10058594, 0.038330078, 0.10839844, -0.056152344, -0.009460449, 0.14550781, -0.30078125, -0.32226563, 0.18847656, -0.40234375, -0.3125, -0.080078125, -0.26757813, 0.16699219, 0.07324219, 0.06347656, 0.06591797, 0.17285156, -0.17773438, 0.0027618408, -0.057617188, -0.2265625, -0.19628906, 0.09667969, 0.13769531, -0.49414063, -0.27929688, 0.123046875, -0.30078125, 0.012939453, -0.1875, -0.20898438, -0.1796875, -0.16015625, -0.032958984, 0.009765625, 0.25390625, -0.25195313, 0.002105713, 0.04296875, 0.01184082, -0.20605469, 0.24804688, -0.203125, -0.17773438, 0.072753906, 0.045410156, 0.21679688, -0.2109375, 0.14550781, -0.16210938, 0.20410156, -0.19628906, -0.35742188, 0.35742188, -0.119628906, 0.35742188, 0.103515625, 0.07080078, -0.24707031, -0.10449219, -0.19238281, 0.1484375, 0.000579834, 0.296875, -0.12695313, -0.039794922, 0.13183594, -0.16601563, 0.125, 0.05126953, -0.14941406, 0.13671875, -0.020751953, 0.34375]'
+    '{-0.064453125, -0.16015625, -0.012084961, 0.13476563, -0.22949219, 0.16210938, 0.3046875, -0.1796875, -0.12109375, 0.25390625, -0.014282227, -0.063964844, -0.080566406, -0.056884766, -0.19628906, 0.2890625, -0.051513672, 0.14257813, -0.10498047, -0.04736328, -0.34765625, 0.35742188, 0.265625, 0.0018844604, -0.01586914, 0.001953125, -0.35546875, 0.22167969, 0.057617188, 0.15917969, 0.08691406, -0.026733398, -0.047851563, 0.23925781, -0.059814453, 0.037841797, 0.17382813, -0.41796875, 0.2890625, 0.32617188, 0.024291992, -0.016479492, -0.064941406, -0.08886719, 0.076660156, -0.15136719, 0.052490234, -0.041992188, -0.05419922, 0.001083374, -0.20117188, 0.123046875, 0.092285156, 0.10449219, -0.0040893555, -0.041992188, 0.014099121, -0.021118164, -0.13476563, -0.24316406, 0.16015625, -0.06689453, -0.08984375, -0.071777344, -0.0059509277, -0.0048217773, -0.00089263916, -0.30664063, -0.0625, 0.079589844, -0.009094238, -0.044921875, 0.099609375, -0.33398438, -0.3984375, 0.055419922, -0.06689453, -0.044677734, 0.11767578, -0.13964844, -0.26367188, 0.17480469, -0.17382813, -0.40625, -0.06738281, -0.076171875, 0.09423828, 0.20996094, -0.16308594, -0.08691406, -0.053466797, -0.103515625, -0.076171875, -0.110839844, -0.03515625, -0.14941406, 0.037841797, 0.38671875, 0.14160156, -0.2890625, -0.16894531, -0.140625, -0.041748047, 0.22753906, 0.24023438, -0.015991211, -0.067871094, 0.21875, -0.42382813, -0.5625, -0.49414063, -0.3359375, 0.13378906, 0.011413574, 0.13671875, 0.032470703, 0.068359375, -0.27539063, -0.15917969, 0.0012130737, 0.012084961, -0.0039978027, 0.004425049, -0.045410156, 0.08642578, 0.099609375, -0.04296875, -0.11328125, 0.13867188, 0.41796875, -0.28320313, -0.07373047, -0.11425781, 0.08691406, -0.021484375, 0.328125, -0.07373047, -0.0134887695, 0.17773438, -0.026245117, 0.13378906, -0.111328125, -0.12792969, -0.12792969, 0.18945313, -0.13867188, 0.29882813, -0.07714844, -0.37695313, -0.103515625, 0.16992188, -0.107421875, -0.29882813, 0.008666992, -0.27734375, -0.20996094, -0.1796875, -0.19628906, -0.22167969, 0.08886719, -0.27734375, -0.13964844, 0.15917969, 0.036376953, 0.033203125, -0.08105469, 0.25390625, -0.08691406, -0.21289063, -0.18945313, -0.22363281, 0.06542969, -0.16601563, 0.088378906, -0.359375, -0.09863281, 0.35546875, -0.0074157715, 0.19042969, 0.16992188, -0.060058594, -0.20605469, 0.08105469, 0.12988281, -0.011352539, 0.33203125, -0.08691406, 0.27539063, -0.032714844, 0.12011719, -0.0625, 0.1953125, -0.10986328, -0.11767578, 0.20996094, 0.19921875, 0.029541016, -0.16015625, 0.0027618408, -0.013671875, 0.034423828, -0.19335938, 0.0035247803, -0.06542969, -0.055664063, 0.09423828, 0.29296875, 0.040527344, -0.09326172, -0.10107422, -0.27539063, 0.043945313, -0.072753906, 0.13867188, 0.023803711, 0.13085938, 0.0023651123, -0.2265625, 0.34765625, 0.13574219, 0.052246094, 0.18164063, 0.040283203, 0.23730469, -0.16992188, 0.10058594, 0.038330078, 0.10839844, -0.056152344, -0.009460449, 0.14550781, -0.30078125, -0.32226563, 0.18847656, -0.40234375, -0.3125, -0.080078125, -0.26757813, 0.16699219, 0.07324219, 0.06347656, 0.06591797, 0.17285156, -0.17773438, 0.0027618408, -0.057617188, -0.2265625, -0.19628906, 0.09667969, 0.13769531, -0.49414063, -0.27929688, 0.123046875, -0.30078125, 0.012939453, -0.1875, -0.20898438, -0.1796875, -0.16015625, -0.032958984, 0.009765625, 0.25390625, -0.25195313, 0.002105713, 0.04296875, 0.01184082, -0.20605469, 0.24804688, -0.203125, -0.17773438, 0.072753906, 0.045410156, 0.21679688, -0.2109375, 0.14550781, -0.16210938, 0.20410156, -0.19628906, -0.35742188, 0.35742188, -0.119628906, 0.35742188, 0.103515625, 0.07080078, -0.24707031, -0.10449219, -0.19238281, 0.1484375, 0.000579834, 0.296875, -0.12695313, -0.039794922, 0.13183594, -0.16601563, 0.125, 0.05126953, -0.14941406, 0.13671875, -0.020751953, 0.34375}'
   ),
   (
     'excited',
-    '[-0.099609375, 0.19433594, 0.13476563, -0.08496094, 0.111816406, -0.03564453, 0.1875, -0.21191406, -0.0079956055, 0.02758789, -0.110839844, -0.019042969, 0.10986328, -0.26171875, 0.13476563, -0.0010910034, 0.13574219, 0.41796875, 0.17578125, 0.21972656, -0.3046875, 0.079589844, 0.17089844, 0.091796875, 0.17480469, 0.038330078, 0.26757813, -0.1171875, 0.037353516, -0.21679688, 0.17773438, 0.19238281, 0.18652344, -0.0023498535, 0.26757813, -0.13476563, 0.018676758, -0.07128906, 0.28710938, -0.0009994507, 0.10205078, -0.21679688, -0.0069274902, 0.20800781, -0.2265625, -0.18554688, -0.29296875, 0.051513672, 0.033203125, 0.20214844, 0.14941406, -0.014587402, 0.008300781, 0.20117188, 0.31835938, 0.28515625, 0.3203125, -0.16796875, 0.20605469, -0.28710938, 0.0023956299, -0.13378906, -0.16601563, -0.07910156, 0.10058594, 0.12695313, 0.099609375, 0.06542969, 0.0024108887, 0.04638672, 0.02758789, -0.033691406, -0.059570313, 0.16308594, -0.18652344, -0.23046875, 0.29882813, 0.13378906, 0.38476563, 0.39257813, -0.020751953, 0.08886719, 0.28125, 0.18164063, 0.27734375, -0.33007813, -0.40039063, 0.1328125, 0.20605469, 0.12060547, -0.032958984, -0.040283203, -0.203125, 0.045654297, -0.16699219, 0.265625, 0.038330078, 0.14941406, -0.118652344, 0.067871094, -0.22460938, 0.03173828, -0.05859375, 0.09667969, 0.265625, 0.110839844, 0.20507813, -0.12988281, 0.1328125, 0.022949219, 0.021972656, 0.18066406, 0.27148438, -0.06933594, 0.11376953, -0.046875, 0.51171875, -0.22753906, -0.0048217773, -0.44921875, 0.3203125, -0.22265625, -0.01159668, 0.07910156, 0.13671875, -0.15722656, -0.26757813, 0.26953125, -0.26171875, 0.19042969, 0.013244629, -0.021118164, -0.27929688, 0.36132813, 0.092285156, 0.11767578, 0.35351563, 0.38085938, 0.06347656, 0.546875, -0.27734375, 0.07519531, 0.140625, -0.055664063, -0.16015625, 0.23144531, 0.10595703, -0.16113281, 0.12792969, -0.5078125, 0.16015625, 0.4140625, 0.045166016, 0.44335938, -0.0057678223, 0.046875, -0.19140625, -0.100097656, -0.48046875, -0.115722656, -0.06542969, -0.044189453, 0.24121094, 0.28710938, 0.375, -0.23925781, 0.13574219, -0.0087890625, 0.12988281, -0.03466797, 0.061035156, -0.05053711, 0.44335938, -0.15820313, 0.18457031, 0.15625, 0.026611328, -0.20800781, -0.21582031, -0.25976563, -0.017944336, 0.08935547, 0.25585938, -0.012207031, -0.25195313, -0.25390625, -0.22167969, 0.3125, -0.12792969, -0.23339844, -0.0859375, -0.09863281, 0.13964844, -0.40039063, 0.15429688, -0.01586914, 0.10595703, 0.15917969, -0.06640625, -0.19140625, 0.080078125, 0.015991211, 0.25976563, -0.22753906, -0.084472656, 0.045166016, -0.022460938, 0.080078125, -0.114746094, 0.23046875, 0.11230469, 0.19921875, -0.0703125, -0.26757813, 0.07910156, 0.20605469, 0.30078125, -0.26367188, 0.015625, 0.096191406, 0.006500244, 0.030029297, -0.23144531, 0.19824219, 0.12011719, -0.23144531, -0.11279297, -0.26171875, -0.1796875, -0.33398438, -0.3671875, 0.119628906, 0.04248047, -0.22070313, 0.05859375, -0.026977539, -0.012145996, -0.095214844, -0.049804688, 0.10498047, -0.099121094, -0.034423828, -0.044433594, 0.12890625, -0.06347656, -0.032958984, 0.1484375, 0.019042969, -0.02722168, -0.37890625, -0.05102539, -0.079589844, 0.33398438, -0.091796875, -0.123046875, -0.067871094, 0.07519531, -0.025146484, -0.22363281, -0.31835938, -0.20996094, 0.06542969, -0.29882813, 0.02709961, 0.10546875, 0.24414063, 0.26171875, -0.1640625, -0.055908203, 0.061035156, -0.016967773, 0.265625, 0.49609375, 0.14160156, 0.12792969, -0.29296875, 0.048339844, 0.07421875, 0.043701172, -0.14648438, -0.095703125, 0.057128906, -0.296875, 0.13769531, 0.21972656, 0.13183594, -0.0060424805, -0.061767578, 0.19726563, -0.2890625, -0.07714844, 0.005340576, -0.32421875, -0.23828125, 0.09082031, 0.05810547, 0.016479492, -0.0032958984, 0.21972656, -0.39453125]'
+    '{-0.099609375, 0.19433594, 0.13476563, -0.08496094, 0.111816406, -0.03564453, 0.1875, -0.21191406, -0.0079956055, 0.02758789, -0.110839844, -0.019042969, 0.10986328, -0.26171875, 0.13476563, -0.0010910034, 0.13574219, 0.41796875, 0.17578125, 0.21972656, -0.3046875, 0.079589844, 0.17089844, 0.091796875, 0.17480469, 0.038330078, 0.26757813, -0.1171875, 0.037353516, -0.21679688, 0.17773438, 0.19238281, 0.18652344, -0.0023498535, 0.26757813, -0.13476563, 0.018676758, -0.07128906, 0.28710938, -0.0009994507, 0.10205078, -0.21679688, -0.0069274902, 0.20800781, -0.2265625, -0.18554688, -0.29296875, 0.051513672, 0.033203125, 0.20214844, 0.14941406, -0.014587402, 0.008300781, 0.20117188, 0.31835938, 0.28515625, 0.3203125, -0.16796875, 0.20605469, -0.28710938, 0.0023956299, -0.13378906, -0.16601563, -0.07910156, 0.10058594, 0.12695313, 0.099609375, 0.06542969, 0.0024108887, 0.04638672, 0.02758789, -0.033691406, -0.059570313, 0.16308594, -0.18652344, -0.23046875, 0.29882813, 0.13378906, 0.38476563, 0.39257813, -0.020751953, 0.08886719, 0.28125, 0.18164063, 0.27734375, -0.33007813, -0.40039063, 0.1328125, 0.20605469, 0.12060547, -0.032958984, -0.040283203, -0.203125, 0.045654297, -0.16699219, 0.265625, 0.038330078, 0.14941406, -0.118652344, 0.067871094, -0.22460938, 0.03173828, -0.05859375, 0.09667969, 0.265625, 0.110839844, 0.20507813, -0.12988281, 0.1328125, 0.022949219, 0.021972656, 0.18066406, 0.27148438, -0.06933594, 0.11376953, -0.046875, 0.51171875, -0.22753906, -0.0048217773, -0.44921875, 0.3203125, -0.22265625, -0.01159668, 0.07910156, 0.13671875, -0.15722656, -0.26757813, 0.26953125, -0.26171875, 0.19042969, 0.013244629, -0.021118164, -0.27929688, 0.36132813, 0.092285156, 0.11767578, 0.35351563, 0.38085938, 0.06347656, 0.546875, -0.27734375, 0.07519531, 0.140625, -0.055664063, -0.16015625, 0.23144531, 0.10595703, -0.16113281, 0.12792969, -0.5078125, 0.16015625, 0.4140625, 0.045166016, 0.44335938, -0.0057678223, 0.046875, -0.19140625, -0.100097656, -0.48046875, -0.115722656, -0.06542969, -0.044189453, 0.24121094, 0.28710938, 0.375, -0.23925781, 0.13574219, -0.0087890625, 0.12988281, -0.03466797, 0.061035156, -0.05053711, 0.44335938, -0.15820313, 0.18457031, 0.15625, 0.026611328, -0.20800781, -0.21582031, -0.25976563, -0.017944336, 0.08935547, 0.25585938, -0.012207031, -0.25195313, -0.25390625, -0.22167969, 0.3125, -0.12792969, -0.23339844, -0.0859375, -0.09863281, 0.13964844, -0.40039063, 0.15429688, -0.01586914, 0.10595703, 0.15917969, -0.06640625, -0.19140625, 0.080078125, 0.015991211, 0.25976563, -0.22753906, -0.084472656, 0.045166016, -0.022460938, 0.080078125, -0.114746094, 0.23046875, 0.11230469, 0.19921875, -0.0703125, -0.26757813, 0.07910156, 0.20605469, 0.30078125, -0.26367188, 0.015625, 0.096191406, 0.006500244, 0.030029297, -0.23144531, 0.19824219, 0.12011719, -0.23144531, -0.11279297, -0.26171875, -0.1796875, -0.33398438, -0.3671875, 0.119628906, 0.04248047, -0.22070313, 0.05859375, -0.026977539, -0.012145996, -0.095214844, -0.049804688, 0.10498047, -0.099121094, -0.034423828, -0.044433594, 0.12890625, -0.06347656, -0.032958984, 0.1484375, 0.019042969, -0.02722168, -0.37890625, -0.05102539, -0.079589844, 0.33398438, -0.091796875, -0.123046875, -0.067871094, 0.07519531, -0.025146484, -0.22363281, -0.31835938, -0.20996094, 0.06542969, -0.29882813, 0.02709961, 0.10546875, 0.24414063, 0.26171875, -0.1640625, -0.055908203, 0.061035156, -0.016967773, 0.265625, 0.49609375, 0.14160156, 0.12792969, -0.29296875, 0.048339844, 0.07421875, 0.043701172, -0.14648438, -0.095703125, 0.057128906, -0.296875, 0.13769531, 0.21972656, 0.13183594, -0.0060424805, -0.061767578, 0.19726563, -0.2890625, -0.07714844, 0.005340576, -0.32421875, -0.23828125, 0.09082031, 0.05810547, 0.016479492, -0.0032958984, 0.21972656, -0.39453125}'
   ),
   (
     'fruit',
-    '[-0.05834961, 0.067871094, -0.053955078, 0.33398438, -0.13574219, 0.08203125, 0.19726563, -0.17675781, 0.031982422, 0.375, 0.32226563, -0.075683594, 0.022460938, 0.24902344, -0.16699219, 0.083496094, -0.34960938, 0.1640625, -0.004058838, 0.061767578, -0.107421875, 0.0066833496, 0.14160156, 0.0546875, 0.084472656, -0.31054688, -0.30859375, 0.061279297, 0.002670288, 0.03881836, -0.025390625, -0.08251953, 0.20996094, 0.022705078, -0.09326172, 0.13183594, -0.14160156, -0.44140625, 0.09423828, 0.13769531, 0.05883789, -0.0625, -0.040283203, 0.022216797, -0.115722656, -0.30078125, 0.0029754639, 0.056884766, -0.15527344, 0.03125, 0.07080078, 0.020263672, 0.11279297, 0.009643555, -0.03515625, -0.02319336, -0.032226563, 0.023925781, -0.0625, -0.2734375, -0.109375, -0.068359375, -0.10205078, -0.025756836, -0.2421875, -0.21875, 0.013366699, -0.28125, 0.12890625, -0.07421875, -0.03540039, 0.008239746, 0.06347656, -0.005340576, -0.28515625, -0.06225586, -0.24707031, 0.08496094, -0.091796875, -0.0087890625, -0.10205078, 0.20996094, -0.016479492, -0.36328125, -0.040283203, -0.072265625, -0.05126953, 0.032714844, 0.0027008057, 0.18164063, 0.12695313, -0.23925781, -0.07324219, -0.030761719, -0.018920898, -0.045410156, -0.07470703, 0.06542969, 0.07128906, -0.1484375, -0.3046875, -0.20898438, 0.045166016, 0.21972656, -0.0390625, -0.12060547, 0.075683594, 0.048339844, -0.359375, -0.3203125, -0.39453125, -0.27539063, 0.08251953, 0.024658203, -0.09082031, 0.030273438, 0.19140625, -0.13378906, -0.11621094, -0.09765625, -0.061767578, 0.3125, 0.1015625, -0.049560547, -0.04736328, 0.111816406, -0.20214844, 0.048828125, 0.092285156, 0.22363281, -0.13183594, -0.07470703, -0.029052734, -0.104003906, 0.045166016, 0.39648438, 0.011779785, 0.08496094, -0.059814453, -0.19628906, -0.111328125, -0.18457031, -0.11816406, -0.092285156, 0.0703125, -0.00090789795, 0.33984375, 0.1640625, -0.47851563, -0.0008735657, 0.28125, 0.030273438, -0.21875, 0.24316406, -0.018554688, -0.14550781, -0.11230469, -0.28710938, -0.23144531, -0.025634766, -0.27734375, -0.12597656, 0.17382813, 0.18945313, 0.008544922, -0.091796875, 0.05419922, 0.068359375, -0.083984375, -0.2578125, -0.15332031, 0.08154297, -0.12158203, -0.3046875, -0.30273438, -0.045898438, 0.25, -0.11621094, 0.24316406, 0.234375, -0.140625, -0.33398438, -0.008728027, 0.18457031, -0.22363281, 0.080566406, -0.059814453, 0.053955078, -0.125, 0.11035156, -0.092285156, 0.375, -0.4296875, -0.009460449, 0.15136719, 0.26367188, 0.18457031, -0.23730469, -0.13769531, -0.22851563, 0.03149414, -0.19726563, -0.023803711, -0.17675781, -0.053466797, 0.08154297, 0.041503906, 0.13671875, 0.10888672, -0.34765625, -0.53125, 0.00092697144, -0.040039063, 0.3203125, -0.07373047, 0.13378906, -0.044189453, 0.13378906, 0.25, 0.484375, 0.14550781, -0.12792969, -0.22851563, 0.16699219, 0.3203125, 0.265625, -0.076171875, 0.2578125, 0.011474609, 0.18066406, 0.037597656, -0.28320313, -0.15625, 0.14746094, -0.26953125, -0.33007813, -0.06738281, -0.0071105957, 0.33984375, -0.11328125, -0.09814453, -0.005126953, 0.08642578, -0.083496094, 0.106933594, -0.111816406, -0.092285156, 0.016845703, -0.060546875, 0.24414063, -0.10449219, -0.11621094, 0.119628906, -0.15917969, 0.03540039, -0.03466797, 0.0067443848, -0.34179688, -0.15820313, 0.008666992, -0.09863281, 0.13574219, -0.30664063, -0.0134887695, 0.10205078, -0.31640625, -0.2578125, 0.12792969, -0.1484375, -0.026611328, -0.03930664, 0.20703125, 0.28710938, -0.11279297, 0.080078125, 0.10449219, -0.10449219, -0.1640625, -0.042236328, 0.060058594, -0.15917969, 0.33398438, 0.140625, -0.068359375, -0.056640625, -0.11376953, 0.05419922, 0.3359375, -0.08544922, 0.328125, -0.15039063, 0.041992188, 0.23046875, -0.3046875, -0.080566406, 0.18554688, 0.106933594, 0.084472656, 0.023071289, 0.24316406]'
+    '{-0.05834961, 0.067871094, -0.053955078, 0.33398438, -0.13574219, 0.08203125, 0.19726563, -0.17675781, 0.031982422, 0.375, 0.32226563, -0.075683594, 0.022460938, 0.24902344, -0.16699219, 0.083496094, -0.34960938, 0.1640625, -0.004058838, 0.061767578, -0.107421875, 0.0066833496, 0.14160156, 0.0546875, 0.084472656, -0.31054688, -0.30859375, 0.061279297, 0.002670288, 0.03881836, -0.025390625, -0.08251953, 0.20996094, 0.022705078, -0.09326172, 0.13183594, -0.14160156, -0.44140625, 0.09423828, 0.13769531, 0.05883789, -0.0625, -0.040283203, 0.022216797, -0.115722656, -0.30078125, 0.0029754639, 0.056884766, -0.15527344, 0.03125, 0.07080078, 0.020263672, 0.11279297, 0.009643555, -0.03515625, -0.02319336, -0.032226563, 0.023925781, -0.0625, -0.2734375, -0.109375, -0.068359375, -0.10205078, -0.025756836, -0.2421875, -0.21875, 0.013366699, -0.28125, 0.12890625, -0.07421875, -0.03540039, 0.008239746, 0.06347656, -0.005340576, -0.28515625, -0.06225586, -0.24707031, 0.08496094, -0.091796875, -0.0087890625, -0.10205078, 0.20996094, -0.016479492, -0.36328125, -0.040283203, -0.072265625, -0.05126953, 0.032714844, 0.0027008057, 0.18164063, 0.12695313, -0.23925781, -0.07324219, -0.030761719, -0.018920898, -0.045410156, -0.07470703, 0.06542969, 0.07128906, -0.1484375, -0.3046875, -0.20898438, 0.045166016, 0.21972656, -0.0390625, -0.12060547, 0.075683594, 0.048339844, -0.359375, -0.3203125, -0.39453125, -0.27539063, 0.08251953, 0.024658203, -0.09082031, 0.030273438, 0.19140625, -0.13378906, -0.11621094, -0.09765625, -0.061767578, 0.3125, 0.1015625, -0.049560547, -0.04736328, 0.111816406, -0.20214844, 0.048828125, 0.092285156, 0.22363281, -0.13183594, -0.07470703, -0.029052734, -0.104003906, 0.045166016, 0.39648438, 0.011779785, 0.08496094, -0.059814453, -0.19628906, -0.111328125, -0.18457031, -0.11816406, -0.092285156, 0.0703125, -0.00090789795, 0.33984375, 0.1640625, -0.47851563, -0.0008735657, 0.28125, 0.030273438, -0.21875, 0.24316406, -0.018554688, -0.14550781, -0.11230469, -0.28710938, -0.23144531, -0.025634766, -0.27734375, -0.12597656, 0.17382813, 0.18945313, 0.008544922, -0.091796875, 0.05419922, 0.068359375, -0.083984375, -0.2578125, -0.15332031, 0.08154297, -0.12158203, -0.3046875, -0.30273438, -0.045898438, 0.25, -0.11621094, 0.24316406, 0.234375, -0.140625, -0.33398438, -0.008728027, 0.18457031, -0.22363281, 0.080566406, -0.059814453, 0.053955078, -0.125, 0.11035156, -0.092285156, 0.375, -0.4296875, -0.009460449, 0.15136719, 0.26367188, 0.18457031, -0.23730469, -0.13769531, -0.22851563, 0.03149414, -0.19726563, -0.023803711, -0.17675781, -0.053466797, 0.08154297, 0.041503906, 0.13671875, 0.10888672, -0.34765625, -0.53125, 0.00092697144, -0.040039063, 0.3203125, -0.07373047, 0.13378906, -0.044189453, 0.13378906, 0.25, 0.484375, 0.14550781, -0.12792969, -0.22851563, 0.16699219, 0.3203125, 0.265625, -0.076171875, 0.2578125, 0.011474609, 0.18066406, 0.037597656, -0.28320313, -0.15625, 0.14746094, -0.26953125, -0.33007813, -0.06738281, -0.0071105957, 0.33984375, -0.11328125, -0.09814453, -0.005126953, 0.08642578, -0.083496094, 0.106933594, -0.111816406, -0.092285156, 0.016845703, -0.060546875, 0.24414063, -0.10449219, -0.11621094, 0.119628906, -0.15917969, 0.03540039, -0.03466797, 0.0067443848, -0.34179688, -0.15820313, 0.008666992, -0.09863281, 0.13574219, -0.30664063, -0.0134887695, 0.10205078, -0.31640625, -0.2578125, 0.12792969, -0.1484375, -0.026611328, -0.03930664, 0.20703125, 0.28710938, -0.11279297, 0.080078125, 0.10449219, -0.10449219, -0.1640625, -0.042236328, 0.060058594, -0.15917969, 0.33398438, 0.140625, -0.068359375, -0.056640625, -0.11376953, 0.05419922, 0.3359375, -0.08544922, 0.328125, -0.15039063, 0.041992188, 0.23046875, -0.3046875, -0.080566406, 0.18554688, 0.106933594, 0.084472656, 0.023071289, 0.24316406}'
   ),
   (
     'future',
-    '[-0.080566406, -0.012817383, 0.14355469, 0.051757813, -0.0625, -0.042236328, 0.107421875, -0.06689453, 0.20019531, 0.14453125, -0.26367188, 0.12451172, -0.048339844, 0.05859375, -0.13769531, -0.067871094, -0.11035156, 0.27148438, 0.056884766, 0.0018157959, -0.10546875, 0.008239746, -0.25195313, 0.16015625, -0.08105469, 0.044433594, 0.029296875, 0.044921875, -0.055664063, 0.030517578, 0.075683594, -0.12792969, -0.123046875, 0.029174805, 0.016235352, 0.06542969, 0.16992188, -0.021606445, -0.03125, -0.22753906, 0.01977539, -0.017700195, 0.041259766, -0.0048217773, -0.04296875, -0.076660156, -0.076171875, 0.053710938, -0.09375, -0.052246094, 0.10498047, 0.26171875, 0.025878906, -0.091796875, -0.009277344, -0.038085938, -0.032470703, -0.22460938, 0.04638672, 0.09375, -0.03930664, -0.091796875, 0.017089844, 0.17382813, -0.203125, 0.0390625, 0.16796875, 0.071777344, -0.053222656, -0.038085938, -0.18847656, -0.021362305, 0.21386719, -0.12402344, -0.234375, -0.16308594, -0.115234375, 0.064453125, 0.046875, 0.034179688, 0.22851563, 0.016479492, -0.16015625, -0.08105469, 0.14550781, -0.14257813, -0.23046875, 0.03149414, 0.07861328, 0.16992188, 0.26367188, -0.091796875, 0.028198242, -0.22558594, -0.018554688, -0.103515625, 0.19042969, 0.15722656, 0.12695313, -0.023071289, -0.12207031, -0.07470703, 0.24023438, -0.12451172, -0.25, -0.1015625, -0.11816406, -0.08886719, -0.044433594, 0.14746094, 0.119140625, -0.025634766, 0.06640625, 0.048339844, 0.27734375, -0.029052734, 0.055908203, -0.013427734, 0.11328125, 0.14648438, 0.140625, 0.049316406, 0.22851563, 0.080078125, 0.0033416748, 0.014282227, -0.32421875, 0.10449219, -0.19921875, -0.06225586, -0.19238281, 0.0070495605, -0.37695313, 0.036865234, 0.14648438, 0.026123047, 0.10498047, 0.021850586, 0.19726563, 0.33203125, 0.051757813, -0.11669922, -0.048828125, 0.24707031, 0.34179688, -0.14453125, 0.017089844, -0.052978516, 0.040039063, -0.018798828, -0.08642578, 0.2578125, -0.1875, -0.0146484375, -0.09277344, 0.16308594, 0.16894531, -0.14160156, 0.20898438, 0.033203125, 0.05102539, 0.10205078, 0.20605469, -0.052734375, 0.123535156, 0.20507813, -0.043945313, 0.071777344, -0.018798828, -0.01373291, 0.064453125, -0.14550781, 0.076171875, -0.30664063, 0.2265625, -0.06347656, 0.17285156, -0.18554688, 0.09277344, 0.17675781, -0.22558594, -0.044677734, 0.07910156, -0.109375, 0.005218506, -0.063964844, 0.029418945, 0.022338867, 0.21582031, 0.2890625, 0.052734375, 0.12890625, 0.056640625, -0.21484375, -0.05102539, -0.0077819824, -0.12207031, -0.08300781, 0.091796875, -0.18945313, -0.12988281, 0.05859375, 0.091308594, -0.122558594, 0.0028686523, -0.1171875, 0.063964844, -0.084472656, -0.030517578, -0.022949219, -0.07714844, -0.021240234, 0.049316406, 0.06591797, 0.0625, -0.0024108887, 0.25976563, -0.19433594, -0.08154297, 0.16894531, -0.05126953, -0.049560547, -0.006958008, -0.010681152, 0.09863281, -0.265625, -0.1171875, -0.100097656, -0.103027344, -0.06542969, -0.01977539, 0.22363281, 0.12109375, -0.040527344, 0.44335938, -0.13378906, -0.076660156, -0.12695313, 0.0062561035, -0.07373047, 0.04296875, -0.040039063, 0.017700195, -0.10058594, -0.095703125, -0.07128906, 0.017456055, -0.171875, -0.06689453, 0.17285156, -0.07421875, 0.23828125, 0.12695313, 0.1953125, -0.13769531, -0.075683594, 0.14550781, 0.22265625, -0.29296875, -0.007385254, -0.075683594, 0.022094727, -0.043701172, 0.28320313, 0.061279297, 0.23046875, -0.2265625, -0.09082031, -0.24707031, -0.20996094, 0.19628906, 0.022949219, 0.11669922, -0.0011672974, -0.14160156, -0.012939453, 0.09423828, -0.10205078, -0.088378906, 0.0026855469, 0.055664063, 0.11767578, 0.06689453, 0.1484375, -0.060791016, 0.07470703, 0.106933594, -0.19726563, 0.11767578, 0.078125, -0.21679688, 0.16015625, -0.024902344, -0.052246094, 0.063964844, 0.036132813, -0.048339844, 0.04638672, -0.026855469, -0.16503906]'
+    '{-0.080566406, -0.012817383, 0.14355469, 0.051757813, -0.0625, -0.042236328, 0.107421875, -0.06689453, 0.20019531, 0.14453125, -0.26367188, 0.12451172, -0.048339844, 0.05859375, -0.13769531, -0.067871094, -0.11035156, 0.27148438, 0.056884766, 0.0018157959, -0.10546875, 0.008239746, -0.25195313, 0.16015625, -0.08105469, 0.044433594, 0.029296875, 0.044921875, -0.055664063, 0.030517578, 0.075683594, -0.12792969, -0.123046875, 0.029174805, 0.016235352, 0.06542969, 0.16992188, -0.021606445, -0.03125, -0.22753906, 0.01977539, -0.017700195, 0.041259766, -0.0048217773, -0.04296875, -0.076660156, -0.076171875, 0.053710938, -0.09375, -0.052246094, 0.10498047, 0.26171875, 0.025878906, -0.091796875, -0.009277344, -0.038085938, -0.032470703, -0.22460938, 0.04638672, 0.09375, -0.03930664, -0.091796875, 0.017089844, 0.17382813, -0.203125, 0.0390625, 0.16796875, 0.071777344, -0.053222656, -0.038085938, -0.18847656, -0.021362305, 0.21386719, -0.12402344, -0.234375, -0.16308594, -0.115234375, 0.064453125, 0.046875, 0.034179688, 0.22851563, 0.016479492, -0.16015625, -0.08105469, 0.14550781, -0.14257813, -0.23046875, 0.03149414, 0.07861328, 0.16992188, 0.26367188, -0.091796875, 0.028198242, -0.22558594, -0.018554688, -0.103515625, 0.19042969, 0.15722656, 0.12695313, -0.023071289, -0.12207031, -0.07470703, 0.24023438, -0.12451172, -0.25, -0.1015625, -0.11816406, -0.08886719, -0.044433594, 0.14746094, 0.119140625, -0.025634766, 0.06640625, 0.048339844, 0.27734375, -0.029052734, 0.055908203, -0.013427734, 0.11328125, 0.14648438, 0.140625, 0.049316406, 0.22851563, 0.080078125, 0.0033416748, 0.014282227, -0.32421875, 0.10449219, -0.19921875, -0.06225586, -0.19238281, 0.0070495605, -0.37695313, 0.036865234, 0.14648438, 0.026123047, 0.10498047, 0.021850586, 0.19726563, 0.33203125, 0.051757813, -0.11669922, -0.048828125, 0.24707031, 0.34179688, -0.14453125, 0.017089844, -0.052978516, 0.040039063, -0.018798828, -0.08642578, 0.2578125, -0.1875, -0.0146484375, -0.09277344, 0.16308594, 0.16894531, -0.14160156, 0.20898438, 0.033203125, 0.05102539, 0.10205078, 0.20605469, -0.052734375, 0.123535156, 0.20507813, -0.043945313, 0.071777344, -0.018798828, -0.01373291, 0.064453125, -0.14550781, 0.076171875, -0.30664063, 0.2265625, -0.06347656, 0.17285156, -0.18554688, 0.09277344, 0.17675781, -0.22558594, -0.044677734, 0.07910156, -0.109375, 0.005218506, -0.063964844, 0.029418945, 0.022338867, 0.21582031, 0.2890625, 0.052734375, 0.12890625, 0.056640625, -0.21484375, -0.05102539, -0.0077819824, -0.12207031, -0.08300781, 0.091796875, -0.18945313, -0.12988281, 0.05859375, 0.091308594, -0.122558594, 0.0028686523, -0.1171875, 0.063964844, -0.084472656, -0.030517578, -0.022949219, -0.07714844, -0.021240234, 0.049316406, 0.06591797, 0.0625, -0.0024108887, 0.25976563, -0.19433594, -0.08154297, 0.16894531, -0.05126953, -0.049560547, -0.006958008, -0.010681152, 0.09863281, -0.265625, -0.1171875, -0.100097656, -0.103027344, -0.06542969, -0.01977539, 0.22363281, 0.12109375, -0.040527344, 0.44335938, -0.13378906, -0.076660156, -0.12695313, 0.0062561035, -0.07373047, 0.04296875, -0.040039063, 0.017700195, -0.10058594, -0.095703125, -0.07128906, 0.017456055, -0.171875, -0.06689453, 0.17285156, -0.07421875, 0.23828125, 0.12695313, 0.1953125, -0.13769531, -0.075683594, 0.14550781, 0.22265625, -0.29296875, -0.007385254, -0.075683594, 0.022094727, -0.043701172, 0.28320313, 0.061279297, 0.23046875, -0.2265625, -0.09082031, -0.24707031, -0.20996094, 0.19628906, 0.022949219, 0.11669922, -0.0011672974, -0.14160156, -0.012939453, 0.09423828, -0.10205078, -0.088378906, 0.0026855469, 0.055664063, 0.11767578, 0.06689453, 0.1484375, -0.060791016, 0.07470703, 0.106933594, -0.19726563, 0.11767578, 0.078125, -0.21679688, 0.16015625, -0.024902344, -0.052246094, 0.063964844, 0.036132813, -0.048339844, 0.04638672, -0.026855469, -0.16503906}'
   ),
   (
     'happy',
-    '[-0.0005187988, 0.16015625, 0.0016098022, 0.025390625, 0.099121094, -0.0859375, 0.32421875, -0.021728516, 0.13476563, 0.11035156, -0.10498047, -0.029052734, -0.023803711, -0.040283203, -0.036865234, 0.23242188, 0.3203125, 0.10107422, 0.05834961, -0.00029182434, -0.032958984, 0.21191406, 0.04321289, -0.0859375, 0.28125, -0.017822266, 0.0037994385, -0.171875, 0.20605469, -0.18554688, 0.037353516, -0.012145996, 0.20410156, -0.038085938, 0.036132813, -0.08154297, 0.084472656, 0.09375, 0.14453125, 0.07421875, 0.25195313, -0.07910156, 0.08691406, 0.01586914, 0.109375, -0.22363281, -0.0051574707, 0.16894531, -0.13671875, -0.025146484, -0.03857422, -0.013305664, 0.13867188, 0.17675781, 0.11035156, 0.15136719, 0.07861328, -0.16992188, 0.12060547, -0.4375, -0.04321289, 0.13476563, -0.34570313, 0.091308594, 0.04711914, 0.09667969, -0.016113281, -0.04711914, -0.046875, 0.13769531, 0.099609375, 0.044921875, -0.024902344, 0.15820313, -0.35742188, -0.12109375, 0.115722656, 0.09082031, 0.140625, 0.16015625, -0.004425049, 0.053466797, 0.22851563, 0.18847656, -0.03881836, -0.25390625, -0.17480469, 0.09814453, 0.010864258, 0.14160156, 0.0078125, 0.13671875, -0.20800781, -0.034179688, -0.25, 0.12597656, 0.15722656, 0.0033111572, -0.15136719, -0.06982422, -0.140625, 0.20605469, -0.03540039, 0.15722656, 0.05834961, -0.035888672, 0.21289063, -0.11376953, 0.14160156, -0.012939453, 0.091308594, -0.03955078, 0.09765625, -0.026977539, 0.013000488, -0.13085938, 0.33203125, -0.35351563, -0.05444336, -0.025024414, -0.14257813, 0.064941406, 0.055419922, -0.048339844, -0.11230469, -0.1328125, -0.06738281, -0.14160156, -0.20507813, -0.12988281, -0.104003906, -0.08105469, -0.16796875, 0.16308594, -0.11376953, -0.051757813, 0.076171875, 0.359375, 0.104003906, 0.359375, -0.087402344, 0.06542969, -0.010986328, -0.18847656, -0.06591797, 0.23046875, -0.296875, 0.006591797, 0.14941406, -0.17382813, 0.13183594, 0.23632813, -0.092285156, 0.17089844, -0.017089844, 0.03125, -0.0033721924, 0.09667969, -0.26171875, -0.018432617, -0.18554688, 0.12402344, 0.30078125, 0.24316406, 0.30664063, -0.328125, -0.05053711, 0.1015625, 0.07861328, -0.14453125, -0.12597656, -0.024169922, 0.29492188, -0.15039063, -0.039794922, 0.27539063, 0.12695313, -0.09863281, -0.13964844, 0.025268555, -0.08544922, -0.017211914, 0.091796875, 0.13964844, -0.23925781, -0.21191406, -0.22167969, 0.15332031, -0.01586914, -0.20019531, -0.020751953, 0.035888672, -0.0000006966293, -0.21386719, 0.20019531, -0.109375, -0.051513672, 0.06225586, -0.32226563, -0.07861328, 0.05029297, 0.07080078, 0.12011719, -0.1796875, 0.15917969, -0.0010223389, -0.34960938, 0.125, 0.064453125, 0.08105469, -0.033935547, 0.07421875, -0.030883789, -0.13867188, -0.031982422, 0.19921875, 0.125, 0.056884766, -0.16796875, 0.13085938, 0.029052734, -0.014953613, -0.13964844, 0.040771484, -0.010559082, -0.17480469, 0.21289063, -0.14160156, 0.023071289, -0.033691406, -0.087890625, -0.06640625, -0.06933594, -0.07421875, 0.0703125, -0.020141602, -0.12695313, -0.036376953, 0.059326172, 0.11816406, -0.0063476563, -0.07421875, 0.031982422, 0.06689453, -0.22753906, 0.06542969, 0.017944336, 0.14648438, -0.05493164, -0.115234375, -0.21679688, 0.087402344, 0.26171875, 0.15429688, 0.006713867, -0.027832031, -0.0041503906, -0.020996094, -0.05517578, -0.009765625, -0.12988281, 0.13183594, -0.008422852, 0.22949219, 0.17871094, 0.19433594, 0.046875, 0.021850586, -0.02758789, 0.17382813, 0.13378906, 0.13671875, 0.31054688, 0.009399414, 0.092285156, -0.24414063, -0.05102539, 0.078125, -0.14355469, 0.091796875, 0.029663086, 0.009460449, -0.20410156, 0.16015625, 0.14355469, -0.020263672, 0.021362305, -0.06982422, -0.003112793, -0.025268555, -0.10986328, 0.107910156, -0.0703125, -0.12792969, -0.05078125, 0.04272461, -0.07324219, -0.03540039, 0.08886719, -0.30273438]'
+    '{-0.0005187988, 0.16015625, 0.0016098022, 0.025390625, 0.099121094, -0.0859375, 0.32421875, -0.021728516, 0.13476563, 0.11035156, -0.10498047, -0.029052734, -0.023803711, -0.040283203, -0.036865234, 0.23242188, 0.3203125, 0.10107422, 0.05834961, -0.00029182434, -0.032958984, 0.21191406, 0.04321289, -0.0859375, 0.28125, -0.017822266, 0.0037994385, -0.171875, 0.20605469, -0.18554688, 0.037353516, -0.012145996, 0.20410156, -0.038085938, 0.036132813, -0.08154297, 0.084472656, 0.09375, 0.14453125, 0.07421875, 0.25195313, -0.07910156, 0.08691406, 0.01586914, 0.109375, -0.22363281, -0.0051574707, 0.16894531, -0.13671875, -0.025146484, -0.03857422, -0.013305664, 0.13867188, 0.17675781, 0.11035156, 0.15136719, 0.07861328, -0.16992188, 0.12060547, -0.4375, -0.04321289, 0.13476563, -0.34570313, 0.091308594, 0.04711914, 0.09667969, -0.016113281, -0.04711914, -0.046875, 0.13769531, 0.099609375, 0.044921875, -0.024902344, 0.15820313, -0.35742188, -0.12109375, 0.115722656, 0.09082031, 0.140625, 0.16015625, -0.004425049, 0.053466797, 0.22851563, 0.18847656, -0.03881836, -0.25390625, -0.17480469, 0.09814453, 0.010864258, 0.14160156, 0.0078125, 0.13671875, -0.20800781, -0.034179688, -0.25, 0.12597656, 0.15722656, 0.0033111572, -0.15136719, -0.06982422, -0.140625, 0.20605469, -0.03540039, 0.15722656, 0.05834961, -0.035888672, 0.21289063, -0.11376953, 0.14160156, -0.012939453, 0.091308594, -0.03955078, 0.09765625, -0.026977539, 0.013000488, -0.13085938, 0.33203125, -0.35351563, -0.05444336, -0.025024414, -0.14257813, 0.064941406, 0.055419922, -0.048339844, -0.11230469, -0.1328125, -0.06738281, -0.14160156, -0.20507813, -0.12988281, -0.104003906, -0.08105469, -0.16796875, 0.16308594, -0.11376953, -0.051757813, 0.076171875, 0.359375, 0.104003906, 0.359375, -0.087402344, 0.06542969, -0.010986328, -0.18847656, -0.06591797, 0.23046875, -0.296875, 0.006591797, 0.14941406, -0.17382813, 0.13183594, 0.23632813, -0.092285156, 0.17089844, -0.017089844, 0.03125, -0.0033721924, 0.09667969, -0.26171875, -0.018432617, -0.18554688, 0.12402344, 0.30078125, 0.24316406, 0.30664063, -0.328125, -0.05053711, 0.1015625, 0.07861328, -0.14453125, -0.12597656, -0.024169922, 0.29492188, -0.15039063, -0.039794922, 0.27539063, 0.12695313, -0.09863281, -0.13964844, 0.025268555, -0.08544922, -0.017211914, 0.091796875, 0.13964844, -0.23925781, -0.21191406, -0.22167969, 0.15332031, -0.01586914, -0.20019531, -0.020751953, 0.035888672, -0.0000006966293, -0.21386719, 0.20019531, -0.109375, -0.051513672, 0.06225586, -0.32226563, -0.07861328, 0.05029297, 0.07080078, 0.12011719, -0.1796875, 0.15917969, -0.0010223389, -0.34960938, 0.125, 0.064453125, 0.08105469, -0.033935547, 0.07421875, -0.030883789, -0.13867188, -0.031982422, 0.19921875, 0.125, 0.056884766, -0.16796875, 0.13085938, 0.029052734, -0.014953613, -0.13964844, 0.040771484, -0.010559082, -0.17480469, 0.21289063, -0.14160156, 0.023071289, -0.033691406, -0.087890625, -0.06640625, -0.06933594, -0.07421875, 0.0703125, -0.020141602, -0.12695313, -0.036376953, 0.059326172, 0.11816406, -0.0063476563, -0.07421875, 0.031982422, 0.06689453, -0.22753906, 0.06542969, 0.017944336, 0.14648438, -0.05493164, -0.115234375, -0.21679688, 0.087402344, 0.26171875, 0.15429688, 0.006713867, -0.027832031, -0.0041503906, -0.020996094, -0.05517578, -0.009765625, -0.12988281, 0.13183594, -0.008422852, 0.22949219, 0.17871094, 0.19433594, 0.046875, 0.021850586, -0.02758789, 0.17382813, 0.13378906, 0.13671875, 0.31054688, 0.009399414, 0.092285156, -0.24414063, -0.05102539, 0.078125, -0.14355469, 0.091796875, 0.029663086, 0.009460449, -0.20410156, 0.16015625, 0.14355469, -0.020263672, 0.021362305, -0.06982422, -0.003112793, -0.025268555, -0.10986328, 0.107910156, -0.0703125, -0.12792969, -0.05078125, 0.04272461, -0.07324219, -0.03540039, 0.08886719, -0.30273438}'
   ),
   (
     'orange',
-    '[-0.10498047, -0.18261719, 0.099121094, 0.26367188, -0.19628906, -0.20019531, 0.013977051, -0.44140625, 0.044677734, 0.28125, 0.123535156, 0.045410156, 0.16894531, -0.171875, -0.39648438, -0.09277344, -0.34375, 0.2421875, -0.13476563, -0.064941406, -0.056884766, 0.25, 0.036621094, -0.12451172, -0.13476563, -0.0703125, -0.24511719, 0.10498047, -0.014587402, 0.032714844, 0.19042969, 0.18359375, 0.19238281, -0.111816406, 0.028320313, 0.123046875, 0.071777344, -0.078125, -0.026611328, 0.27734375, 0.07910156, -0.09814453, 0.003540039, -0.2109375, -0.0115356445, -0.33007813, -0.1328125, -0.24316406, -0.036865234, 0.1640625, -0.32226563, 0.11816406, 0.19628906, 0.16308594, 0.06982422, -0.07861328, 0.014831543, -0.068359375, -0.15332031, -0.09863281, -0.20019531, -0.115722656, -0.15039063, -0.10644531, -0.07421875, 0.039794922, -0.103515625, -0.011962891, 0.033691406, 0.100097656, 0.10498047, 0.20410156, -0.045898438, -0.34570313, -0.28515625, -0.111328125, 0.33203125, -0.07519531, -0.014831543, 0.19433594, 0.20117188, -0.020996094, -0.044189453, -0.30273438, -0.041748047, 0.09667969, -0.26171875, 0.13671875, 0.045166016, 0.09814453, -0.13476563, -0.15332031, -0.12011719, 0.13574219, 0.29492188, 0.10253906, 0.067871094, 0.034179688, -0.064941406, 0.04663086, 0.13476563, 0.064941406, 0.092285156, -0.10644531, -0.063964844, 0.0154418945, 0.4375, 0.03466797, -0.5078125, 0.020141602, -0.15820313, -0.16601563, 0.025390625, 0.23144531, -0.038085938, 0.16210938, -0.010559082, 0.1171875, -0.0077209473, 0.11669922, 0.046875, -0.063964844, -0.13085938, -0.21777344, 0.13671875, 0.28515625, 0.068359375, -0.12792969, 0.30859375, 0.3984375, -0.14257813, 0.19238281, -0.17285156, 0.034179688, 0.0019226074, 0.15625, -0.019042969, 0.07373047, 0.10888672, -0.08935547, 0.016723633, 0.09033203, -0.16503906, -0.07519531, 0.32617188, 0.057373047, 0.1640625, -0.07519531, -0.27148438, -0.18457031, 0.034179688, 0.2109375, -0.11621094, -0.06933594, -0.103515625, -0.1328125, -0.059814453, -0.23242188, -0.0546875, 0.12451172, -0.22070313, -0.12158203, 0.27734375, 0.067871094, 0.04296875, 0.041015625, 0.05834961, -0.0859375, -0.053466797, -0.034423828, -0.1640625, -0.09277344, -0.30078125, 0.33984375, -0.26171875, -0.030639648, 0.104003906, -0.06298828, 0.071777344, -0.24609375, -0.23046875, -0.018798828, -0.18554688, 0.234375, -0.15527344, -0.22265625, -0.018432617, 0.05102539, 0.018188477, -0.1484375, 0.06640625, 0.07421875, -0.03112793, -0.21582031, 0.13769531, 0.27734375, -0.16699219, 0.122558594, -0.14453125, 0.043945313, 0.087890625, -0.29296875, -0.056884766, -0.072265625, 0.30078125, -0.09667969, -0.001411438, -0.10107422, -0.1875, -0.13378906, -0.04663086, -0.12988281, -0.091308594, -0.017333984, 0.16503906, 0.17089844, 0.118652344, -0.20214844, -0.10205078, 0.3046875, -0.19921875, 0.021240234, -0.15332031, 0.114746094, -0.037353516, -0.01953125, 0.110839844, 0.26367188, -0.01940918, -0.21582031, 0.17675781, 0.103027344, 0.14648438, -0.013183594, -0.27539063, -0.27929688, 0.067871094, -0.21875, 0.53515625, -0.10595703, 0.1796875, -0.05810547, -0.21875, -0.053466797, -0.052734375, -0.15917969, 0.044433594, -0.16796875, -0.033691406, -0.12060547, -0.19628906, 0.07128906, 0.2578125, 0.24414063, 0.16113281, -0.27734375, -0.12988281, 0.045410156, 0.06689453, 0.035888672, 0.014404297, 0.00018978119, 0.076171875, 0.16601563, 0.095703125, -0.07421875, -0.30859375, 0.19042969, -0.024291992, -0.088378906, 0.25390625, -0.004425049, 0.022949219, 0.07080078, 0.107910156, 0.037841797, -0.04272461, -0.29492188, 0.09765625, 0.10839844, -0.14355469, 0.07861328, 0.14160156, -0.15332031, -0.20410156, -0.13476563, -0.140625, 0.111328125, -0.122558594, -0.23730469, -0.09667969, -0.37109375, -0.099121094, -0.23730469, 0.24609375, -0.10058594, 0.064941406, -0.025634766, 0.13574219, 0.07519531]'
+    '{-0.10498047, -0.18261719, 0.099121094, 0.26367188, -0.19628906, -0.20019531, 0.013977051, -0.44140625, 0.044677734, 0.28125, 0.123535156, 0.045410156, 0.16894531, -0.171875, -0.39648438, -0.09277344, -0.34375, 0.2421875, -0.13476563, -0.064941406, -0.056884766, 0.25, 0.036621094, -0.12451172, -0.13476563, -0.0703125, -0.24511719, 0.10498047, -0.014587402, 0.032714844, 0.19042969, 0.18359375, 0.19238281, -0.111816406, 0.028320313, 0.123046875, 0.071777344, -0.078125, -0.026611328, 0.27734375, 0.07910156, -0.09814453, 0.003540039, -0.2109375, -0.0115356445, -0.33007813, -0.1328125, -0.24316406, -0.036865234, 0.1640625, -0.32226563, 0.11816406, 0.19628906, 0.16308594, 0.06982422, -0.07861328, 0.014831543, -0.068359375, -0.15332031, -0.09863281, -0.20019531, -0.115722656, -0.15039063, -0.10644531, -0.07421875, 0.039794922, -0.103515625, -0.011962891, 0.033691406, 0.100097656, 0.10498047, 0.20410156, -0.045898438, -0.34570313, -0.28515625, -0.111328125, 0.33203125, -0.07519531, -0.014831543, 0.19433594, 0.20117188, -0.020996094, -0.044189453, -0.30273438, -0.041748047, 0.09667969, -0.26171875, 0.13671875, 0.045166016, 0.09814453, -0.13476563, -0.15332031, -0.12011719, 0.13574219, 0.29492188, 0.10253906, 0.067871094, 0.034179688, -0.064941406, 0.04663086, 0.13476563, 0.064941406, 0.092285156, -0.10644531, -0.063964844, 0.0154418945, 0.4375, 0.03466797, -0.5078125, 0.020141602, -0.15820313, -0.16601563, 0.025390625, 0.23144531, -0.038085938, 0.16210938, -0.010559082, 0.1171875, -0.0077209473, 0.11669922, 0.046875, -0.063964844, -0.13085938, -0.21777344, 0.13671875, 0.28515625, 0.068359375, -0.12792969, 0.30859375, 0.3984375, -0.14257813, 0.19238281, -0.17285156, 0.034179688, 0.0019226074, 0.15625, -0.019042969, 0.07373047, 0.10888672, -0.08935547, 0.016723633, 0.09033203, -0.16503906, -0.07519531, 0.32617188, 0.057373047, 0.1640625, -0.07519531, -0.27148438, -0.18457031, 0.034179688, 0.2109375, -0.11621094, -0.06933594, -0.103515625, -0.1328125, -0.059814453, -0.23242188, -0.0546875, 0.12451172, -0.22070313, -0.12158203, 0.27734375, 0.067871094, 0.04296875, 0.041015625, 0.05834961, -0.0859375, -0.053466797, -0.034423828, -0.1640625, -0.09277344, -0.30078125, 0.33984375, -0.26171875, -0.030639648, 0.104003906, -0.06298828, 0.071777344, -0.24609375, -0.23046875, -0.018798828, -0.18554688, 0.234375, -0.15527344, -0.22265625, -0.018432617, 0.05102539, 0.018188477, -0.1484375, 0.06640625, 0.07421875, -0.03112793, -0.21582031, 0.13769531, 0.27734375, -0.16699219, 0.122558594, -0.14453125, 0.043945313, 0.087890625, -0.29296875, -0.056884766, -0.072265625, 0.30078125, -0.09667969, -0.001411438, -0.10107422, -0.1875, -0.13378906, -0.04663086, -0.12988281, -0.091308594, -0.017333984, 0.16503906, 0.17089844, 0.118652344, -0.20214844, -0.10205078, 0.3046875, -0.19921875, 0.021240234, -0.15332031, 0.114746094, -0.037353516, -0.01953125, 0.110839844, 0.26367188, -0.01940918, -0.21582031, 0.17675781, 0.103027344, 0.14648438, -0.013183594, -0.27539063, -0.27929688, 0.067871094, -0.21875, 0.53515625, -0.10595703, 0.1796875, -0.05810547, -0.21875, -0.053466797, -0.052734375, -0.15917969, 0.044433594, -0.16796875, -0.033691406, -0.12060547, -0.19628906, 0.07128906, 0.2578125, 0.24414063, 0.16113281, -0.27734375, -0.12988281, 0.045410156, 0.06689453, 0.035888672, 0.014404297, 0.00018978119, 0.076171875, 0.16601563, 0.095703125, -0.07421875, -0.30859375, 0.19042969, -0.024291992, -0.088378906, 0.25390625, -0.004425049, 0.022949219, 0.07080078, 0.107910156, 0.037841797, -0.04272461, -0.29492188, 0.09765625, 0.10839844, -0.14355469, 0.07861328, 0.14160156, -0.15332031, -0.20410156, -0.13476563, -0.140625, 0.111328125, -0.122558594, -0.23730469, -0.09667969, -0.37109375, -0.099121094, -0.23730469, 0.24609375, -0.10058594, 0.064941406, -0.025634766, 0.13574219, 0.07519531}'
   ),
   (
     'pear',
-    '[-0.19335938, -0.016967773, -0.055664063, 0.2109375, -0.084472656, 0.080566406, 0.23535156, -0.24707031, 0.014831543, 0.265625, -0.072265625, -0.040283203, -0.15039063, 0.06933594, -0.030761719, 0.16992188, -0.27734375, 0.09716797, -0.08691406, -0.13867188, -0.17578125, 0.18457031, 0.031982422, -0.22460938, -0.06982422, 0.00982666, -0.24023438, 0.16113281, 0.020507813, 0.1015625, -0.037109375, -0.05517578, 0.22265625, -0.055419922, -0.15917969, -0.0068969727, 0.0625, -0.11816406, 0.15234375, 0.16503906, -0.005554199, -0.18945313, -0.375, -0.1171875, 0.19238281, -0.44335938, 0.107421875, -0.1796875, 0.08154297, -0.032226563, -0.140625, 0.049804688, -0.072753906, 0.06640625, -0.12011719, -0.107421875, 0.040283203, -0.09814453, -0.21484375, -0.33984375, -0.11279297, 0.053466797, -0.35351563, -0.06640625, -0.16699219, -0.35351563, 0.05834961, -0.31445313, 0.31445313, 0.1875, 0.23144531, -0.022583008, 0.099121094, 0.013977051, -0.28125, 0.1875, -0.20410156, -0.055908203, 0.14941406, 0.030029297, -0.1796875, -0.10498047, -0.07080078, -0.20117188, 0.09472656, 0.09765625, 0.072753906, 0.36914063, -0.119628906, -0.043701172, -0.20898438, -0.25585938, -0.021728516, 0.07373047, 0.08300781, -0.33203125, -0.15820313, -0.021728516, 0.05053711, -0.3671875, -0.29492188, -0.26171875, -0.15332031, 0.18359375, 0.05810547, -0.027709961, 0.052978516, 0.21972656, -0.28515625, -0.15625, -0.25195313, -0.37109375, 0.087890625, -0.0703125, 0.08105469, -0.0029754639, 0.14746094, -0.15039063, -0.07910156, -0.10644531, 0.021850586, 0.19335938, -0.033203125, 0.15625, 0.19335938, 0.2265625, -0.25390625, 0.25, 0.08154297, 0.3671875, -0.07080078, 0.05029297, -0.06201172, 0.04711914, -0.123046875, 0.29101563, -0.12597656, 0.09375, 0.08203125, 0.00048828125, -0.14257813, -0.16210938, -0.35742188, -0.028686523, 0.14160156, 0.030639648, -0.035888672, 0.08496094, -0.5234375, -0.0026245117, 0.2421875, 0.024291992, -0.2109375, 0.40625, -0.029052734, -0.11669922, -0.19140625, -0.1171875, -0.14550781, -0.009277344, -0.18359375, -0.017333984, 0.34570313, 0.07080078, 0.029907227, -0.123046875, 0.36914063, -0.056152344, 0.014160156, -0.068847656, -0.22070313, 0.17480469, 0.014221191, 0.022094727, -0.38867188, -0.072265625, 0.39648438, 0.18066406, -0.12890625, 0.08886719, -0.2578125, -0.2734375, -0.24121094, 0.22949219, -0.09814453, -0.012023926, -0.13671875, 0.067871094, 0.059570313, -0.036376953, 0.030883789, 0.083496094, -0.19824219, -0.01586914, 0.27148438, 0.33789063, 0.087402344, -0.056152344, 0.05126953, -0.061523438, -0.08154297, -0.2578125, -0.15332031, 0.22558594, -0.055664063, -0.012512207, 0.0095825195, -0.10205078, 0.06640625, -0.11376953, -0.36523438, -0.20507813, -0.05419922, 0.17675781, -0.099609375, -0.039794922, -0.12695313, 0.0052490234, 0.48632813, 0.34765625, 0.09033203, 0.17480469, -0.123535156, 0.114746094, -0.12597656, -0.0625, 0.1171875, 0.0014266968, -0.005065918, 0.33007813, -0.118652344, -0.24804688, -0.20800781, 0.25390625, -0.104003906, -0.35742188, -0.18847656, -0.21191406, 0.30078125, -0.22460938, 0.26757813, 0.037109375, -0.025024414, -0.003829956, 0.19824219, -0.140625, -0.06738281, 0.041503906, -0.033447266, 0.33398438, -0.13085938, -0.21679688, 0.3359375, -0.29492188, 0.13476563, 0.029174805, -0.107421875, -0.3984375, -0.08642578, -0.002029419, 0.06591797, 0.19140625, -0.27734375, 0.1484375, 0.01928711, -0.234375, -0.3984375, 0.20019531, -0.22949219, 0.031982422, -0.016967773, 0.19140625, -0.0625, -0.10839844, 0.103515625, -0.12792969, 0.13476563, -0.05126953, -0.24023438, 0.34179688, -0.28125, 0.1640625, 0.25, -0.07861328, 0.09375, -0.0859375, -0.13378906, -0.033447266, -0.13867188, 0.44140625, -0.091796875, -0.30078125, 0.23242188, -0.14648438, 0.36523438, -0.071777344, -0.017456055, 0.013183594, 0.071777344, 0.14355469]'
+    '{-0.19335938, -0.016967773, -0.055664063, 0.2109375, -0.084472656, 0.080566406, 0.23535156, -0.24707031, 0.014831543, 0.265625, -0.072265625, -0.040283203, -0.15039063, 0.06933594, -0.030761719, 0.16992188, -0.27734375, 0.09716797, -0.08691406, -0.13867188, -0.17578125, 0.18457031, 0.031982422, -0.22460938, -0.06982422, 0.00982666, -0.24023438, 0.16113281, 0.020507813, 0.1015625, -0.037109375, -0.05517578, 0.22265625, -0.055419922, -0.15917969, -0.0068969727, 0.0625, -0.11816406, 0.15234375, 0.16503906, -0.005554199, -0.18945313, -0.375, -0.1171875, 0.19238281, -0.44335938, 0.107421875, -0.1796875, 0.08154297, -0.032226563, -0.140625, 0.049804688, -0.072753906, 0.06640625, -0.12011719, -0.107421875, 0.040283203, -0.09814453, -0.21484375, -0.33984375, -0.11279297, 0.053466797, -0.35351563, -0.06640625, -0.16699219, -0.35351563, 0.05834961, -0.31445313, 0.31445313, 0.1875, 0.23144531, -0.022583008, 0.099121094, 0.013977051, -0.28125, 0.1875, -0.20410156, -0.055908203, 0.14941406, 0.030029297, -0.1796875, -0.10498047, -0.07080078, -0.20117188, 0.09472656, 0.09765625, 0.072753906, 0.36914063, -0.119628906, -0.043701172, -0.20898438, -0.25585938, -0.021728516, 0.07373047, 0.08300781, -0.33203125, -0.15820313, -0.021728516, 0.05053711, -0.3671875, -0.29492188, -0.26171875, -0.15332031, 0.18359375, 0.05810547, -0.027709961, 0.052978516, 0.21972656, -0.28515625, -0.15625, -0.25195313, -0.37109375, 0.087890625, -0.0703125, 0.08105469, -0.0029754639, 0.14746094, -0.15039063, -0.07910156, -0.10644531, 0.021850586, 0.19335938, -0.033203125, 0.15625, 0.19335938, 0.2265625, -0.25390625, 0.25, 0.08154297, 0.3671875, -0.07080078, 0.05029297, -0.06201172, 0.04711914, -0.123046875, 0.29101563, -0.12597656, 0.09375, 0.08203125, 0.00048828125, -0.14257813, -0.16210938, -0.35742188, -0.028686523, 0.14160156, 0.030639648, -0.035888672, 0.08496094, -0.5234375, -0.0026245117, 0.2421875, 0.024291992, -0.2109375, 0.40625, -0.029052734, -0.11669922, -0.19140625, -0.1171875, -0.14550781, -0.009277344, -0.18359375, -0.017333984, 0.34570313, 0.07080078, 0.029907227, -0.123046875, 0.36914063, -0.056152344, 0.014160156, -0.068847656, -0.22070313, 0.17480469, 0.014221191, 0.022094727, -0.38867188, -0.072265625, 0.39648438, 0.18066406, -0.12890625, 0.08886719, -0.2578125, -0.2734375, -0.24121094, 0.22949219, -0.09814453, -0.012023926, -0.13671875, 0.067871094, 0.059570313, -0.036376953, 0.030883789, 0.083496094, -0.19824219, -0.01586914, 0.27148438, 0.33789063, 0.087402344, -0.056152344, 0.05126953, -0.061523438, -0.08154297, -0.2578125, -0.15332031, 0.22558594, -0.055664063, -0.012512207, 0.0095825195, -0.10205078, 0.06640625, -0.11376953, -0.36523438, -0.20507813, -0.05419922, 0.17675781, -0.099609375, -0.039794922, -0.12695313, 0.0052490234, 0.48632813, 0.34765625, 0.09033203, 0.17480469, -0.123535156, 0.114746094, -0.12597656, -0.0625, 0.1171875, 0.0014266968, -0.005065918, 0.33007813, -0.118652344, -0.24804688, -0.20800781, 0.25390625, -0.104003906, -0.35742188, -0.18847656, -0.21191406, 0.30078125, -0.22460938, 0.26757813, 0.037109375, -0.025024414, -0.003829956, 0.19824219, -0.140625, -0.06738281, 0.041503906, -0.033447266, 0.33398438, -0.13085938, -0.21679688, 0.3359375, -0.29492188, 0.13476563, 0.029174805, -0.107421875, -0.3984375, -0.08642578, -0.002029419, 0.06591797, 0.19140625, -0.27734375, 0.1484375, 0.01928711, -0.234375, -0.3984375, 0.20019531, -0.22949219, 0.031982422, -0.016967773, 0.19140625, -0.0625, -0.10839844, 0.103515625, -0.12792969, 0.13476563, -0.05126953, -0.24023438, 0.34179688, -0.28125, 0.1640625, 0.25, -0.07861328, 0.09375, -0.0859375, -0.13378906, -0.033447266, -0.13867188, 0.44140625, -0.091796875, -0.30078125, 0.23242188, -0.14648438, 0.36523438, -0.071777344, -0.017456055, 0.013183594, 0.071777344, 0.14355469}'
   );
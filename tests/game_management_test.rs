@@ -11,6 +11,7 @@ use similarium::{
 fn get_test_command_payload() -> CommandPayload {
     CommandPayload {
         team_id: "invalid_team_id".to_string(),
+        enterprise_id: None,
         channel_id: "invalid_channel_id".to_string(),
         user_id: "invalid_user_id".to_string(),
         text: "invalid_text".to_string(),
@@ -199,6 +200,32 @@ async fn test_schedule_game_on_channel_creates_channel_if_it_doesnt_exist(
     Ok(())
 }
 
+#[sqlx::test(fixtures("channel"))]
+async fn test_schedule_game_on_channel_stores_enterprise_of_channel(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let now = chrono::NaiveTime::from_hms_opt(0, 0, 0).unwrap();
+    let mut payload = get_test_command_payload();
+    payload.enterprise_id = Some("enterprise_id".to_string());
+
+    let mut mock_slack_client = MockSlackClient::new();
+    mock_slack_client
+        .expect_get_user_details()
+        .returning(|_, _| Ok(get_test_user_info_response()));
+    mock_slack_client
+        .expect_post_message()
+        .returning(|_, _, _, _| Ok(serde_json::Value::Null));
+
+    schedule_game_on_channel(&pool, &mock_slack_client, &payload, "token", now).await?;
+
+    let channel = Channel::get(payload.channel_id.as_str(), &pool)
+        .await?
+        .expect("Channel should have been created");
+    assert_eq!(channel.enterprise_id.as_deref(), Some("enterprise_id"));
+
+    Ok(())
+}
+
 #[sqlx::test(fixtures("channel"))]
 async fn test_schedule_game_on_channel_updates_channel_if_it_exists(
     pool: sqlx::PgPool,
@@ -231,3 +258,43 @@ async fn test_schedule_game_on_channel_updates_channel_if_it_exists(
 
     Ok(())
 }
+
+#[sqlx::test(fixtures("channel"))]
+async fn test_schedule_game_on_channel_stores_local_time_and_timezone(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let nine_am = chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+    let payload = get_test_command_payload();
+
+    let mut mock_slack_client = MockSlackClient::new();
+    mock_slack_client
+        .expect_get_user_details()
+        .returning(|_, _| {
+            let mut response = get_test_user_info_response();
+            if let Some(user) = response.user.as_mut() {
+                user.tz = "Europe/London".to_string();
+                user.tz_offset = 3600;
+            }
+            Ok(response)
+        });
+    mock_slack_client
+        .expect_post_message()
+        .returning(|message, _, _, _| {
+            assert_eq!(
+                message,
+                "<@user_id> has started a daily game of Similarium in the morning at 09:00 Europe/London"
+            );
+            Ok(serde_json::Value::Null)
+        });
+
+    schedule_game_on_channel(&pool, &mock_slack_client, &payload, "token", nine_am).await?;
+
+    let channel = Channel::get(payload.channel_id.as_str(), &pool)
+        .await?
+        .unwrap();
+    assert_eq!(channel.hour, 9);
+    assert_eq!(channel.minute, 0);
+    assert_eq!(channel.timezone, "Europe/London");
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use fang::{asynk::async_queue::AsyncQueue, NoTls};
+use mockall::mock;
+use similarium::{
+    game::updates::GameMessageUpdater,
+    models::Game,
+    slack_client::{Block, SlackMessage},
+    SimilariumError,
+};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use uuid::Uuid;
+
+mock! {
+    SlackClient {}
+
+    impl SlackMessage for SlackClient {
+        async fn post_message(
+            &self,
+            text: &str,
+            channel_id: &str,
+            token: &str,
+            blocks: Option<Vec<Block>>,
+        ) -> Result<serde_json::Value, SimilariumError>;
+
+        async fn post_ephemeral(
+            &self,
+            text: &str,
+            channel_id: &str,
+            user_id: &str,
+            token: &str,
+            blocks: Option<Vec<Block>>,
+        ) -> Result<serde_json::Value, SimilariumError>;
+
+        async fn chat_update(
+            &self,
+            text: &str,
+            channel_id: &str,
+            message_ts: &str,
+            token: &str,
+            blocks: Option<Vec<Block>>,
+        ) -> Result<serde_json::Value, SimilariumError>;
+    }
+}
+
+/// A queue that isn't connected, which is fine as long as nothing is rate limited
+fn get_test_queue() -> AsyncQueue<NoTls> {
+    AsyncQueue::builder()
+        .uri("postgres://localhost/unused")
+        .max_pool_size(1_u32)
+        .build()
+}
+
+async fn get_game(pool: &sqlx::PgPool) -> Result<Game, SimilariumError> {
+    let game_id: Uuid = Uuid::parse_str("00000001-0000-4000-a000-000000000000").unwrap();
+    let mut game = Game::get_by_id(game_id, pool).await?.unwrap();
+    game.set_thread_ts("123456.789012", pool).await?;
+
+    Ok(game)
+}
+
+#[sqlx::test(fixtures("channel", "games"))]
+async fn test_game_message_updater_coalesces_a_burst_of_updates(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let game = get_game(&pool).await?;
+
+    // Count the updates, rather than expecting them, as a panic in the spawned update is lost
+    let updates = Arc::new(AtomicUsize::new(0));
+    let counter = updates.clone();
+    let mut mock_slack_client = MockSlackClient::new();
+    mock_slack_client
+        .expect_chat_update()
+        .withf(|_, channel_id, message_ts, token, blocks| {
+            channel_id == "channel_id"
+                && message_ts == "123456.789012"
+                && token == "token"
+                && blocks.is_some()
+        })
+        .returning(move |_, _, _, _, _| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Ok(serde_json::json!({"ok": true}))
+        });
+
+    let updater = Arc::new(GameMessageUpdater::new(
+        Duration::from_millis(50),
+        pool.clone(),
+        mock_slack_client,
+        get_test_queue(),
+    ));
+
+    tokio::task::LocalSet::new()
+        .run_until(async {
+            // A burst of guesses only sends one update
+            for _ in 0..5 {
+                updater.request_update(&game, "token");
+            }
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            assert_eq!(updates.load(Ordering::SeqCst), 1);
+
+            // A guess after the update went out sends another one
+            updater.request_update(&game, "token");
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            assert_eq!(updates.load(Ordering::SeqCst), 2);
+        })
+        .await;
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "games"))]
+async fn test_game_message_updater_flush_sends_the_update_straight_away(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let game = get_game(&pool).await?;
+
+    let mut mock_slack_client = MockSlackClient::new();
+    mock_slack_client
+        .expect_chat_update()
+        .times(1)
+        .returning(|_, _, _, _, _| Ok(serde_json::json!({"ok": true})));
+
+    let updater = GameMessageUpdater::new(
+        Duration::from_secs(60),
+        pool.clone(),
+        mock_slack_client,
+        get_test_queue(),
+    );
+
+    updater.flush(game.id, "token").await?;
+
+    Ok(())
+}
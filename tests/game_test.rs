@@ -1,4 +1,5 @@
 use similarium::models::{Game, Guess, GuessContextOrder, Word2Vec};
+use similarium::similarity::PostgresBackend;
 use similarium::SimilariumError;
 use uuid::Uuid;
 
@@ -14,9 +15,10 @@ fn test_adding_guess_to_game(pool: sqlx::PgPool) -> Result<(), SimilariumError>
     let secret = Word2Vec {
         word: game.secret.clone(),
     };
-    secret.create_materialised_view(&pool).await?;
+    let backend = PostgresBackend::new(pool.clone());
+    secret.compute_similarities(&backend).await?;
 
-    let similarity = secret.get_similarity(guess, &pool).await?;
+    let similarity = secret.get_similarity(guess, &backend).await?;
 
     let guess = Guess {
         id: Uuid::new_v4(),
@@ -1,6 +1,7 @@
-use similarium::game::submit_guess;
+use similarium::game::{get_suggestion_game, submit_guess};
 use similarium::models::{Game, User, Word2Vec};
-use similarium::SimilariumError;
+use similarium::similarity::PostgresBackend;
+use similarium::{SimilariumError, SimilariumErrorType};
 use uuid::Uuid;
 
 #[sqlx::test(fixtures("channel", "games", "users", "words"))]
@@ -15,16 +16,73 @@ fn test_submitting_guess_to_game_preprocesses_guesses(
     let secret = Word2Vec {
         word: game.secret.clone(),
     };
-    secret.create_materialised_view(&pool).await?;
+    let similarity = PostgresBackend::new(pool.clone());
+    secret.compute_similarities(&similarity).await?;
 
-    let guess = submit_guess(&user, &game, "fruit", &pool).await?;
+    let guess = submit_guess(&user, &game, "fruit", &pool, &similarity).await?;
     assert_eq!(guess.word, "fruit");
 
-    let guess = submit_guess(&user, &game, "Happy", &pool).await?;
+    let guess = submit_guess(&user, &game, "Happy", &pool, &similarity).await?;
     assert_eq!(guess.word, "happy");
 
-    let guess = submit_guess(&user, &game, " PEAR ", &pool).await?;
+    let guess = submit_guess(&user, &game, " PEAR ", &pool, &similarity).await?;
     assert_eq!(guess.word, "pear");
 
     Ok(())
 }
+
+#[sqlx::test(fixtures("channel", "games", "users", "words"))]
+fn test_submitting_guess_falls_back_to_lemma_in_vocabulary(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let game_id: Uuid = Uuid::parse_str("00000001-0000-4000-a000-000000000000").unwrap();
+    let game = Game::get_by_id(game_id, &pool).await?.unwrap();
+
+    let user = User::get("user_id_1", &pool).await?.unwrap();
+
+    let secret = Word2Vec {
+        word: game.secret.clone(),
+    };
+    let similarity = PostgresBackend::new(pool.clone());
+    secret.compute_similarities(&similarity).await?;
+
+    let guess = submit_guess(&user, &game, "Apples", &pool, &similarity).await?;
+    assert_eq!(guess.word, "apple");
+
+    let guess = submit_guess(&user, &game, "happier", &pool, &similarity).await?;
+    assert_eq!(guess.word, "happy");
+
+    // A plural of the secret that isn't in the vocabulary counts as the secret
+    let guess = submit_guess(&user, &game, "secrets", &pool, &similarity).await?;
+    assert!(guess.is_secret());
+
+    let err = submit_guess(&user, &game, "appletinis", &pool, &similarity)
+        .await
+        .unwrap_err();
+    assert_eq!(err.error_type, SimilariumErrorType::NotFound);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "games"))]
+fn test_suggestions_only_guess_on_active_games_on_the_channel(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let active = "00000001-0000-4000-a000-000000000000:pear";
+    let (game, word) = get_suggestion_game(active, "channel_id", &pool).await?;
+    assert_eq!(game.unwrap().secret, "secret");
+    assert_eq!(word, "pear");
+
+    let (game, _) = get_suggestion_game(active, "other_channel", &pool).await?;
+    assert!(game.is_none());
+
+    let ended = "00000002-0000-4000-a000-000000000000:pear";
+    let (game, _) = get_suggestion_game(ended, "channel_id", &pool).await?;
+    assert!(game.is_none());
+
+    let missing = "00000009-0000-4000-a000-000000000000:pear";
+    let (game, _) = get_suggestion_game(missing, "channel_id", &pool).await?;
+    assert!(game.is_none());
+
+    Ok(())
+}
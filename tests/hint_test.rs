@@ -0,0 +1,219 @@
+use fang::{asynk::async_queue::AsyncQueue, NoTls};
+use mockall::mock;
+use similarium::game::{request_hint, reveal_hint, submit_guess};
+use similarium::models::{Game, User, Word2Vec};
+use similarium::similarity::PostgresBackend;
+use similarium::slack_client::{Block, SlackMessage};
+use similarium::{SimilariumError, SimilariumErrorType};
+use uuid::Uuid;
+
+mock! {
+    SlackClient {}
+
+    impl SlackMessage for SlackClient {
+        async fn post_message(
+            &self,
+            text: &str,
+            channel_id: &str,
+            token: &str,
+            blocks: Option<Vec<Block>>,
+        ) -> Result<serde_json::Value, SimilariumError>;
+
+        async fn post_ephemeral(
+            &self,
+            text: &str,
+            channel_id: &str,
+            user_id: &str,
+            token: &str,
+            blocks: Option<Vec<Block>>,
+        ) -> Result<serde_json::Value, SimilariumError>;
+
+        async fn chat_update(
+            &self,
+            text: &str,
+            channel_id: &str,
+            message_ts: &str,
+            token: &str,
+            blocks: Option<Vec<Block>>,
+        ) -> Result<serde_json::Value, SimilariumError>;
+    }
+}
+
+async fn get_game(pool: &sqlx::PgPool) -> Result<(Game, PostgresBackend), SimilariumError> {
+    let game_id: Uuid = Uuid::parse_str("00000001-0000-4000-a000-000000000000").unwrap();
+    let game = Game::get_by_id(game_id, pool).await?.unwrap();
+
+    let secret = Word2Vec {
+        word: game.secret.clone(),
+    };
+    let similarity = PostgresBackend::new(pool.clone());
+    secret.compute_similarities(&similarity).await?;
+
+    Ok((game, similarity))
+}
+
+#[sqlx::test(fixtures("channel", "games", "users", "words"))]
+fn test_hint_is_halfway_between_best_guess_and_secret(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let (game, similarity) = get_game(&pool).await?;
+    let user = User::get("user_id_1", &pool).await?.unwrap();
+
+    // Find the word furthest from the secret, so there is room for a hint
+    let secret = Word2Vec {
+        word: game.secret.clone(),
+    };
+    let furthest = secret.get_word_at_rank(i64::MAX, &similarity).await?;
+    let guess = submit_guess(&user, &game, &furthest.word, &pool, &similarity).await?;
+
+    let hint = reveal_hint(&game, &user.id, &pool, &similarity).await?;
+    assert_eq!(hint.rank, guess.rank / 2);
+    assert_eq!(
+        hint.word,
+        secret
+            .get_word_at_rank(guess.rank / 2, &similarity)
+            .await?
+            .word
+    );
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "games", "users", "words"))]
+fn test_hint_without_guesses_never_reveals_the_secret(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let (game, similarity) = get_game(&pool).await?;
+
+    let hint = reveal_hint(&game, "user_id_1", &pool, &similarity).await?;
+    assert!(hint.rank > 0);
+    assert_ne!(hint.word, game.secret);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "games", "users", "words"))]
+fn test_hint_can_only_be_used_once_per_game(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let (game, similarity) = get_game(&pool).await?;
+
+    assert!(!game.user_already_hinted("user_id_1", &pool).await?);
+    reveal_hint(&game, "user_id_1", &pool, &similarity).await?;
+    assert!(game.user_already_hinted("user_id_1", &pool).await?);
+    assert_eq!(game.get_hinted_user_ids(&pool).await?, vec!["user_id_1"]);
+
+    let err = reveal_hint(&game, "user_id_1", &pool, &similarity)
+        .await
+        .unwrap_err();
+    assert_eq!(err.error_type, SimilariumErrorType::ValidationError);
+
+    // Other users still get their own hint
+    reveal_hint(&game, "user_id_2", &pool, &similarity).await?;
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "games", "users", "words"))]
+fn test_only_one_of_two_hints_at_the_same_time_is_given(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let (game, similarity) = get_game(&pool).await?;
+
+    let request_hint = |pool: sqlx::PgPool, similarity: PostgresBackend| {
+        tokio::spawn(async move {
+            let game = Game::get_by_id(game.id, &pool).await?.unwrap();
+            reveal_hint(&game, "user_id_1", &pool, &similarity).await
+        })
+    };
+    let first = request_hint(pool.clone(), similarity.clone());
+    let second = request_hint(pool.clone(), similarity.clone());
+
+    let mut errors = vec![];
+    for request in [first, second] {
+        if let Err(e) = request.await? {
+            errors.push(e);
+        }
+    }
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].error_type, SimilariumErrorType::ValidationError);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "games", "users", "words"))]
+fn test_no_hint_when_too_close_to_the_secret(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let (game, similarity) = get_game(&pool).await?;
+    let user = User::get("user_id_1", &pool).await?.unwrap();
+
+    let secret = Word2Vec {
+        word: game.secret.clone(),
+    };
+    let closest = secret.get_word_at_rank(1, &similarity).await?;
+    submit_guess(&user, &game, &closest.word, &pool, &similarity).await?;
+
+    let err = reveal_hint(&game, &user.id, &pool, &similarity)
+        .await
+        .unwrap_err();
+    assert_eq!(err.error_type, SimilariumErrorType::ValidationError);
+    assert!(!game.user_already_hinted(&user.id, &pool).await?);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "games", "users", "words"))]
+fn test_no_hint_for_winners_or_ended_games(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let (game, similarity) = get_game(&pool).await?;
+
+    game.add_winner("user_id_1", 1, &pool).await?;
+    assert!(reveal_hint(&game, "user_id_1", &pool, &similarity)
+        .await
+        .is_err());
+
+    let game_id: Uuid = Uuid::parse_str("00000002-0000-4000-a000-000000000000").unwrap();
+    let ended_game = Game::get_by_id(game_id, &pool).await?.unwrap();
+    assert!(reveal_hint(&ended_game, "user_id_2", &pool, &similarity)
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+/// A queue that isn't connected, so the clue can't be queued
+fn get_test_queue() -> AsyncQueue<NoTls> {
+    AsyncQueue::builder()
+        .uri("postgres://localhost/unused")
+        .max_pool_size(1_u32)
+        .build()
+}
+
+#[sqlx::test(fixtures("channel", "games", "users", "words"))]
+fn test_request_hint_posts_the_hint_without_waiting_for_the_clue(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let (game, similarity) = get_game(&pool).await?;
+
+    // The clue is queued for the model to write, so only the hint is posted straight away
+    let mut slack_client = MockSlackClient::new();
+    slack_client
+        .expect_post_ephemeral()
+        .withf(|text, _, user_id, _, _| {
+            user_id == "user_id_1" && text.starts_with(":bulb: Your hint:") && !text.contains('\n')
+        })
+        .times(1)
+        .returning(|_, _, _, _, _| Ok(serde_json::json!({"ok": true})));
+
+    // Failing to queue the clue doesn't fail the hint
+    request_hint(
+        &pool,
+        &similarity,
+        &slack_client,
+        &mut get_test_queue(),
+        &game,
+        "user_id_1",
+        "xoxb-token",
+    )
+    .await?;
+
+    assert!(game.user_already_hinted("user_id_1", &pool).await?);
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use similarium::game::home::{get_home_dashboard, ChannelStreak};
+use similarium::models::{Channel, Game};
+use similarium::SimilariumError;
+use uuid::Uuid;
+
+#[sqlx::test(fixtures("channel", "games", "users", "guesses"))]
+async fn test_home_dashboard_shows_best_guess_in_active_games(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let dashboard = get_home_dashboard("user_id_3", None, "team_id", &pool).await?;
+
+    assert_eq!(dashboard.games.len(), 1);
+    let game = &dashboard.games[0];
+    assert_eq!(game.channel_id, "channel_id");
+    assert_eq!(game.puzzle_number, 3);
+    assert_eq!(game.best_guess.as_ref().unwrap().word, "fruit");
+    assert_eq!(game.best_guess.as_ref().unwrap().rank, 30);
+    assert!(!game.won);
+
+    assert_eq!(
+        dashboard.streaks,
+        vec![ChannelStreak {
+            channel_id: "channel_id".to_string(),
+            current: 0,
+            longest: 0,
+        }]
+    );
+    assert!(dashboard.recent_wins.is_empty());
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "games", "users", "guesses"))]
+async fn test_home_dashboard_counts_guesses_made_by_someone_else_first(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    // user_id_1 guessed "happy" after user_id_3 had already guessed it
+    let dashboard = get_home_dashboard("user_id_1", None, "team_id", &pool).await?;
+
+    assert_eq!(dashboard.games.len(), 1);
+    assert_eq!(
+        dashboard.games[0].best_guess.as_ref().unwrap().word,
+        "happy"
+    );
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "games", "users", "guesses"))]
+async fn test_home_dashboard_lists_recent_wins(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let game_id = Uuid::parse_str("00000001-0000-4000-a000-000000000000").unwrap();
+    let game = Game::get_by_id(game_id, &pool).await?.unwrap();
+    game.add_winner("user_id_3", 7, &pool).await?;
+
+    let dashboard = get_home_dashboard("user_id_3", None, "team_id", &pool).await?;
+
+    assert!(dashboard.games[0].won);
+    assert_eq!(dashboard.streaks[0].current, 1);
+    assert_eq!(dashboard.streaks[0].longest, 1);
+    assert_eq!(dashboard.recent_wins.len(), 1);
+    assert_eq!(dashboard.recent_wins[0].puzzle_number, 3);
+    assert_eq!(dashboard.recent_wins[0].guess_idx, 7);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "games", "users", "guesses"))]
+async fn test_home_dashboard_only_includes_the_users_team(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let dashboard = get_home_dashboard("user_id_3", None, "other_team_id", &pool).await?;
+
+    assert!(dashboard.games.is_empty());
+    assert!(dashboard.streaks.is_empty());
+    assert!(dashboard.recent_wins.is_empty());
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "games", "users", "guesses"))]
+async fn test_home_dashboard_includes_channels_shared_in_the_org(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let mut channel = Channel::get("channel_id", &pool).await?.unwrap();
+    channel.enterprise_id = Some("enterprise_id".to_string());
+    channel.update(&pool).await?;
+
+    let dashboard =
+        get_home_dashboard("user_id_3", Some("enterprise_id"), "other_team_id", &pool).await?;
+
+    assert_eq!(dashboard.games.len(), 1);
+    assert_eq!(dashboard.streaks.len(), 1);
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use similarium::importer::{import_vectors, ImportOptions, ImportSummary, VECTOR_DIMENSIONS};
+use similarium::SimilariumError;
+use std::io::Write;
+use std::path::PathBuf;
+
+fn write_vectors_file(name: &str, words: &[&str]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("{}-{}.vec", name, uuid::Uuid::new_v4()));
+    let mut file = std::fs::File::create(&path).unwrap();
+    writeln!(file, "{} {}", words.len(), VECTOR_DIMENSIONS).unwrap();
+    for (idx, word) in words.iter().enumerate() {
+        let values = (0..VECTOR_DIMENSIONS)
+            .map(|i| ((i + idx) as f32 / 100.0).to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        writeln!(file, "{} {}", word, values).unwrap();
+    }
+    path
+}
+
+fn write_word_list(name: &str, words: &[&str]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("{}-{}.txt", name, uuid::Uuid::new_v4()));
+    std::fs::write(&path, words.join("\n")).unwrap();
+    path
+}
+
+#[sqlx::test]
+async fn test_import_vectors_filters_words(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let path = write_vectors_file("filters", &["apple", "pear", "Banana", "darn"]);
+    let mut options = ImportOptions::new(path);
+    options.allow_list = Some(write_word_list("allow", &["apple", "pear", "darn"]));
+    options.deny_list = Some(write_word_list("deny", &["darn"]));
+
+    let summary = import_vectors(&options, &pool).await?;
+
+    assert_eq!(
+        summary,
+        ImportSummary {
+            read: 4,
+            inserted: 2,
+            existing: 0,
+            filtered: 2,
+        }
+    );
+
+    let words: Vec<String> = sqlx::query_scalar("SELECT word FROM word2vec ORDER BY word")
+        .fetch_all(&pool)
+        .await?;
+    assert_eq!(words, vec!["apple", "pear"]);
+
+    Ok(())
+}
+
+#[sqlx::test]
+async fn test_import_vectors_can_be_resumed(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let partial = write_vectors_file("partial", &["apple", "pear"]);
+    import_vectors(&ImportOptions::new(partial), &pool).await?;
+
+    let full = write_vectors_file("full", &["apple", "pear", "plum"]);
+    let mut options = ImportOptions::new(full);
+    options.batch_size = 1;
+
+    let summary = import_vectors(&options, &pool).await?;
+
+    assert_eq!(
+        summary,
+        ImportSummary {
+            read: 3,
+            inserted: 1,
+            existing: 2,
+            filtered: 0,
+        }
+    );
+
+    let count: i64 = sqlx::query_scalar("SELECT count(*) FROM word2vec")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(count, 3);
+
+    Ok(())
+}
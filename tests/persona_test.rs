@@ -0,0 +1,116 @@
+use similarium::game::personas::{get_current_persona, import_personas, use_persona};
+use similarium::models::{Channel, Persona};
+use similarium::{SimilariumError, SimilariumErrorType};
+
+async fn get_channel(pool: &sqlx::PgPool) -> Result<Channel, SimilariumError> {
+    Ok(Channel::get("channel_id", pool).await?.unwrap())
+}
+
+#[sqlx::test]
+fn test_personas_are_seeded(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let names = Persona::get_all(&pool)
+        .await?
+        .into_iter()
+        .map(|persona| persona.name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["deadpan", "friendly", "witty"]);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel"))]
+fn test_channel_uses_default_persona(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let channel = get_channel(&pool).await?;
+    assert_eq!(channel.persona, None);
+    assert!(channel.ai_enabled);
+    assert_eq!(get_current_persona(&channel), Some("witty"));
+
+    let persona = channel.get_persona(&pool).await?;
+    assert_eq!(persona.name, "witty");
+    assert!(persona.taunt_prompt.contains("{participants}"));
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel"))]
+fn test_use_persona_and_turn_off(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    use_persona("channel_id", Some("friendly"), &pool).await?;
+    let channel = get_channel(&pool).await?;
+    assert_eq!(channel.persona, Some("friendly".to_string()));
+    assert_eq!(channel.get_persona(&pool).await?.name, "friendly");
+
+    use_persona("channel_id", None, &pool).await?;
+    let channel = get_channel(&pool).await?;
+    assert!(!channel.ai_enabled);
+    assert_eq!(get_current_persona(&channel), None);
+
+    // Choosing a persona turns the AI messages back on
+    use_persona("channel_id", Some("deadpan"), &pool).await?;
+    let channel = get_channel(&pool).await?;
+    assert!(channel.ai_enabled);
+    assert_eq!(get_current_persona(&channel), Some("deadpan"));
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel"))]
+fn test_use_persona_requires_existing_persona(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let err = use_persona("channel_id", Some("grumpy"), &pool)
+        .await
+        .unwrap_err();
+    assert_eq!(err.error_type, SimilariumErrorType::ValidationError);
+    assert_eq!(get_channel(&pool).await?.persona, None);
+
+    let err = use_persona("other_channel", Some("witty"), &pool)
+        .await
+        .unwrap_err();
+    assert_eq!(err.error_type, SimilariumErrorType::ValidationError);
+
+    Ok(())
+}
+
+fn get_pirate(description: &str) -> Persona {
+    Persona {
+        name: "pirate".to_string(),
+        description: description.to_string(),
+        celebration_prompt: "Celebrate {user} like a pirate".to_string(),
+        taunt_prompt: "Taunt like a pirate".to_string(),
+        win_prompt: "Announce {user} won like a pirate".to_string(),
+    }
+}
+
+#[sqlx::test(fixtures("channel"))]
+fn test_custom_persona_can_be_imported(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    import_personas(&[get_pirate("Arr")], &pool).await?;
+    use_persona("channel_id", Some("pirate"), &pool).await?;
+
+    // Importing it again replaces the prompts
+    import_personas(&[get_pirate("Yo ho ho")], &pool).await?;
+    assert_eq!(
+        get_channel(&pool)
+            .await?
+            .get_persona(&pool)
+            .await?
+            .description,
+        "Yo ho ho"
+    );
+
+    Ok(())
+}
+
+#[sqlx::test]
+fn test_import_personas_rejects_invalid_personas(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let mut off = get_pirate("");
+    off.name = "off".to_string();
+
+    let err = import_personas(&[get_pirate("Arr"), off], &pool)
+        .await
+        .unwrap_err();
+    assert_eq!(err.error_type, SimilariumErrorType::ValidationError);
+    // None of them are imported
+    assert!(Persona::get("pirate", &pool).await?.is_none());
+
+    Ok(())
+}
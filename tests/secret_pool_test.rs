@@ -0,0 +1,115 @@
+use similarium::game::secret_pools::use_secret_pool;
+use similarium::game::secrets::{get_channel_secret, get_secret};
+use similarium::models::{Channel, SecretPool, Word2Vec};
+use similarium::{SimilariumError, SimilariumErrorType};
+
+async fn get_channel(pool: &sqlx::PgPool) -> Result<Channel, SimilariumError> {
+    Ok(Channel::get("channel_id", pool).await?.unwrap())
+}
+
+#[sqlx::test(fixtures("secret_pools"))]
+fn test_get_summaries(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let summaries = SecretPool::get_summaries(&pool).await?;
+
+    let counts = summaries
+        .iter()
+        .map(|summary| (summary.name.as_str(), summary.word_count))
+        .collect::<Vec<_>>();
+    assert_eq!(counts, vec![("empty", 0), ("fruit", 3)]);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "secret_pools"))]
+fn test_use_secret_pool(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    use_secret_pool("channel_id", "fruit", &pool).await?;
+    assert_eq!(
+        get_channel(&pool).await?.secret_pool,
+        Some("fruit".to_string())
+    );
+
+    use_secret_pool("channel_id", "default", &pool).await?;
+    assert_eq!(get_channel(&pool).await?.secret_pool, None);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "secret_pools"))]
+fn test_use_secret_pool_requires_pool_with_words(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    for name in ["empty", "notapool"] {
+        let err = use_secret_pool("channel_id", name, &pool)
+            .await
+            .unwrap_err();
+        assert_eq!(err.error_type, SimilariumErrorType::ValidationError);
+    }
+    assert_eq!(get_channel(&pool).await?.secret_pool, None);
+
+    let err = use_secret_pool("other_channel", "fruit", &pool)
+        .await
+        .unwrap_err();
+    assert_eq!(err.error_type, SimilariumErrorType::ValidationError);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "secret_pools"))]
+fn test_get_channel_secret_draws_from_pool(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let channel = get_channel(&pool).await?;
+    assert_eq!(
+        get_channel_secret(&channel, 7, &pool).await?,
+        get_secret("channel_id", 7)
+    );
+
+    use_secret_pool("channel_id", "fruit", &pool).await?;
+    let channel = get_channel(&pool).await?;
+
+    let mut secrets = vec![];
+    for puzzle_number in 0..3 {
+        secrets.push(get_channel_secret(&channel, puzzle_number, &pool).await?);
+    }
+    // The next cycle starts over in the same order
+    assert_eq!(get_channel_secret(&channel, 3, &pool).await?, secrets[0]);
+
+    secrets.sort();
+    assert_eq!(secrets, vec!["apple", "orange", "pear"]);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "secret_pools"))]
+fn test_deleted_pool_falls_back_to_target_words(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    use_secret_pool("channel_id", "fruit", &pool).await?;
+    sqlx::query("DELETE FROM secret_pool WHERE name = 'fruit'")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let channel = get_channel(&pool).await?;
+    assert_eq!(channel.secret_pool, None);
+    assert_eq!(
+        get_channel_secret(&channel, 0, &pool).await?,
+        get_secret("channel_id", 0)
+    );
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("words"))]
+fn test_add_words_to_pool_skips_duplicates(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let words = vec!["apple".to_string(), "notaword".to_string()];
+    let known = Word2Vec::get_known_words(&words, &pool).await?;
+    assert_eq!(known, vec!["apple"]);
+
+    let secret_pool = SecretPool {
+        name: "fruit".to_string(),
+        description: "".to_string(),
+    };
+    secret_pool.upsert(&pool).await?;
+    assert_eq!(secret_pool.add_words(&known, &pool).await?, 1);
+    assert_eq!(secret_pool.add_words(&known, &pool).await?, 0);
+    assert_eq!(secret_pool.get_words(&pool).await?, vec!["apple"]);
+
+    Ok(())
+}
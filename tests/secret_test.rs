@@ -0,0 +1,93 @@
+use similarium::game::secret_pools::use_secret_pool;
+use similarium::game::secrets::{
+    choose_secret, get_channel_secret, get_secret, get_upcoming_secrets, record_secret,
+};
+use similarium::models::{Channel, ChannelSecret};
+use similarium::SimilariumError;
+
+#[sqlx::test(fixtures("channel", "secret_pools"))]
+fn test_choose_secret_skips_recorded_secrets(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    use_secret_pool("channel_id", "fruit", &pool).await?;
+    let channel = Channel::get("channel_id", &pool).await?.unwrap();
+
+    let mut secrets = vec![];
+    for puzzle_number in 0..3 {
+        let choice = choose_secret(&channel, puzzle_number, &pool).await?;
+        assert_eq!(choice.remaining, 2 - puzzle_number as usize);
+        assert!(!secrets.contains(&choice.secret));
+
+        record_secret(&channel.id, &choice.secret, puzzle_number, &pool).await?;
+        secrets.push(choice.secret);
+    }
+
+    // With the pool used up, the secret played longest ago is picked again
+    let choice = choose_secret(&channel, 3, &pool).await?;
+    assert_eq!(choice.secret, secrets[0]);
+    assert_eq!(choice.remaining, 0);
+
+    record_secret(&channel.id, &choice.secret, 3, &pool).await?;
+    let used = ChannelSecret::get_used(&channel.id, &pool).await?;
+    assert_eq!(used.len(), 3);
+    assert_eq!(used[&secrets[0]], 3);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "games"))]
+fn test_choose_secret_without_history_is_unchanged(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let channel = Channel::get("channel_id", &pool).await?.unwrap();
+
+    assert_eq!(
+        choose_secret(&channel, 12, &pool).await?.secret,
+        get_secret("channel_id", 12)
+    );
+
+    // Once recorded, the same puzzle number gets a different secret
+    record_secret(&channel.id, &get_secret("channel_id", 12), 12, &pool).await?;
+    assert_ne!(
+        choose_secret(&channel, 12, &pool).await?.secret,
+        get_secret("channel_id", 12)
+    );
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "games"))]
+fn test_get_upcoming_secrets_gets_the_next_game_of_active_channels(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let mut channel = Channel::get("channel_id", &pool).await?.unwrap();
+    // The last game in the fixtures is puzzle number 3
+    let next_secret = get_channel_secret(&channel, 4, &pool).await?;
+    assert_eq!(get_upcoming_secrets(&pool).await?, vec![next_secret]);
+
+    channel.active = false;
+    channel.update(&pool).await?;
+    assert_eq!(get_upcoming_secrets(&pool).await?, Vec::<String>::new());
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "games", "secret_pools"))]
+fn test_get_upcoming_secrets_skips_channels_without_words(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    use_secret_pool("channel_id", "fruit", &pool).await?;
+    let channel = Channel::get("channel_id", &pool).await?.unwrap();
+    record_secret(&channel.id, "apple", 3, &pool).await?;
+    Channel {
+        id: "empty_channel_id".to_string(),
+        secret_pool: Some("empty".to_string()),
+        ..Channel::get("channel_id", &pool).await?.unwrap()
+    }
+    .insert(&pool)
+    .await?;
+
+    let next_secret = choose_secret(&channel, 4, &pool).await?.secret;
+    assert_ne!(next_secret, "apple");
+    assert_eq!(get_upcoming_secrets(&pool).await?, vec![next_secret]);
+
+    Ok(())
+}
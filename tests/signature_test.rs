@@ -0,0 +1,106 @@
+use actix_web::{
+    http::StatusCode,
+    test::{self, TestRequest},
+    web, App, HttpResponse,
+};
+use similarium::api::{compute_slack_signature, VerifySlackSignature};
+
+const SECRET: &str = "test-signing-secret";
+
+fn fixture(name: &str) -> String {
+    std::fs::read_to_string(format!("tests/fixtures/slack/{}.txt", name)).unwrap()
+}
+
+/// Build a request for a fixture payload, signed as Slack would sign it
+fn signed_request(uri: &str, body: &str, secret: &str, timestamp: i64) -> TestRequest {
+    TestRequest::post()
+        .uri(uri)
+        .insert_header(("Content-Type", "application/x-www-form-urlencoded"))
+        .insert_header(("X-Slack-Request-Timestamp", timestamp.to_string()))
+        .insert_header((
+            "X-Slack-Signature",
+            compute_slack_signature(secret, timestamp, body.as_bytes()),
+        ))
+        .set_payload(body.to_string())
+}
+
+async fn echo(body: String) -> HttpResponse {
+    HttpResponse::Ok().body(body)
+}
+
+macro_rules! test_app {
+    () => {
+        test::init_service(
+            App::new().service(
+                web::scope("/events")
+                    .wrap(VerifySlackSignature::new(SECRET))
+                    .route("", web::post().to(echo)),
+            ),
+        )
+        .await
+    };
+}
+
+#[actix_web::test]
+async fn test_signed_requests_reach_the_handler_with_their_body() {
+    let app = test_app!();
+
+    for name in ["command", "event"] {
+        let body = fixture(name);
+        let now = chrono::Utc::now().timestamp();
+        let req = signed_request("/events", &body, SECRET, now).to_request();
+
+        let res = test::call_service(&app, req).await;
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(test::read_body(res).await, body.as_bytes());
+    }
+}
+
+#[actix_web::test]
+async fn test_unsigned_requests_are_rejected() {
+    let app = test_app!();
+
+    let req = TestRequest::post()
+        .uri("/events")
+        .set_payload(fixture("event"))
+        .to_request();
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[actix_web::test]
+async fn test_requests_signed_with_another_secret_are_rejected() {
+    let app = test_app!();
+
+    let now = chrono::Utc::now().timestamp();
+    let req = signed_request("/events", &fixture("event"), "another-secret", now).to_request();
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[actix_web::test]
+async fn test_replayed_requests_are_rejected() {
+    let app = test_app!();
+
+    let an_hour_ago = chrono::Utc::now().timestamp() - 60 * 60;
+    let req = signed_request("/events", &fixture("event"), SECRET, an_hour_ago).to_request();
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[actix_web::test]
+async fn test_tampered_requests_are_rejected() {
+    let app = test_app!();
+
+    let now = chrono::Utc::now().timestamp();
+    let body = fixture("command");
+    let req = signed_request("/events", &body, SECRET, now)
+        .set_payload(body.replace("text=start", "text=stop"))
+        .to_request();
+
+    let res = test::call_service(&app, req).await;
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+}
@@ -0,0 +1,195 @@
+use similarium::game::submit_guess;
+use similarium::models::{Game, User, Word2Vec};
+use similarium::similarity::{InMemoryBackend, PostgresBackend, SimilarityBackend};
+use similarium::{SimilariumError, SimilariumErrorType};
+use uuid::Uuid;
+
+async fn count_similarities(secret: &str, pool: &sqlx::PgPool) -> i64 {
+    sqlx::query_scalar("SELECT count(*) FROM secret_similarity WHERE secret = $1")
+        .bind(secret)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+}
+
+#[sqlx::test(fixtures("words"))]
+fn test_compute_similarities_ranks_every_word(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let similarity = PostgresBackend::new(pool.clone());
+    let secret = Word2Vec {
+        word: "secret".to_string(),
+    };
+    assert!(!similarity.has_similarities("secret").await?);
+
+    secret.compute_similarities(&similarity).await?;
+    assert!(similarity.has_similarities("secret").await?);
+
+    let word_count: i64 = sqlx::query_scalar("SELECT count(*) FROM word2vec")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(count_similarities("secret", &pool).await, word_count);
+
+    let itself = secret.get_similarity("secret", &similarity).await?;
+    assert_eq!(itself.rank, 0);
+
+    let closest = secret.get_word_at_rank(1, &similarity).await?;
+    assert_eq!(closest.rank, 1);
+    assert_eq!(
+        secret.get_closest_words(1, &similarity).await?,
+        vec![closest.word]
+    );
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("words"))]
+fn test_compute_similarities_only_computes_once(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let similarity = PostgresBackend::new(pool.clone());
+    let secret = Word2Vec {
+        word: "secret".to_string(),
+    };
+    secret.compute_similarities(&similarity).await?;
+    let count = count_similarities("secret", &pool).await;
+
+    secret.compute_similarities(&similarity).await?;
+    assert_eq!(count_similarities("secret", &pool).await, count);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("words"))]
+fn test_compute_similarities_for_unknown_word_errors(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let similarity = PostgresBackend::new(pool.clone());
+    for word in ["notaword", "secret'; DROP TABLE word2vec; --"] {
+        let secret = Word2Vec {
+            word: word.to_string(),
+        };
+        let err = secret.compute_similarities(&similarity).await.unwrap_err();
+        assert_eq!(err.error_type, SimilariumErrorType::ValueError);
+    }
+
+    // The words are bound as parameters, so nothing was dropped
+    let secret = Word2Vec {
+        word: "secret".to_string(),
+    };
+    secret.compute_similarities(&similarity).await?;
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("words"))]
+fn test_get_similarity_for_unknown_word_is_not_found(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let similarity = PostgresBackend::new(pool.clone());
+    let secret = Word2Vec {
+        word: "secret".to_string(),
+    };
+    secret.compute_similarities(&similarity).await?;
+
+    let err = secret
+        .get_similarity("notaword", &similarity)
+        .await
+        .unwrap_err();
+    assert_eq!(err.error_type, SimilariumErrorType::NotFound);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "games", "words"))]
+fn test_prune_similarities_keeps_secrets_of_active_games(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let similarity = PostgresBackend::new(pool.clone());
+    // The active game in the fixtures uses "secret"
+    for word in ["secret", "apple"] {
+        let secret = Word2Vec {
+            word: word.to_string(),
+        };
+        secret.compute_similarities(&similarity).await?;
+    }
+
+    assert_eq!(similarity.prune_similarities(&[]).await?, 1);
+    assert!(count_similarities("secret", &pool).await > 0);
+    assert_eq!(count_similarities("apple", &pool).await, 0);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("words"))]
+fn test_prune_similarities_keeps_upcoming_secrets(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let similarity = PostgresBackend::new(pool.clone());
+    for word in ["secret", "apple"] {
+        similarity.compute_similarities(word).await?;
+    }
+
+    assert_eq!(
+        similarity
+            .prune_similarities(&["apple".to_string()])
+            .await?,
+        1
+    );
+    assert_eq!(count_similarities("secret", &pool).await, 0);
+    assert!(count_similarities("apple", &pool).await > 0);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("words"))]
+fn test_in_memory_backend_ranks_like_postgres(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let postgres = PostgresBackend::new(pool.clone());
+    let memory = InMemoryBackend::load_from_db(&pool, 1).await?;
+    postgres.compute_similarities("secret").await?;
+    memory.compute_similarities("secret").await?;
+
+    let words: Vec<String> = sqlx::query_scalar("SELECT word FROM word2vec")
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+    assert_eq!(memory.len(), words.len());
+
+    for word in words {
+        assert_eq!(
+            memory.get_similarity("secret", &word).await?.rank,
+            postgres.get_similarity("secret", &word).await?.rank,
+            "rank of {}",
+            word
+        );
+    }
+    assert_eq!(
+        memory.get_closest_words("secret", 5).await?,
+        postgres.get_closest_words("secret", 5).await?
+    );
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "games", "users", "words"))]
+fn test_submitting_guess_with_in_memory_backend(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let similarity = InMemoryBackend::load_from_db(&pool, 1).await?;
+    let game_id: Uuid = Uuid::parse_str("00000001-0000-4000-a000-000000000000").unwrap();
+    let game = Game::get_by_id(game_id, &pool).await?.unwrap();
+    let user = User::get("user_id_1", &pool).await?.unwrap();
+
+    let guess = submit_guess(&user, &game, "Secret", &pool, &similarity).await?;
+    assert!(guess.is_secret());
+    assert_eq!(guess.rank, 0);
+
+    let err = submit_guess(&user, &game, "notaword", &pool, &similarity)
+        .await
+        .unwrap_err();
+    assert_eq!(err.error_type, SimilariumErrorType::NotFound);
+
+    // Nothing was written to the similarity table
+    assert!(
+        !PostgresBackend::new(pool.clone())
+            .has_similarities(&game.secret)
+            .await?
+    );
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use similarium::game::stats::get_player_stats;
+use similarium::models::{Game, GameWinnerAssociation};
+use similarium::SimilariumError;
+use uuid::Uuid;
+
+async fn get_games(pool: &sqlx::PgPool) -> Result<(Game, Game), SimilariumError> {
+    let active_id = Uuid::parse_str("00000001-0000-4000-a000-000000000000").unwrap();
+    let ended_id = Uuid::parse_str("00000002-0000-4000-a000-000000000000").unwrap();
+
+    Ok((
+        Game::get_by_id(active_id, pool).await?.unwrap(),
+        Game::get_by_id(ended_id, pool).await?.unwrap(),
+    ))
+}
+
+#[sqlx::test(fixtures("channel", "games", "users", "words", "guesses"))]
+fn test_player_stats(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let (active_game, ended_game) = get_games(&pool).await?;
+    ended_game.add_winner("user_id_3", 5, &pool).await?;
+    active_game.add_winner("user_id_3", 12, &pool).await?;
+
+    let stats = get_player_stats("channel_id", "user_id_3", &pool).await?;
+
+    // Only the active game has guesses in the fixtures
+    assert_eq!(stats.games_played, 1);
+    assert_eq!(stats.games_won, 2);
+    assert_eq!(stats.average_guesses, Some(8.5));
+    assert_eq!(stats.best_guess, Some(5));
+    assert_eq!(stats.current_streak, 2);
+    assert_eq!(stats.longest_streak, 2);
+    assert_eq!(stats.guess_distribution, vec![1, 1, 0, 0, 0, 0]);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "games", "users", "words", "guesses"))]
+fn test_player_stats_counts_latest_guesser_as_playing(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    let stats = get_player_stats("channel_id", "user_id_1", &pool).await?;
+
+    assert_eq!(stats.games_played, 1);
+    assert_eq!(stats.games_won, 0);
+    assert_eq!(stats.average_guesses, None);
+    assert_eq!(stats.best_guess, None);
+    assert_eq!(stats.current_streak, 0);
+    assert_eq!(stats.longest_streak, 0);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "games", "users", "words", "guesses"))]
+fn test_player_stats_for_user_without_games(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let stats = get_player_stats("channel_id", "user_id_2", &pool).await?;
+
+    assert_eq!(stats.games_played, 0);
+    assert_eq!(stats.win_rate(), 0.0);
+
+    Ok(())
+}
+
+async fn add_winner(
+    game: &Game,
+    user_id: &str,
+    guess_idx: i64,
+    created: i64,
+    pool: &sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    GameWinnerAssociation {
+        game_id: game.id,
+        user_id: user_id.to_string(),
+        guess_idx,
+        created,
+    }
+    .insert(pool)
+    .await
+}
+
+#[sqlx::test(fixtures("channel", "games", "users"))]
+fn test_top_solvers(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let (active_game, ended_game) = get_games(&pool).await?;
+    add_winner(&ended_game, "user_id_2", 10, 1, &pool).await?;
+    add_winner(&ended_game, "user_id_1", 20, 2, &pool).await?;
+    add_winner(&ended_game, "user_id_3", 30, 3, &pool).await?;
+    add_winner(&active_game, "user_id_3", 40, 4, &pool).await?;
+
+    let solvers = GameWinnerAssociation::get_top_solvers("channel_id", None, 10, &pool).await?;
+    let user_ids = solvers
+        .iter()
+        .map(|solver| solver.user_id.as_str())
+        .collect::<Vec<_>>();
+
+    // Most wins first, then most first solves, then fewest guesses on average
+    assert_eq!(user_ids, vec!["user_id_3", "user_id_2", "user_id_1"]);
+    assert_eq!(solvers[0].wins, 2);
+    assert_eq!(solvers[0].average_guesses, 35.0);
+    assert_eq!(solvers[0].best_guess, 30);
+    assert_eq!(solvers[0].first_solves, 1);
+    assert_eq!(solvers[1].first_solves, 1);
+    assert_eq!(solvers[2].first_solves, 0);
+
+    let solvers = GameWinnerAssociation::get_top_solvers("channel_id", None, 1, &pool).await?;
+    assert_eq!(solvers.len(), 1);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "games", "users"))]
+fn test_top_solvers_first_solves_break_ties(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let (_, ended_game) = get_games(&pool).await?;
+    add_winner(&ended_game, "user_id_1", 20, 2, &pool).await?;
+    add_winner(&ended_game, "user_id_2", 10, 3, &pool).await?;
+
+    let solvers = GameWinnerAssociation::get_top_solvers("channel_id", None, 10, &pool).await?;
+    let user_ids = solvers
+        .iter()
+        .map(|solver| solver.user_id.as_str())
+        .collect::<Vec<_>>();
+
+    assert_eq!(user_ids, vec!["user_id_1", "user_id_2"]);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "games", "users"))]
+fn test_top_solvers_since(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let (_, ended_game) = get_games(&pool).await?;
+    add_winner(&ended_game, "user_id_1", 20, 1, &pool).await?;
+
+    // The fixture games are from 2019
+    let since = chrono::DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z")
+        .unwrap()
+        .to_utc();
+    let solvers =
+        GameWinnerAssociation::get_top_solvers("channel_id", Some(since), 10, &pool).await?;
+    assert!(solvers.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use similarium::suggestions::SuggestionIndex;
+use similarium::SimilariumError;
+
+#[sqlx::test(fixtures("words"))]
+fn test_suggestion_index_loads_vocabulary(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    let index = SuggestionIndex::load_from_db(&pool).await?;
+    assert_eq!(index.len(), 8);
+
+    assert_eq!(index.suggest("aple"), vec!["apple"]);
+    assert_eq!(index.suggest("oragne"), vec!["orange"]);
+    assert_eq!(index.suggest("secert"), vec!["secret"]);
+    assert_eq!(index.suggest("banana"), Vec::<String>::new());
+
+    Ok(())
+}
@@ -0,0 +1,204 @@
+use similarium::models::{Channel, SlackBot};
+use similarium::utils::uninstall_team;
+use similarium::{SimilariumError, SimilariumErrorType};
+
+#[sqlx::test(fixtures("slack_bots"))]
+async fn test_get_slack_bot_token(pool: sqlx::PgPool) -> Result<(), SimilariumError> {
+    assert_eq!(
+        SlackBot::get_slack_bot_token(None, "team_id", &pool).await?,
+        "xoxb-token"
+    );
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "slack_bots"))]
+async fn test_uninstall_team_revokes_token_and_deactivates_channels(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    uninstall_team(None, "team_id", &pool).await?;
+
+    let error = SlackBot::get_slack_bot_token(None, "team_id", &pool)
+        .await
+        .unwrap_err();
+    assert_eq!(error.error_type, SimilariumErrorType::TokenRevoked);
+    assert!(!Channel::get("channel_id", &pool).await?.unwrap().active);
+
+    // Other teams are left alone
+    assert_eq!(
+        SlackBot::get_slack_bot_token(None, "other_team_id", &pool).await?,
+        "xoxb-other"
+    );
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("slack_bots"))]
+async fn test_reinstalling_after_uninstall_uses_new_token(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    uninstall_team(None, "team_id", &pool).await?;
+
+    SlackBot {
+        id: uuid::Uuid::new_v4(),
+        app_id: "app_id".to_string(),
+        enterprise_id: None,
+        enterprise_name: None,
+        team_id: Some("team_id".to_string()),
+        team_name: Some("Team".to_string()),
+        bot_token: Some("xoxb-new".to_string()),
+        bot_id: None,
+        bot_user_id: Some("bot_user_id".to_string()),
+        bot_scopes: None,
+        bot_refresh_token: None,
+        bot_token_expires_at: None,
+        is_enterprise_install: false,
+        installed_at: chrono::Utc::now(),
+        revoked_at: None,
+    }
+    .upsert(&pool)
+    .await?;
+
+    assert_eq!(
+        SlackBot::get_slack_bot_token(None, "team_id", &pool).await?,
+        "xoxb-new"
+    );
+
+    // The reinstall replaces the previous install rather than adding another
+    let installs: i64 = sqlx::query_scalar("SELECT count(*) FROM slack_bots WHERE team_id = $1")
+        .bind("team_id")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(installs, 1);
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "slack_bots"))]
+async fn test_get_slack_bot_token_falls_back_to_org_wide_install(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    get_org_wide_install().upsert(&pool).await?;
+
+    // A workspace with its own install uses it, and any other in the org uses the org-wide one
+    assert_eq!(
+        SlackBot::get_slack_bot_token(Some("enterprise_id"), "team_id", &pool).await?,
+        "xoxb-token"
+    );
+    assert_eq!(
+        SlackBot::get_slack_bot_token(Some("enterprise_id"), "shared_team_id", &pool).await?,
+        "xoxb-org"
+    );
+    assert_eq!(
+        SlackBot::get_slack_bot_token(None, "shared_team_id", &pool)
+            .await
+            .unwrap_err()
+            .error_type,
+        SimilariumErrorType::NotFound
+    );
+
+    // A channel in the org uses the org-wide install once its workspace uninstalls
+    let mut channel = Channel::get("channel_id", &pool).await?.unwrap();
+    channel.enterprise_id = Some("enterprise_id".to_string());
+    channel.update(&pool).await?;
+    SlackBot::revoke(None, "team_id", &pool).await?;
+    assert_eq!(channel.get_slack_bot_token(&pool).await?, "xoxb-org");
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "slack_bots"))]
+async fn test_uninstall_org_wide_install_deactivates_org_channels(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    sqlx::query("DELETE FROM slack_bots WHERE team_id = 'team_id'")
+        .execute(&pool)
+        .await?;
+    get_org_wide_install().upsert(&pool).await?;
+    let mut channel = Channel::get("channel_id", &pool).await?.unwrap();
+    channel.enterprise_id = Some("enterprise_id".to_string());
+    channel.update(&pool).await?;
+
+    uninstall_team(Some("enterprise_id"), "shared_team_id", &pool).await?;
+
+    assert!(!Channel::get("channel_id", &pool).await?.unwrap().active);
+    assert_eq!(
+        channel
+            .get_slack_bot_token(&pool)
+            .await
+            .unwrap_err()
+            .error_type,
+        SimilariumErrorType::TokenRevoked
+    );
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "slack_bots"))]
+async fn test_uninstall_org_wide_install_keeps_channels_on_teams_with_their_own_install(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    get_org_wide_install().upsert(&pool).await?;
+    let mut channel = Channel::get("channel_id", &pool).await?.unwrap();
+    channel.enterprise_id = Some("enterprise_id".to_string());
+    channel.update(&pool).await?;
+    let shared_channel = Channel {
+        id: "shared_channel_id".to_string(),
+        team_id: "shared_team_id".to_string(),
+        ..Channel::get("channel_id", &pool).await?.unwrap()
+    };
+    shared_channel.insert(&pool).await?;
+
+    uninstall_team(Some("enterprise_id"), "shared_team_id", &pool).await?;
+
+    // The channel on the team with its own install keeps using it
+    let channel = Channel::get("channel_id", &pool).await?.unwrap();
+    assert!(channel.active);
+    assert_eq!(channel.get_slack_bot_token(&pool).await?, "xoxb-token");
+    assert!(
+        !Channel::get("shared_channel_id", &pool)
+            .await?
+            .unwrap()
+            .active
+    );
+
+    Ok(())
+}
+
+#[sqlx::test(fixtures("channel", "slack_bots"))]
+async fn test_uninstall_team_keeps_channels_that_the_org_wide_install_serves(
+    pool: sqlx::PgPool,
+) -> Result<(), SimilariumError> {
+    get_org_wide_install().upsert(&pool).await?;
+    let mut channel = Channel::get("channel_id", &pool).await?.unwrap();
+    channel.enterprise_id = Some("enterprise_id".to_string());
+    channel.update(&pool).await?;
+
+    uninstall_team(Some("enterprise_id"), "team_id", &pool).await?;
+
+    let channel = Channel::get("channel_id", &pool).await?.unwrap();
+    assert!(channel.active);
+    assert_eq!(channel.get_slack_bot_token(&pool).await?, "xoxb-org");
+
+    Ok(())
+}
+
+fn get_org_wide_install() -> SlackBot {
+    SlackBot {
+        id: uuid::Uuid::new_v4(),
+        app_id: "app_id".to_string(),
+        enterprise_id: Some("enterprise_id".to_string()),
+        enterprise_name: Some("Org".to_string()),
+        team_id: None,
+        team_name: None,
+        bot_token: Some("xoxb-org".to_string()),
+        bot_id: None,
+        bot_user_id: Some("bot_user_id".to_string()),
+        bot_scopes: None,
+        bot_refresh_token: None,
+        bot_token_expires_at: None,
+        is_enterprise_install: true,
+        installed_at: chrono::Utc::now(),
+        revoked_at: None,
+    }
+}